@@ -0,0 +1,164 @@
+//! Define a stable API to embed the generator as a library.
+//!
+//! Unlike the other modules, this one does not depend on CLI concerns
+//! (parsed cli args, exit statuses...): a [`Generator`] is configured
+//! through its [`GeneratorBuilder`] using the enums of this module, and
+//! reports failures as [`Error`].
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use gitignore_template_generator::api::{Generator, TemplateSources};
+//!
+//! let generator = Generator::builder()
+//!     .sources(TemplateSources::Remote)
+//!     .timeout(Duration::from_secs(10))
+//!     .build()?;
+//!
+//! let gitignore = generator.generate(&["rust", "python"])?;
+//! # Ok::<(), gitignore_template_generator::api::Error>(())
+//! ```
+use std::time::Duration;
+
+use crate::{
+    core::{SourcePrecedence, TemplateManager, TemplateOrder},
+    dialect::ApiDialectKind,
+    layout::Layout,
+};
+
+pub use crate::error::Error;
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Enum of the sources templates are served from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TemplateSources {
+    /// Only serve templates from the remote API.
+    Remote,
+
+    /// Only serve templates from the local template directory.
+    Local,
+
+    /// Serve templates from both the local template directory and the
+    /// remote API.
+    Both,
+}
+
+/// Enum of policies for templates served by both sources.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Precedence {
+    /// Only generate the local version of the template.
+    Local,
+
+    /// Only generate the remote version of the template.
+    Remote,
+
+    /// Generate both versions of the template, local one first.
+    Both,
+
+    /// Generate the local version of the template, completed with the rules
+    /// of the remote one it lacks.
+    Merge,
+}
+
+/// Enum of policies for the order of generated templates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Order {
+    /// Order templates as given.
+    Given,
+
+    /// Order templates by name.
+    Alphabetical,
+
+    /// Group templates by source, local ones first, in the order returned
+    /// by each source.
+    Source,
+}
+
+/// Enum of dialects the remote API may speak.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dialect {
+    /// The dialect of the Toptal API, serving comma-separated template
+    /// names and generating several templates at once.
+    Toptal,
+
+    /// The dialect of APIs serving templates as a JSON object.
+    Json,
+
+    /// The dialect of APIs serving one template per call.
+    PerTemplate,
+}
+
+/// Enum of policies for the newline ending generated content.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NewlinePolicy {
+    /// Always end content with a single newline.
+    Always,
+
+    /// Never end content with a newline.
+    Never,
+}
+
+/// Enum of the sources a generated template may be served from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TemplateSource {
+    /// The local template directory.
+    Local,
+
+    /// The remote API.
+    Remote,
+}
+
+/// DTO struct representing a template generated on its own (see
+/// [`Generator::generate_each`]).
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedTemplate {
+    /// The template name.
+    pub name: String,
+
+    /// The source serving the template.
+    pub source: TemplateSource,
+
+    /// The location the template is served from (i.e. the template file
+    /// path for local templates, or the generator url for remote ones).
+    pub location: String,
+
+    /// The template content.
+    pub content: String,
+}
+
+/// Builder of [`Generator`].
+///
+/// Every setting is optional, falling back to the same default as the
+/// matching cli option.
+#[derive(Clone, Debug)]
+pub struct GeneratorBuilder {
+    sources: TemplateSources,
+    server_url: String,
+    generator_uri: String,
+    lister_uri: String,
+    api_dialect: ApiDialectKind,
+    timeout: Duration,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    cache_responses: bool,
+    default_template_dir: Option<String>,
+    template_extensions: Vec<String>,
+    excluded_templates: Vec<String>,
+    prefer: SourcePrecedence,
+    order: TemplateOrder,
+    strict: bool,
+    check_template_names: bool,
+    layout: Layout,
+}
+
+/// Generator of gitignore templates, as configured by its
+/// [`GeneratorBuilder`].
+pub struct Generator {
+    manager: Box<dyn TemplateManager>,
+    check_template_names: bool,
+    layout: Layout,
+}
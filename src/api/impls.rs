@@ -0,0 +1,450 @@
+use std::{path::Path, time::Duration};
+
+use crate::{
+    api::{
+        Dialect, Error, GeneratedTemplate, Generator, GeneratorBuilder, NewlinePolicy, Order,
+        Precedence, TemplateSource, TemplateSources,
+    },
+    cache::FileSystemCache,
+    constant::{
+        error_messages,
+        template_manager::{
+            self, CACHE_DIR, CACHE_HOME_ENV_VAR, DEFAULT_CACHE_HOME, DEFAULT_TEMPLATE_DIR,
+        },
+    },
+    core::{
+        GitignoreTemplateManager, LocalGitignoreTemplateManager, RemoteGitignoreTemplateManager,
+        SourcePrecedence, SourcedTemplate, StringKind, TemplateManager, TemplateOrder,
+    },
+    dialect::ApiDialectKind,
+    http_client::{CachedHttpClient, HttpClient, UreqHttpClient},
+    layout::{Layout, TrailingNewline},
+    parser::Args,
+};
+
+impl From<Precedence> for SourcePrecedence {
+    fn from(precedence: Precedence) -> Self {
+        match precedence {
+            Precedence::Local => Self::Local,
+            Precedence::Remote => Self::Remote,
+            Precedence::Both => Self::Both,
+            Precedence::Merge => Self::Merge,
+        }
+    }
+}
+
+impl From<Order> for TemplateOrder {
+    fn from(order: Order) -> Self {
+        match order {
+            Order::Given => Self::Given,
+            Order::Alphabetical => Self::Alphabetical,
+            Order::Source => Self::Source,
+        }
+    }
+}
+
+impl From<Dialect> for ApiDialectKind {
+    fn from(dialect: Dialect) -> Self {
+        match dialect {
+            Dialect::Toptal => Self::Toptal,
+            Dialect::Json => Self::Json,
+            Dialect::PerTemplate => Self::PerTemplate,
+        }
+    }
+}
+
+impl From<NewlinePolicy> for TrailingNewline {
+    fn from(newline_policy: NewlinePolicy) -> Self {
+        match newline_policy {
+            NewlinePolicy::Always => Self::Always,
+            NewlinePolicy::Never => Self::Never,
+        }
+    }
+}
+
+impl From<SourcedTemplate> for GeneratedTemplate {
+    fn from(template: SourcedTemplate) -> Self {
+        Self {
+            name: template.name,
+            source: match template.source {
+                StringKind::Local => TemplateSource::Local,
+                _ => TemplateSource::Remote,
+            },
+            location: template.location,
+            content: template.content,
+        }
+    }
+}
+
+impl Default for GeneratorBuilder {
+    fn default() -> Self {
+        Self {
+            sources: TemplateSources::Both,
+            server_url: template_manager::BASE_URL.to_string(),
+            generator_uri: template_manager::GENERATOR_URI.to_string(),
+            lister_uri: template_manager::LISTER_URI.to_string(),
+            api_dialect: ApiDialectKind::Toptal,
+            timeout: Duration::from_secs(template_manager::TIMEOUT_INT),
+            connect_timeout: None,
+            read_timeout: None,
+            cache_responses: false,
+            default_template_dir: None,
+            template_extensions: vec![template_manager::TEMPLATE_EXTENSION.to_string()],
+            excluded_templates: Vec::new(),
+            prefer: SourcePrecedence::Both,
+            order: TemplateOrder::Source,
+            strict: false,
+            check_template_names: false,
+            layout: Layout::default(),
+        }
+    }
+}
+
+/// Builds the generator settings matching given parsed cli args, so that
+/// the CLI serves templates the same way as the library.
+impl From<&Args> for GeneratorBuilder {
+    fn from(args: &Args) -> Self {
+        Self {
+            sources: TemplateSources::Both,
+            server_url: args.server_url.clone(),
+            generator_uri: args.generator_uri.clone(),
+            lister_uri: args.lister_uri.clone(),
+            api_dialect: args.api_dialect,
+            timeout: args.timeout,
+            connect_timeout: args.connect_timeout,
+            read_timeout: args.read_timeout,
+            cache_responses: args.cache_responses,
+            default_template_dir: None,
+            template_extensions: args.template_extensions.clone(),
+            excluded_templates: args.excluded_templates.clone(),
+            prefer: args.prefer,
+            order: args.order,
+            strict: args.strict,
+            check_template_names: args.check_template_names,
+            layout: Layout::from(args),
+        }
+    }
+}
+
+impl GeneratorBuilder {
+    /// Creates a builder with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the sources templates are served from (defaults to
+    /// [`TemplateSources::Both`]).
+    pub fn sources(mut self, sources: TemplateSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Sets the base url of the remote API (defaults to
+    /// [`template_manager::BASE_URL`]).
+    pub fn server_url(mut self, server_url: &str) -> Self {
+        self.server_url = server_url.to_string();
+        self
+    }
+
+    /// Sets the endpoint URI generating templates (defaults to
+    /// [`template_manager::GENERATOR_URI`]).
+    pub fn generator_uri(mut self, generator_uri: &str) -> Self {
+        self.generator_uri = generator_uri.to_string();
+        self
+    }
+
+    /// Sets the endpoint URI listing templates (defaults to
+    /// [`template_manager::LISTER_URI`]).
+    pub fn lister_uri(mut self, lister_uri: &str) -> Self {
+        self.lister_uri = lister_uri.to_string();
+        self
+    }
+
+    /// Sets the dialect spoken by the remote API (defaults to
+    /// [`Dialect::Toptal`]).
+    pub fn api_dialect(mut self, api_dialect: Dialect) -> Self {
+        self.api_dialect = api_dialect.into();
+        self
+    }
+
+    /// Sets the total deadline of remote API calls (defaults to
+    /// [`template_manager::TIMEOUT_INT`] seconds).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the connection timeout of remote API calls (defaults to none).
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the response read timeout of remote API calls (defaults to
+    /// none).
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Sets whether to cache remote API responses in the [`CACHE_DIR`]
+    /// directory (defaults to `false`).
+    pub fn cache_responses(mut self, cache_responses: bool) -> Self {
+        self.cache_responses = cache_responses;
+        self
+    }
+
+    /// Sets the local template directory used unless the
+    /// [`template_manager::HOME_ENV_VAR`] env var is set (defaults to
+    /// [`DEFAULT_TEMPLATE_DIR`]).
+    pub fn default_template_dir(mut self, default_template_dir: &str) -> Self {
+        self.default_template_dir = Some(default_template_dir.to_string());
+        self
+    }
+
+    /// Sets the file extensions of local templates (defaults to
+    /// [`template_manager::TEMPLATE_EXTENSION`]).
+    pub fn template_extensions(mut self, template_extensions: &[String]) -> Self {
+        self.template_extensions = template_extensions.to_vec();
+        self
+    }
+
+    /// Sets the patterns of local templates to be neither listed nor
+    /// generated (defaults to none).
+    pub fn excluded_templates(mut self, excluded_templates: &[String]) -> Self {
+        self.excluded_templates = excluded_templates.to_vec();
+        self
+    }
+
+    /// Sets the policy for templates served by both sources (defaults to
+    /// [`Precedence::Both`]).
+    pub fn prefer(mut self, prefer: Precedence) -> Self {
+        self.prefer = prefer.into();
+        self
+    }
+
+    /// Sets the policy for the order of generated templates (defaults to
+    /// [`Order::Source`]).
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order.into();
+        self
+    }
+
+    /// Sets whether template names must match exactly, case included
+    /// (defaults to `false`).
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets whether to check template names are available before
+    /// generating them (defaults to `false`).
+    pub fn check_template_names(mut self, check_template_names: bool) -> Self {
+        self.check_template_names = check_template_names;
+        self
+    }
+
+    /// Sets the format of section headers (defaults to
+    /// [`template_manager::SECTION_HEADER`]).
+    pub fn section_header(mut self, section_header: &str) -> Self {
+        self.layout.section_header = section_header.to_string();
+        self
+    }
+
+    /// Sets the format of local template titles (defaults to
+    /// [`template_manager::TEMPLATE_TITLE`]).
+    pub fn template_title(mut self, template_title: &str) -> Self {
+        self.layout.template_title = template_title.to_string();
+        self
+    }
+
+    /// Sets whether to write section headers and local template titles
+    /// (defaults to `true`).
+    pub fn headers(mut self, headers: bool) -> Self {
+        self.layout.headers = headers;
+        self
+    }
+
+    /// Sets the policy for the newline ending generated templates (defaults
+    /// to [`NewlinePolicy::Always`]).
+    pub fn trailing_newline(mut self, trailing_newline: NewlinePolicy) -> Self {
+        self.layout.trailing_newline = trailing_newline.into();
+        self
+    }
+
+    /// Builds the generator.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the generator, or an [`Error`] if it could not
+    /// be set up (e.g. `$HOME` could not be read to locate the local
+    /// template directory or the cache).
+    pub fn build(self) -> Result<Generator, Error> {
+        Ok(Generator {
+            manager: self.build_manager()?,
+            check_template_names: self.check_template_names,
+            layout: Layout {
+                banner: false,
+                ..self.layout
+            },
+        })
+    }
+
+    /// Builds the template manager serving templates from the configured
+    /// sources.
+    pub(crate) fn build_manager(&self) -> Result<Box<dyn TemplateManager>, Error> {
+        Ok(match self.sources {
+            TemplateSources::Remote => Box::new(self.build_remote_manager()?),
+            TemplateSources::Local => Box::new(self.build_local_manager()?),
+            TemplateSources::Both => Box::new(
+                GitignoreTemplateManager::new(vec![
+                    Box::new(self.build_local_manager()?),
+                    Box::new(self.build_remote_manager()?),
+                ])
+                .with_layout(self.layout.clone())
+                .with_precedence(self.prefer)
+                .with_order(self.order)
+                .with_strict(self.strict),
+            ),
+        })
+    }
+
+    /// Builds the template manager serving templates from the local
+    /// template directory.
+    pub(crate) fn build_local_manager(&self) -> Result<LocalGitignoreTemplateManager, Error> {
+        Ok(
+            LocalGitignoreTemplateManager::new(Some(self.default_template_dir_path()?))
+                .with_layout(self.layout.clone())
+                .with_strict(self.strict)
+                .with_extensions(&self.template_extensions)
+                .with_excluded(&self.excluded_templates),
+        )
+    }
+
+    /// Builds the template manager serving templates from the remote API.
+    pub(crate) fn build_remote_manager(&self) -> Result<RemoteGitignoreTemplateManager, Error> {
+        let ureq_http_client = UreqHttpClient {
+            server_url: self.server_url.clone(),
+            global_timeout: Some(self.timeout),
+            connect_timeout: self.connect_timeout,
+            read_timeout: self.read_timeout,
+        };
+        let http_client: Box<dyn HttpClient> = if self.cache_responses {
+            Box::new(CachedHttpClient {
+                http_client: Box::new(ureq_http_client),
+                server_url: self.server_url.clone(),
+                cache: Box::new(FileSystemCache::new(&self.cache_dir()?)),
+            })
+        } else {
+            Box::new(ureq_http_client)
+        };
+
+        Ok(RemoteGitignoreTemplateManager::new(
+            http_client,
+            Some(self.generator_uri.clone()),
+            Some(self.lister_uri.clone()),
+        )
+        .with_api_dialect(self.api_dialect.to_dialect())
+        .with_server_url(&self.server_url)
+        .with_order(self.order)
+        .with_strict(self.strict))
+    }
+
+    fn default_template_dir_path(&self) -> Result<String, Error> {
+        Ok(match &self.default_template_dir {
+            Some(default_template_dir) => default_template_dir.clone(),
+            None => DEFAULT_TEMPLATE_DIR.replace("{home}", &Self::home_path()?),
+        })
+    }
+
+    /// Gets the directory of cached API responses, owned by the tool within
+    /// the base directory of user-specific cache files, i.e. the one given
+    /// by the [`CACHE_HOME_ENV_VAR`] env var if set to an absolute path, or
+    /// [`DEFAULT_CACHE_HOME`] otherwise.
+    pub(crate) fn cache_dir(&self) -> Result<String, Error> {
+        let cache_home = match std::env::var(CACHE_HOME_ENV_VAR) {
+            Ok(cache_home) if Path::new(&cache_home).is_absolute() => cache_home,
+            _ => DEFAULT_CACHE_HOME.replace("{home}", &Self::home_path()?),
+        };
+
+        Ok(CACHE_DIR.replace("{cache_home}", &cache_home))
+    }
+
+    fn home_path() -> Result<String, Error> {
+        std::env::var("HOME").map_err(|error| Error::Env {
+            message: error_messages::READ_HOME_ENV_VAR.replace("{error}", &error.to_string()),
+            source: error,
+        })
+    }
+}
+
+impl Generator {
+    /// Creates a builder to configure a generator.
+    pub fn builder() -> GeneratorBuilder {
+        GeneratorBuilder::new()
+    }
+
+    /// Generates the gitignore content made of the templates matching given
+    /// template names, laid out as configured.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the generated content, or an [`Error`] on
+    /// failure (e.g. template not found, network issues...).
+    pub fn generate<S: AsRef<str>>(&self, template_names: &[S]) -> Result<String, Error> {
+        let template_names = Self::to_owned_names(template_names);
+        let generated = if self.check_template_names {
+            self.manager.generate_with_template_check(&template_names)?
+        } else {
+            self.manager.generate(&template_names)?
+        };
+
+        Ok(self.layout.render(&generated.value, ""))
+    }
+
+    /// Generates each template matching given template names on its own,
+    /// along with the source serving it.
+    ///
+    /// # Returns
+    ///
+    /// A result containing one template per template name and serving
+    /// source, or an [`Error`] on failure.
+    pub fn generate_each<S: AsRef<str>>(
+        &self,
+        template_names: &[S],
+    ) -> Result<Vec<GeneratedTemplate>, Error> {
+        Ok(self
+            .manager
+            .generate_each(&Self::to_owned_names(template_names))?
+            .into_iter()
+            .map(GeneratedTemplate::from)
+            .collect())
+    }
+
+    /// Lists the names of available templates, sorted and without
+    /// duplicates.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the template names, or an [`Error`] on failure.
+    pub fn list(&self) -> Result<Vec<String>, Error> {
+        let mut template_names: Vec<String> = self
+            .manager
+            .list()?
+            .value
+            .lines()
+            .map(|line| line.trim_start_matches('*').to_string())
+            .collect();
+        template_names.sort();
+        template_names.dedup();
+
+        Ok(template_names)
+    }
+
+    fn to_owned_names<S: AsRef<str>>(template_names: &[S]) -> Vec<String> {
+        template_names
+            .iter()
+            .map(|template_name| template_name.as_ref().to_string())
+            .collect()
+    }
+}
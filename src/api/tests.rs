@@ -0,0 +1,209 @@
+use mockito::Server;
+use rstest::{fixture, rstest};
+use serial_test::{parallel, serial};
+
+use crate::{
+    api::{Error, GeneratedTemplate, Generator, NewlinePolicy, TemplateSource, TemplateSources},
+    constant::template_manager,
+    http_client::HttpError,
+    test_helper::{DefaultTestUtils, EnvTestContext, TestUtils},
+};
+
+#[fixture]
+fn ctx() -> EnvTestContext {
+    DefaultTestUtils::create_env_test_context()
+}
+
+mod generator {
+    use super::*;
+
+    mod generate {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            #[parallel]
+            fn it_generates_remote_templates_laid_out_as_configured() {
+                let mut mock_server = Server::new();
+                let mock = mock_server
+                    .mock(
+                        "GET",
+                        format!("{}/rust", template_manager::GENERATOR_URI).as_str(),
+                    )
+                    .with_status(200)
+                    .with_body("### Rust ###\ntarget/\n")
+                    .create();
+
+                let generator = Generator::builder()
+                    .sources(TemplateSources::Remote)
+                    .server_url(&mock_server.url())
+                    .trailing_newline(NewlinePolicy::Never)
+                    .build()
+                    .unwrap();
+
+                let actual = generator.generate(&["rust"]);
+                let expected = Ok(String::from("### Rust ###\ntarget/"));
+
+                mock.assert();
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            #[parallel]
+            fn it_fails_with_http_error_when_template_not_served() {
+                let mut mock_server = Server::new();
+                let mock = mock_server
+                    .mock(
+                        "GET",
+                        format!("{}/foo", template_manager::GENERATOR_URI).as_str(),
+                    )
+                    .with_status(404)
+                    .create();
+
+                let generator = Generator::builder()
+                    .sources(TemplateSources::Remote)
+                    .server_url(&mock_server.url())
+                    .build()
+                    .unwrap();
+
+                let actual = generator.generate(&["foo"]);
+
+                mock.assert();
+                assert!(matches!(
+                    actual,
+                    Err(Error::Http(HttpError::ClientStatus { status: 404, .. }))
+                ));
+            }
+        }
+    }
+
+    mod generate_each {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            #[parallel]
+            fn it_generates_each_template_along_with_its_source() {
+                let mut mock_server = Server::new();
+                let mock = mock_server
+                    .mock(
+                        "GET",
+                        format!("{}/rust", template_manager::GENERATOR_URI).as_str(),
+                    )
+                    .with_status(200)
+                    .with_body("### Rust ###\ntarget/\n")
+                    .create();
+
+                let generator = Generator::builder()
+                    .sources(TemplateSources::Remote)
+                    .server_url(&mock_server.url())
+                    .build()
+                    .unwrap();
+
+                let actual = generator.generate_each(&["rust"]);
+                let expected = Ok(vec![GeneratedTemplate {
+                    name: String::from("rust"),
+                    source: TemplateSource::Remote,
+                    location: format!(
+                        "{}{}/rust",
+                        mock_server.url(),
+                        template_manager::GENERATOR_URI
+                    ),
+                    content: String::from("### Rust ###\ntarget/"),
+                }]);
+
+                mock.assert();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod list {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[serial]
+            fn it_lists_local_template_names(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                DefaultTestUtils::set_env_var(template_manager::HOME_ENV_VAR, &template_dir);
+
+                let generator = Generator::builder()
+                    .sources(TemplateSources::Local)
+                    .build()
+                    .unwrap();
+
+                let actual = generator.list();
+                let expected = Ok(DefaultTestUtils::to_string_list("python rust"));
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod generator_builder {
+    use super::*;
+
+    mod cache_dir {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[serial]
+            fn it_puts_cache_in_cache_home_from_env_var(_ctx: EnvTestContext) {
+                let original_cache_home = std::env::var(template_manager::CACHE_HOME_ENV_VAR);
+                DefaultTestUtils::set_env_var(template_manager::CACHE_HOME_ENV_VAR, "/tmp/xdg");
+
+                let actual = Generator::builder().cache_dir();
+                let expected = Ok(String::from("/tmp/xdg/gitignore-template-generator"));
+
+                match original_cache_home {
+                    Ok(value) => {
+                        DefaultTestUtils::set_env_var(template_manager::CACHE_HOME_ENV_VAR, value)
+                    }
+                    Err(_) => {
+                        DefaultTestUtils::remove_env_var(template_manager::CACHE_HOME_ENV_VAR)
+                    }
+                }
+                assert_eq!(actual, expected);
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_puts_cache_in_default_cache_home_when_env_var_not_absolute(_ctx: EnvTestContext) {
+                let original_cache_home = std::env::var(template_manager::CACHE_HOME_ENV_VAR);
+                DefaultTestUtils::set_env_var(template_manager::CACHE_HOME_ENV_VAR, "relative");
+                DefaultTestUtils::set_env_var(template_manager::HOME_ENV_VAR, "/tmp/h/mytemplates");
+
+                let actual = Generator::builder().cache_dir();
+                let expected = Ok(format!(
+                    "{}/.cache/gitignore-template-generator",
+                    std::env::var("HOME").unwrap()
+                ));
+
+                match original_cache_home {
+                    Ok(value) => {
+                        DefaultTestUtils::set_env_var(template_manager::CACHE_HOME_ENV_VAR, value)
+                    }
+                    Err(_) => {
+                        DefaultTestUtils::remove_env_var(template_manager::CACHE_HOME_ENV_VAR)
+                    }
+                }
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Define components to cache HTTP responses.
+use std::{cell::RefCell, collections::HashMap, io::Error};
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// DTO struct representing a cached HTTP response along with its
+/// validators.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CachedResponse {
+    /// The raw response body.
+    pub body: Vec<u8>,
+
+    /// The value of the `ETag` response header, if any.
+    pub etag: Option<String>,
+
+    /// The value of the `Last-Modified` response header, if any.
+    pub last_modified: Option<String>,
+}
+
+/// Response cache trait to store and load HTTP responses.
+pub trait ResponseCache {
+    /// Loads the response cached for given key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the cached response (e.g. the called url).
+    ///
+    /// # Returns
+    ///
+    /// The cached response if any, or `None` if there is none or it could
+    /// not be read.
+    fn load(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Stores given response under given key, replacing any previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the cached response (e.g. the called url).
+    /// * `response` - The response to be cached.
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or a [`std::io::Error`] on error (e.g. file system
+    /// failure, insufficient privilege...).
+    fn store(&self, key: &str, response: &CachedResponse) -> Result<(), Error>;
+}
+
+/// Response cache implementation storing responses as files in a
+/// directory.
+///
+/// Each response is stored in its own file, named after a hash of its key.
+/// The directory is created on first store if it does not exist.
+pub struct FileSystemCache {
+    /// The path of the directory holding the cached responses.
+    pub directory_path: String,
+}
+
+/// Response cache implementation keeping responses in memory.
+#[derive(Default)]
+pub struct MemoryCache {
+    /// The cached responses, keyed by their key.
+    pub entries: RefCell<HashMap<String, CachedResponse>>,
+}
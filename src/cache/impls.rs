@@ -0,0 +1,81 @@
+use std::{fs, io::Error, path::PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use super::{CachedResponse, FileSystemCache, MemoryCache, ResponseCache};
+
+impl FileSystemCache {
+    pub fn new(directory_path: &str) -> Self {
+        Self {
+            directory_path: directory_path.to_string(),
+        }
+    }
+
+    /// Gets the path of the file holding the response cached for given
+    /// key, named after the hex SHA-256 digest of the key so that it stays
+    /// the same across builds.
+    pub(crate) fn entry_path(&self, key: &str) -> PathBuf {
+        let digest = Sha256::digest(key.as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        PathBuf::from(&self.directory_path).join(digest)
+    }
+}
+
+impl CachedResponse {
+    /// Serializes the response as its `ETag` line, its `Last-Modified`
+    /// line, then its raw body.
+    fn serialize(&self) -> Vec<u8> {
+        let mut content = format!(
+            "{}\n{}\n",
+            self.etag.as_deref().unwrap_or_default(),
+            self.last_modified.as_deref().unwrap_or_default(),
+        )
+        .into_bytes();
+        content.extend_from_slice(&self.body);
+
+        content
+    }
+
+    fn deserialize(content: &[u8]) -> Option<Self> {
+        let mut parts = content.splitn(3, |byte| *byte == b'\n');
+        let mut next_validator = || {
+            let value = String::from_utf8(parts.next()?.to_vec()).ok()?;
+            Some((!value.is_empty()).then_some(value))
+        };
+
+        Some(Self {
+            etag: next_validator()?,
+            last_modified: next_validator()?,
+            body: parts.next()?.to_vec(),
+        })
+    }
+}
+
+impl ResponseCache for FileSystemCache {
+    fn load(&self, key: &str) -> Option<CachedResponse> {
+        let content = fs::read(self.entry_path(key)).ok()?;
+
+        CachedResponse::deserialize(&content)
+    }
+
+    fn store(&self, key: &str, response: &CachedResponse) -> Result<(), Error> {
+        fs::create_dir_all(&self.directory_path)?;
+        fs::write(self.entry_path(key), response.serialize())
+    }
+}
+
+impl ResponseCache for MemoryCache {
+    fn load(&self, key: &str) -> Option<CachedResponse> {
+        self.entries.borrow().get(key).cloned()
+    }
+
+    fn store(&self, key: &str, response: &CachedResponse) -> Result<(), Error> {
+        self.entries
+            .borrow_mut()
+            .insert(key.to_string(), response.clone());
+        Ok(())
+    }
+}
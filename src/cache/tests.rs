@@ -0,0 +1,129 @@
+use std::{env, fs, path::PathBuf};
+
+use crate::cache::{CachedResponse, FileSystemCache, MemoryCache, ResponseCache};
+
+fn cached_response() -> CachedResponse {
+    CachedResponse {
+        body: b"rust\npython\n".to_vec(),
+        etag: Some(String::from("\"abc\"")),
+        last_modified: None,
+    }
+}
+
+mod file_system_cache {
+    use super::*;
+
+    fn temp_cache(name: &str) -> FileSystemCache {
+        let directory_path = env::temp_dir().join(format!(
+            "gitignore_template_generator_cache_{name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory_path);
+
+        FileSystemCache::new(&directory_path.to_string_lossy())
+    }
+
+    mod load {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_loads_previously_stored_response() {
+                let cache = temp_cache("load");
+                let response = cached_response();
+
+                cache.store("/api/list", &response).unwrap();
+
+                let actual = cache.load("/api/list");
+                let expected = Some(response);
+
+                fs::remove_dir_all(&cache.directory_path).unwrap();
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_loads_nothing_when_key_not_cached() {
+                let cache = temp_cache("miss");
+
+                let actual = cache.load("/api/list");
+                let expected = None;
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod entry_path {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_names_entry_after_sha256_of_key() {
+                let cache = FileSystemCache::new("cache");
+
+                let actual = cache.entry_path("abc");
+                let expected = PathBuf::from(
+                    "cache/ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+                );
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod store {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_replaces_previously_stored_response() {
+                let cache = temp_cache("store");
+                let response = CachedResponse {
+                    body: b"rust".to_vec(),
+                    etag: None,
+                    last_modified: Some(String::from("Wed, 21 Oct 2015 07:28:00 GMT")),
+                };
+
+                cache.store("/api/list", &cached_response()).unwrap();
+                cache.store("/api/list", &response).unwrap();
+
+                let actual = cache.load("/api/list");
+                let expected = Some(response);
+
+                fs::remove_dir_all(&cache.directory_path).unwrap();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod memory_cache {
+    use super::*;
+
+    mod load {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_loads_previously_stored_response() {
+                let cache = MemoryCache::default();
+                let response = cached_response();
+
+                cache.store("/api/list", &response).unwrap();
+
+                let actual = cache.load("/api/list");
+                let expected = Some(response);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub mod error_messages;
 pub mod exit_status;
 pub mod help_messages;
 pub mod help_texts;
+pub mod log_messages;
 pub mod parser_infos;
 pub mod path;
 pub mod template_manager;
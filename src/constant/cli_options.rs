@@ -73,10 +73,305 @@ pub const TIMEOUT: CliOptionName = CliOptionName {
     long: "timeout",
 };
 
-/// Short and long specifier for timeout option.
+/// Long specifier for connect timeout option.
+///
+/// **Value**: `--connect-timeout`
+pub const CONNECT_TIMEOUT: CliOptionName = CliOptionName {
+    short: "",
+    long: "connect-timeout",
+};
+
+/// Long specifier for read timeout option.
 ///
-/// **Value**: `-u --timeout-unit`
-pub const TIMEOUT_UNIT: CliOptionName = CliOptionName {
-    short: "u",
-    long: "timeout-unit",
+/// **Value**: `--read-timeout`
+pub const READ_TIMEOUT: CliOptionName = CliOptionName {
+    short: "",
+    long: "read-timeout",
 };
+
+/// Long specifier for cache option.
+///
+/// **Value**: `--cache`
+pub const CACHE: CliOptionName = CliOptionName {
+    short: "",
+    long: "cache",
+};
+
+/// Long specifier for api dialect option.
+///
+/// **Value**: `--api-dialect`
+pub const API_DIALECT: CliOptionName = CliOptionName {
+    short: "",
+    long: "api-dialect",
+};
+
+/// Long specifier for regex option of search subcommand.
+///
+/// **Value**: `--regex`
+pub const REGEX: CliOptionName = CliOptionName {
+    short: "",
+    long: "regex",
+};
+
+/// Long specifier for content option of search subcommand.
+///
+/// **Value**: `--content`
+pub const CONTENT: CliOptionName = CliOptionName {
+    short: "",
+    long: "content",
+};
+
+/// Long specifier for diff option.
+///
+/// **Value**: `--diff`
+pub const DIFF: CliOptionName = CliOptionName {
+    short: "",
+    long: "diff",
+};
+
+/// Long specifier for lockfile option.
+///
+/// **Value**: `--lockfile`
+pub const LOCKFILE: CliOptionName = CliOptionName {
+    short: "",
+    long: "lockfile",
+};
+
+/// Long specifier for locked option.
+///
+/// **Value**: `--locked`
+pub const LOCKED: CliOptionName = CliOptionName {
+    short: "",
+    long: "locked",
+};
+
+/// Long specifier for section header option.
+///
+/// **Value**: `--section-header`
+pub const SECTION_HEADER: CliOptionName = CliOptionName {
+    short: "",
+    long: "section-header",
+};
+
+/// Long specifier for template title option.
+///
+/// **Value**: `--template-title`
+pub const TEMPLATE_TITLE: CliOptionName = CliOptionName {
+    short: "",
+    long: "template-title",
+};
+
+/// Long specifier for no headers option.
+///
+/// **Value**: `--no-headers`
+pub const NO_HEADERS: CliOptionName = CliOptionName {
+    short: "",
+    long: "no-headers",
+};
+
+/// Long specifier for banner option.
+///
+/// **Value**: `--banner`
+pub const BANNER: CliOptionName = CliOptionName {
+    short: "",
+    long: "banner",
+};
+
+/// Long specifier for trailing newline option.
+///
+/// **Value**: `--trailing-newline`
+pub const TRAILING_NEWLINE: CliOptionName = CliOptionName {
+    short: "",
+    long: "trailing-newline",
+};
+
+/// Long specifier for prefer option.
+///
+/// **Value**: `--prefer`
+pub const PREFER: CliOptionName = CliOptionName {
+    short: "",
+    long: "prefer",
+};
+
+/// Long specifier for order option.
+///
+/// **Value**: `--order`
+pub const ORDER: CliOptionName = CliOptionName {
+    short: "",
+    long: "order",
+};
+
+/// Long specifier for strict option.
+///
+/// **Value**: `--strict`
+pub const STRICT: CliOptionName = CliOptionName {
+    short: "",
+    long: "strict",
+};
+
+/// Long specifier for template extension option.
+///
+/// **Value**: `--template-ext`
+pub const TEMPLATE_EXTENSION: CliOptionName = CliOptionName {
+    short: "",
+    long: "template-ext",
+};
+
+/// Long specifier for exclude option.
+///
+/// **Value**: `--exclude`
+pub const EXCLUDE: CliOptionName = CliOptionName {
+    short: "",
+    long: "exclude",
+};
+
+/// Long specifier for preview option.
+///
+/// **Value**: `--preview`
+pub const PREVIEW: CliOptionName = CliOptionName {
+    short: "",
+    long: "preview",
+};
+
+/// Short and long specifiers for verbose option.
+///
+/// **Value**: `-v --verbose`
+pub const VERBOSE: CliOptionName = CliOptionName {
+    short: "v",
+    long: "verbose",
+};
+
+/// Short and long specifiers for quiet option.
+///
+/// **Value**: `-q --quiet`
+pub const QUIET: CliOptionName = CliOptionName {
+    short: "q",
+    long: "quiet",
+};
+
+/// Long specifier for color option.
+///
+/// **Value**: `--color`
+pub const COLOR: CliOptionName = CliOptionName {
+    short: "",
+    long: "color",
+};
+
+/// Long specifier for no pager option.
+///
+/// **Value**: `--no-pager`
+pub const NO_PAGER: CliOptionName = CliOptionName {
+    short: "",
+    long: "no-pager",
+};
+
+/// Long specifier for from file option of template add subcommand.
+///
+/// **Value**: `--from-file`
+pub const FROM_FILE: CliOptionName = CliOptionName {
+    short: "",
+    long: "from-file",
+};
+
+/// Long specifier for from stdin option of template add subcommand.
+///
+/// **Value**: `--from-stdin`
+pub const FROM_STDIN: CliOptionName = CliOptionName {
+    short: "",
+    long: "from-stdin",
+};
+
+/// Long specifier for force option of template import subcommand.
+///
+/// **Value**: `--force`
+pub const FORCE: CliOptionName = CliOptionName {
+    short: "",
+    long: "force",
+};
+
+/// Long specifier for template option of lint subcommand.
+///
+/// **Value**: `--template`
+pub const LINTED_TEMPLATE: CliOptionName = CliOptionName {
+    short: "",
+    long: "template",
+};
+
+/// Long specifier for file option of explain subcommand.
+///
+/// **Value**: `--file`
+pub const EXPLAINED_FILE: CliOptionName = CliOptionName {
+    short: "",
+    long: "file",
+};
+
+/// Long specifier for file option of verify subcommand.
+///
+/// **Value**: `--file`
+pub const VERIFIED_FILE: CliOptionName = CliOptionName {
+    short: "",
+    long: "file",
+};
+
+/// Long specifier for template option of explain subcommand.
+///
+/// **Value**: `--template`
+pub const EXPLAINED_TEMPLATE: CliOptionName = CliOptionName {
+    short: "",
+    long: "template",
+};
+
+/// Name of the subcommand managing local templates.
+///
+/// **Value**: `template`
+pub const TEMPLATE_COMMAND: &str = "template";
+
+/// Name of the template subcommand adding a local template.
+///
+/// **Value**: `add`
+pub const ADD_COMMAND: &str = "add";
+
+/// Name of the template subcommand editing a local template.
+///
+/// **Value**: `edit`
+pub const EDIT_COMMAND: &str = "edit";
+
+/// Name of the template subcommand removing a local template.
+///
+/// **Value**: `remove`
+pub const REMOVE_COMMAND: &str = "remove";
+
+/// Name of the template subcommand renaming a local template.
+///
+/// **Value**: `rename`
+pub const RENAME_COMMAND: &str = "rename";
+
+/// Name of the template subcommand importing remote templates as local ones.
+///
+/// **Value**: `import`
+pub const IMPORT_COMMAND: &str = "import";
+
+/// Name of the subcommand linting gitignore files and local templates.
+///
+/// **Value**: `lint`
+pub const LINT_COMMAND: &str = "lint";
+
+/// Name of the subcommand explaining whether paths are ignored.
+///
+/// **Value**: `explain`
+pub const EXPLAIN_COMMAND: &str = "explain";
+
+/// Name of the subcommand searching available templates.
+///
+/// **Value**: `search`
+pub const SEARCH_COMMAND: &str = "search";
+
+/// Name of the subcommand showing a template along with its sources.
+///
+/// **Value**: `show`
+pub const SHOW_COMMAND: &str = "show";
+
+/// Name of the subcommand verifying a gitignore file contains templates.
+///
+/// **Value**: `verify`
+pub const VERIFY_COMMAND: &str = "verify";
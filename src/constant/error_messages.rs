@@ -6,6 +6,12 @@ pub const CMD_EXECUTION_FAILURE: &str = "Failed to execute command";
 /// An error occurred during HTTP body parsing.
 pub const BODY_PARSING_ISSUE: &str = "An error occurred during body parsing";
 
+/// JSON template list is not an object.
+pub const JSON_LIST_NOT_OBJECT: &str = "template list must be a JSON object";
+
+/// JSON template list entry misses a field.
+pub const JSON_LIST_MISSING_FIELD: &str = "missing string field '{field}' for template '{key}'";
+
 /// An error occurred while reading a file and converting it to a String
 /// instance.
 pub const FILE_READ_TO_STRING_FAILURE: &str = "Failed to read expected output file";
@@ -16,12 +22,33 @@ pub const COMMAS_NOT_ALLOWED: &str = "Commas are not allowed in template names";
 /// An error occurred during an api call.
 pub const API_CALL_FAILURE: &str = "An error occurred during the API call: {error}";
 
+/// The server failed to process an api call (i.e. 5xx).
+pub const API_SERVER_FAILURE: &str = "The server failed to process the API call: {error}";
+
+/// The host of the server to reach could not be resolved.
+pub const DNS_FAILURE: &str = "Failed to resolve the server host: {error}";
+
+/// The server to reach refused the connection.
+pub const CONNECTION_REFUSED: &str = "The server refused the connection: {error}";
+
+/// The TLS handshake with the server to reach failed.
+pub const TLS_FAILURE: &str = "The TLS handshake with the server failed: {error}";
+
+/// A HTTP error status code occurred during api call.
+pub const HTTP_STATUS: &str = "http status: {status}";
+
+/// Response body sent by the server along with an error status code.
+pub const SERVER_RESPONSE: &str = "{error}\nServer response: {body}";
+
 /// A HTTP error 400 occurred during api call.
 pub const HTTP_400: &str = "http status: 400";
 
 /// A HTTP error 404 occurred during api call.
 pub const HTTP_404: &str = "http status: 404";
 
+/// A HTTP error 500 occurred during api call.
+pub const HTTP_500: &str = "http status: 500";
+
 /// User requested author infos but none is available.
 pub const AUTHOR_INFOS_NOT_AVAILABLE: &str = "Author information not available.";
 
@@ -49,12 +76,18 @@ pub const TIMEOUT: &str = "timeout: global";
 /// Invalid utf-8 encoding.
 pub const INVALID_ENCODING: &str = "io: stream did not contain valid UTF-8";
 
+/// Invalid duration syntax.
+pub const INVALID_DURATION: &str = "Value must be an unsigned integer followed by an optional unit among ms, s or m (e.g. 1500ms, 2s)";
+
 /// No slash in front of URI.
 pub const URI_WITHOUT_STARTING_SLASH: &str = "URIs must start a slash (/)";
 
 /// Invalid URL.
 pub const INVALID_URL: &str = "Value must be a valid URL";
 
+pub const INVALID_PATTERN: &str =
+    "Value must be a valid gitignore pattern (e.g. without unclosed character class)";
+
 pub const INVALID_SCHEME: &str = "URLs must start with a valid scheme (http or https)";
 
 pub const LOCAL_GENERATION: &str =
@@ -68,3 +101,38 @@ pub const READ_HOME_ENV_VAR: &str =
     "An error occurred when trying to read $HOME, which is required for local generation: {error}";
 
 pub const INVALID_MAPPED_URI: &str = "Given URI '{uri}' is not supported in defined map";
+
+pub const FILE_READ_FAILURE: &str = "An error occurred while reading '{path}': {error}";
+
+pub const FILE_WRITE_FAILURE: &str = "An error occurred while writing '{path}': {error}";
+
+pub const LOCKFILE_PARSING: &str = "An error occurred while parsing lockfile '{path}': {error}";
+
+/// Lockfile without template list.
+pub const LOCKFILE_NO_TEMPLATES: &str = "missing 'templates' array";
+
+/// Lockfile entry misses a field.
+pub const LOCKFILE_MISSING_FIELD: &str = "missing string field '{field}' for template #{index}";
+
+/// Lockfile entry with unknown source.
+pub const LOCKFILE_INVALID_SOURCE: &str = "unknown template source '{source}'";
+
+/// Option only applying to generated templates given along with a
+/// subcommand.
+pub const SUBCOMMAND_CONFLICT: &str =
+    "the argument '{argument}' cannot be used with subcommand '{subcommand}'";
+
+pub const LOCKED_TEMPLATES_CHANGED: &str = "Following locked templates changed upstream: {templates}.\nTo update the lockfile, retry without '--locked'.";
+
+pub const INVALID_SEARCH_PATTERN: &str = "Invalid search pattern: {error}";
+
+pub const TEMPLATE_ALREADY_EXISTS: &str = "Local template '{name}' already exists";
+
+pub const TEMPLATE_NOT_FOUND: &str = "Local template '{name}' does not exist";
+
+pub const TEMPLATE_OUTSIDE_DIR: &str =
+    "Local template name '{name}' must be a path relative to the template directory";
+
+pub const LOCAL_EDITION: &str = "An error occurred while managing local template '{name}': {error}";
+
+pub const EDITOR_FAILURE: &str = "Editor '{editor}' exited with an error: {error}";
@@ -3,6 +3,15 @@
 /// Exit status code for successful script execution.
 pub const SUCCESS: i32 = 0;
 
+/// Exit status code for compared file differing from generated templates.
+pub const DIFF_MISMATCH: i32 = 1;
+
+/// Exit status code for verified file missing or holding outdated templates.
+pub const VERIFY_FAILURE: i32 = 1;
+
+/// Exit status code for linted files or templates having issues.
+pub const LINT_FAILURE: i32 = 1;
+
 /// Exit status code for generic script error.
 pub const GENERIC: i32 = 2;
 
@@ -11,3 +20,24 @@ pub const BODY_PARSING_ISSUE: i32 = 3;
 
 /// Exit status code for any error from HTTP client itself.
 pub const HTTP_CLIENT_ERROR: i32 = 4;
+
+/// Exit status code for HTTP call that timed out.
+pub const TIMEOUT: i32 = 5;
+
+/// Exit status code for HTTP call to a host that could not be resolved.
+pub const DNS_FAILURE: i32 = 6;
+
+/// Exit status code for HTTP call whose connection got refused.
+pub const CONNECTION_REFUSED: i32 = 7;
+
+/// Exit status code for HTTP call whose TLS handshake failed.
+pub const TLS_FAILURE: i32 = 8;
+
+/// Exit status code for HTTP call answered with a 4xx status code.
+pub const HTTP_CLIENT_STATUS: i32 = 9;
+
+/// Exit status code for HTTP call answered with a 5xx status code.
+pub const HTTP_SERVER_STATUS: i32 = 10;
+
+/// Exit status code for locked templates that changed upstream.
+pub const LOCK_MISMATCH: i32 = 11;
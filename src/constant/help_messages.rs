@@ -38,8 +38,202 @@ pub const CHECK: &str = "Enable robust template names check";
 
 /// Help message bound to [`crate::parser::Args::timeout`]
 /// field (i.e. timeout option).
-pub const TIMEOUT: &str = "The template generation and listing service calls timeout";
+pub const TIMEOUT: &str = "The template generation and listing service calls total deadline";
 
-/// Help message bound to [`crate::parser::Args::timeout_unit`]
-/// field (i.e. timeout unit option).
-pub const TIMEOUT_UNIT: &str = "The timeout unit";
+/// Help message bound to [`crate::parser::Args::connect_timeout`]
+/// field (i.e. connect timeout option).
+pub const CONNECT_TIMEOUT: &str = "The service calls connection timeout";
+
+/// Help message bound to [`crate::parser::Args::read_timeout`]
+/// field (i.e. read timeout option).
+pub const READ_TIMEOUT: &str = "The service calls response read timeout";
+
+/// Help message bound to [`crate::parser::Args::cache_responses`]
+/// field (i.e. cache option).
+pub const CACHE: &str = "Cache service responses and revalidate them on later calls";
+
+/// Help message bound to [`crate::parser::Args::api_dialect`]
+/// field (i.e. api dialect option).
+pub const API_DIALECT: &str = "The dialect spoken by the template manager";
+
+/// Help message bound to [`crate::parser::Args::diff_path`] field (i.e.
+/// diff option).
+pub const DIFF: &str = "Compare given gitignore file with the generated templates";
+
+/// Help message bound to [`crate::parser::Args::lockfile_path`] field (i.e.
+/// lockfile option).
+pub const LOCKFILE: &str = "Record generated templates sources and hashes in given lockfile";
+
+/// Help message bound to [`crate::parser::Args::locked`] field (i.e. locked
+/// option).
+pub const LOCKED: &str = "Fail if generated templates differ from the lockfile";
+
+/// Help message bound to [`crate::parser::Args::section_header`] field (i.e.
+/// section header option).
+pub const SECTION_HEADER: &str = "The format of generated sections headers";
+
+/// Help message bound to [`crate::parser::Args::template_title`] field (i.e.
+/// template title option).
+pub const TEMPLATE_TITLE: &str = "The format of generated local templates titles";
+
+/// Help message bound to [`crate::parser::Args::no_headers`] field (i.e. no
+/// headers option).
+pub const NO_HEADERS: &str = "Omit sections headers and local templates titles";
+
+/// Help message bound to [`crate::parser::Args::banner`] field (i.e. banner
+/// option).
+pub const BANNER: &str = "Start generated templates with a generated-by banner";
+
+/// Help message bound to [`crate::parser::Args::trailing_newline`] field (i.e.
+/// trailing newline option).
+pub const TRAILING_NEWLINE: &str = "Whether to end generated templates with a newline";
+
+/// Help message bound to [`crate::parser::Args::prefer`] field (i.e. prefer
+/// option).
+pub const PREFER: &str = "The source to prefer for templates both local and remote";
+
+/// Help message bound to [`crate::parser::Args::order`] field (i.e. order
+/// option).
+pub const ORDER: &str = "The order of generated templates";
+
+/// Help message bound to [`crate::parser::Args::strict`] field (i.e. strict
+/// option).
+pub const STRICT: &str = "Match template names exactly, case included";
+
+/// Help message bound to [`crate::parser::Args::template_extensions`] field
+/// (i.e. template extension option).
+pub const TEMPLATE_EXTENSION: &str = "File extension of local templates, can be repeated";
+
+/// Help message bound to [`crate::parser::Args::excluded_templates`] field
+/// (i.e. exclude option).
+pub const EXCLUDE: &str = "Exclude local templates matching pattern, can be repeated";
+
+/// Help message bound to [`crate::parser::Args::preview`] field (i.e. preview
+/// option).
+pub const PREVIEW: &str = "List the present files the generated templates would ignore";
+
+/// Help message bound to [`crate::parser::Args::verbose`] field (i.e. verbose
+/// option).
+pub const VERBOSE: &str = "Log what the tool is doing to stderr (-vv for debug details)";
+
+/// Help message bound to [`crate::parser::Args::quiet`] field (i.e. quiet
+/// option).
+pub const QUIET: &str = "Only report failures through the exit status, without error messages";
+
+/// Help message bound to [`crate::parser::Args::color`] field (i.e. color
+/// option).
+pub const COLOR: &str = "When to style output with colors";
+
+/// Help message bound to [`crate::parser::Args::no_pager`] field (i.e. no pager
+/// option).
+pub const NO_PAGER: &str = "Print output directly instead of through the pager";
+
+/// Help message bound to [`crate::parser::Args::template_command`] field
+/// (i.e. template subcommand).
+pub const TEMPLATE_COMMAND: &str = "Manage local templates";
+
+/// Help message bound to [`crate::parser::TemplateCommand::Add`] variant
+/// (i.e. template add subcommand).
+pub const ADD_COMMAND: &str = "Add a local template, written in $EDITOR unless read from elsewhere";
+
+/// Help message bound to [`crate::parser::TemplateCommand::Edit`] variant
+/// (i.e. template edit subcommand).
+pub const EDIT_COMMAND: &str = "Edit a local template in $EDITOR";
+
+/// Help message bound to [`crate::parser::TemplateCommand::Remove`] variant
+/// (i.e. template remove subcommand).
+pub const REMOVE_COMMAND: &str = "Remove a local template";
+
+/// Help message bound to [`crate::parser::TemplateCommand::Rename`] variant
+/// (i.e. template rename subcommand).
+pub const RENAME_COMMAND: &str = "Rename a local template";
+
+/// Help message bound to [`crate::parser::TemplateCommand::Import`] variant
+/// (i.e. template import subcommand).
+pub const IMPORT_COMMAND: &str = "Import remote templates as local templates";
+
+/// Help message bound to the name of the template managed by template
+/// subcommands.
+pub const MANAGED_TEMPLATE_NAME: &str = "The local template name (e.g. rust or languages/rust)";
+
+/// Help message bound to the new name of the template renamed by template
+/// rename subcommand.
+pub const NEW_TEMPLATE_NAME: &str = "The new local template name";
+
+/// Help message bound to [`crate::parser::TemplateSource::File`] variant
+/// (i.e. from file option).
+pub const FROM_FILE: &str = "Read the template content from given file";
+
+/// Help message bound to [`crate::parser::TemplateSource::Stdin`] variant
+/// (i.e. from stdin option).
+pub const FROM_STDIN: &str = "Read the template content from stdin";
+
+/// Help message bound to the names of the templates imported by template
+/// import subcommand.
+pub const IMPORTED_TEMPLATE_NAMES: &str = "The remote template names to be imported";
+
+/// Help message bound to the force option of template import subcommand.
+pub const FORCE: &str = "Overwrite already existing local templates";
+
+/// Help message bound to [`crate::parser::Args::lint_targets`] field (i.e.
+/// lint subcommand).
+pub const LINT_COMMAND: &str = "Lint gitignore files and local templates";
+
+/// Help message bound to [`crate::parser::LintTargets::paths`] field.
+pub const LINTED_PATHS: &str = "The gitignore files to be linted";
+
+/// Help message bound to [`crate::parser::LintTargets::template_names`]
+/// field (i.e. template option of lint subcommand).
+pub const LINTED_TEMPLATE: &str =
+    "Lint given local template, can be repeated (all of them if no file nor template given)";
+
+/// Help message bound to [`crate::parser::Args::explain_targets`] field
+/// (i.e. explain subcommand).
+pub const EXPLAIN_COMMAND: &str = "Explain whether paths are ignored, and by which rule";
+
+/// Help message bound to [`crate::parser::ExplainTargets::paths`] field.
+pub const EXPLAINED_PATHS: &str = "The paths to be explained, directories ending with a slash";
+
+/// Help message bound to [`crate::parser::ExplainTargets::file_path`] field
+/// (i.e. file option of explain subcommand).
+pub const EXPLAINED_FILE: &str = "The gitignore file to match paths against [default: .gitignore]";
+
+/// Help message bound to [`crate::parser::ExplainTargets::template_names`]
+/// field (i.e. template option of explain subcommand).
+pub const EXPLAINED_TEMPLATE: &str =
+    "Match paths against given generated template instead, can be repeated";
+
+/// Help message bound to [`crate::parser::Args::search_targets`] field
+/// (i.e. search subcommand).
+pub const SEARCH_COMMAND: &str = "Search available templates by name";
+
+/// Help message bound to [`crate::parser::SearchTargets::query`] field.
+pub const SEARCH_QUERY: &str = "The query to search available templates with";
+
+/// Help message bound to [`crate::parser::SearchTargets::regex`] field
+/// (i.e. regex option of search subcommand).
+pub const REGEX: &str = "Handle the search query as a regular expression";
+
+/// Help message bound to [`crate::parser::SearchTargets::content`] field
+/// (i.e. content option of search subcommand).
+pub const CONTENT: &str =
+    "Also search inside local template bodies, remote templates being matched by name only";
+
+/// Help message bound to [`crate::parser::Args::show_template_name`] field
+/// (i.e. show subcommand).
+pub const SHOW_COMMAND: &str = "Show a template content along with its sources";
+
+/// Help message bound to the template name argument of show subcommand.
+pub const SHOWN_TEMPLATE_NAME: &str = "The name of the template to be shown";
+
+/// Help message bound to [`crate::parser::Args::verify_targets`] field
+/// (i.e. verify subcommand).
+pub const VERIFY_COMMAND: &str = "Check a gitignore file contains up to date templates";
+
+/// Help message bound to [`crate::parser::VerifyTargets::template_names`]
+/// field.
+pub const VERIFIED_TEMPLATE_NAMES: &str = "The template names expected in the gitignore file";
+
+/// Help message bound to [`crate::parser::VerifyTargets::file_path`] field
+/// (i.e. file option of verify subcommand).
+pub const VERIFIED_FILE: &str = "The gitignore file to be verified [default: .gitignore]";
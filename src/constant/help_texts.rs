@@ -1,4 +1,45 @@
 pub const NOTHING_TO_BE_PRINTED: &str = "Nothing to be printed";
+pub const UP_TO_DATE: &str = "{path} is up to date with the generated templates";
+pub const GENERATED_LABEL: &str = "generated";
+
+pub const VERIFY_UP_TO_DATE: &str = "{name}: up to date";
+pub const VERIFY_MATCHED_BY_RULES: &str = "{name}: up to date (matched by rules)";
+pub const VERIFY_OUTDATED: &str = "{name}: outdated, {count} rule(s) missing";
+pub const VERIFY_MISSING_RULE: &str = "\n  - {rule}";
+pub const VERIFY_MISSING: &str = "{name}: missing";
+
+pub const LINT_CLEAN: &str = "{source}: no issues found";
+pub const LINT_ISSUE: &str = "{source}:{line}: {message}";
+pub const LINT_CRLF_LINE_ENDING: &str = "CRLF line endings, rules may not match as expected";
+pub const LINT_UNESCAPED_TRAILING_SPACE: &str =
+    "trailing whitespace is ignored unless escaped with a backslash";
+pub const LINT_INVALID_DOUBLE_ASTERISK: &str =
+    "'**' only has a special meaning as a whole path segment";
+pub const LINT_INVALID_PATTERN: &str = "invalid pattern, the rule never matches any path";
+pub const LINT_DUPLICATE: &str = "duplicate of the rule at line {line}";
+pub const LINT_UNREACHABLE_RULE: &str =
+    "already ignored through the directory excluded at line {line}";
+pub const LINT_UNREACHABLE_NEGATION: &str =
+    "negation can never match since the parent directory is excluded at line {line}";
+
+pub const EXPLAIN_UNMATCHED: &str = "{path}: not ignored, no rule matches";
+pub const EXPLAIN_IGNORED: &str = "{path}: ignored by {rule}";
+pub const EXPLAIN_RE_INCLUDED: &str = "{path}: not ignored, re-included by {rule}";
+pub const EXPLAIN_IGNORED_PARENT: &str =
+    "{path}: ignored since its parent directory {directory} is ignored by {rule}";
+pub const EXPLAIN_RULE: &str = "'{rule}' at line {line}";
+pub const EXPLAIN_RULE_SECTION: &str = " of template {section}";
+
+pub const PREVIEW_NOTHING_IGNORED: &str = "No present file would be ignored";
+pub const PREVIEW_TRACKED_LOOKING: &str = " (usually tracked)";
+
+pub const SEARCH_LINE: &str = "  {number}: {line}";
+pub const SEARCH_HIGHLIGHT: &str = "\u{1b}[1;31m{match}\u{1b}[0m";
+
+pub const BANNER: &str = "# Generated by {name} {version}\n# Command: {command}";
+
+pub const TEMPLATE_DETAILS: &str =
+    "==> {name} ({source})\nLocation: {location}\nSize: {size} bytes\nRules: {rules}\n\n{content}";
 
 pub const ENV_VAR_RESET: &str = "{name} env var was set to {value}. Resetting it...";
 pub const ENV_VAR_REMOVAL_BEFORE: &str = "{name} is set. Removing it...";
@@ -10,8 +51,17 @@ pub const REMOVED: &str = "Removed!";
 pub const TEST_CXT_DROPPED: &str = "Test context dropped!";
 pub const TEST_CTX_CREATED: &str = "Test context created!";
 
-pub const DEFAULT_TIMEOUT: &str = "{second}s/{millis}ms";
-
 pub const HELP_FOR_MORE_INFOS: &str = "{error}\nFor more information, try '--help'.";
 pub const STYLED_HELP_FOR_MORE_INFOS: &str =
     "{error}\nFor more information, try '\u{1b}[1m--help\u{1b}[0m'.";
+pub const STYLED_ERROR: &str = "\u{1b}[31m{error}\u{1b}[0m";
+pub const STYLED_LOCAL_TEMPLATE: &str = "\u{1b}[32m{name}\u{1b}[0m";
+pub const STYLED_REMOTE_TEMPLATE: &str = "\u{1b}[36m{name}\u{1b}[0m";
+pub const STYLED_SECTION_HEADER: &str = "\u{1b}[1;33m{header}\u{1b}[0m";
+pub const STYLED_TEMPLATE_TITLE: &str = "\u{1b}[1m{title}\u{1b}[0m";
+
+pub const TEMPLATE_ADDED: &str = "Added template {name} to {path}";
+pub const TEMPLATE_EDITED: &str = "Edited template {name} at {path}";
+pub const TEMPLATE_REMOVED: &str = "Removed template {name} from {path}";
+pub const TEMPLATE_IMPORTED: &str = "Imported template {name} to {path}";
+pub const TEMPLATE_RENAMED: &str = "Renamed template {name} to {new_name} at {path}";
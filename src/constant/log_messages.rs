@@ -0,0 +1,43 @@
+//! Constants for log messages to be displayed on verbose runs.
+
+/// Name of the level of warning log lines, written unless on quiet runs.
+pub const WARNING_LEVEL: &str = "warning";
+
+/// Log line prefixing given message with its verbosity level.
+pub const LOG_LINE: &str = "[{level}] {message}";
+
+/// Resolved cli args.
+pub const RESOLVED_ARGS: &str = "Resolved cli args: {args}";
+
+/// Template directory given by the template directory env var.
+pub const TEMPLATE_DIR_FROM_ENV: &str = "Using template directory {path} (from {name} env var)";
+
+/// Default template directory, the template directory env var being unset.
+pub const DEFAULT_TEMPLATE_DIR: &str =
+    "Using default template directory {path} ({name} env var not set)";
+
+/// HTTP call answered by the server.
+pub const HTTP_RESPONSE: &str = "GET {url} -> {status} in {elapsed} ms";
+
+/// HTTP call that got no answer from the server.
+pub const HTTP_FAILURE: &str = "GET {url} failed in {elapsed} ms: {error}";
+
+/// HTTP response served from the response cache.
+pub const CACHE_HIT: &str = "Serving {url} from cache (not modified)";
+
+/// HTTP response that could not be written into the response cache.
+pub const CACHE_STORE_FAILURE: &str = "Failed to cache response of {url}: {error}";
+
+/// Template names routed to a template source.
+pub const TEMPLATES_ROUTED: &str = "Routing {templates} to {source} source";
+
+/// Template names skipped by a template source, as served by another one
+/// with precedence.
+pub const TEMPLATES_OVERRIDDEN: &str =
+    "Skipping {templates} from {source} source, overridden by {precedence} precedence";
+
+/// Output printed through the pager, exceeding the terminal height.
+pub const PAGING_OUTPUT: &str = "Paging {lines} lines through {pager} ({height} lines terminal)";
+
+/// Pager that could not be run, output being printed directly instead.
+pub const PAGER_FAILURE: &str = "Failed to run pager {pager}, printing directly: {error}";
@@ -1,5 +1,33 @@
 //! Constants for file or directory path.
 
+/// Path to the gitignore file of the current directory.
+pub const GITIGNORE_FILE: &str = ".gitignore";
+
+/// Name of the directory holding the git repository data, never walked
+/// through.
+pub const GIT_DIRECTORY: &str = ".git";
+
+/// Names of files usually tracked by version control (e.g. lockfiles and
+/// manifests), which are worth a warning when ignored.
+pub const TRACKED_LOOKING_FILES: [&str; 16] = [
+    "Cargo.lock",
+    "Cargo.toml",
+    "Gemfile.lock",
+    "Pipfile.lock",
+    "composer.lock",
+    "go.mod",
+    "go.sum",
+    "package-lock.json",
+    "package.json",
+    "pnpm-lock.yaml",
+    "poetry.lock",
+    "pyproject.toml",
+    "yarn.lock",
+    "Makefile",
+    "README.md",
+    "LICENSE",
+];
+
 /// Path to directory containing test output expectations.
 pub const TEST_EXPECTATIONS: &str = "tests/expected";
 
@@ -1,5 +1,4 @@
 //! Constants for gitignore template manager service.
-use crate::helper::TimeoutUnit;
 
 /// Env variable name pointing to local template directory
 pub const HOME_ENV_VAR: &str = "GITIGNORE_TEMPLATE_GENERATOR_HOME";
@@ -7,7 +6,18 @@ pub const HOME_ENV_VAR: &str = "GITIGNORE_TEMPLATE_GENERATOR_HOME";
 /// Default local template directory
 pub const DEFAULT_HOME: &str = ".gitignore_template_generator";
 
-pub const DEFAULT_TEMPLATE_DIR: &str = "{home_path}/.gitignore_template_generator/templates";
+pub const DEFAULT_TEMPLATE_DIR: &str = "{home}/.gitignore_template_generator/templates";
+
+/// Env variable name pointing to the base directory of user-specific cache
+/// files, as defined by the XDG Base Directory specification.
+pub const CACHE_HOME_ENV_VAR: &str = "XDG_CACHE_HOME";
+
+/// Default base directory of user-specific cache files.
+pub const DEFAULT_CACHE_HOME: &str = "{home}/.cache";
+
+/// Directory of cached service responses, within the base directory of
+/// user-specific cache files.
+pub const CACHE_DIR: &str = "{cache_home}/gitignore-template-generator";
 
 /// Template manager service base URL.
 pub const BASE_URL: &str = "https://www.toptal.com";
@@ -24,25 +34,56 @@ pub const GENERATOR_URI: &str = "/developers/gitignore/api";
 /// API call.
 pub const LISTER_URI: &str = "/developers/gitignore/api/list";
 
-/// Timeout in seconds for HTTP calls to generator/lister service (str version).
-pub const TIMEOUT: &str = "5";
+/// Default dialect spoken by the template manager service.
+pub const API_DIALECT: &str = "toptal";
+
+/// Default policy for templates served by both local and remote sources.
+pub const PREFER: &str = "both";
+
+/// Default policy for the order of generated templates.
+pub const ORDER: &str = "source";
+
+/// Default format of the header of each section of generated templates.
+pub const SECTION_HEADER: &str = "## {source}";
+
+/// Default format of the title of each local template.
+pub const TEMPLATE_TITLE: &str = "### *{name} ###";
 
-/// Timeout in milliseconds for HTTP calls to generator/lister service (str version).
-pub const TIMEOUT_MILLISECOND: &str = "5000";
+/// Default policy for the newline ending generated templates.
+pub const TRAILING_NEWLINE: &str = "always";
 
-/// Timeout in seconds for HTTP calls to generator/lister service (integer version).
+/// Default policy for styling output with colors.
+pub const COLOR: &str = "auto";
+
+/// Env var disabling colors when set to a non-empty value, unless colors
+/// are forced (see <https://no-color.org>).
+pub const NO_COLOR_ENV_VAR: &str = "NO_COLOR";
+
+/// Total deadline for HTTP calls to generator/lister service (str version).
+pub const TIMEOUT: &str = "5s";
+
+/// Total deadline in seconds for HTTP calls to generator/lister service
+/// (integer version).
 pub const TIMEOUT_INT: u64 = 5;
 
-/// Timeout milliseconds for HTTP calls to generator/lister service (integer version).
-pub const TIMEOUT_MILLISECOND_INT: u64 = 5000;
+/// Default file extension of local templates.
+pub const TEMPLATE_EXTENSION: &str = "txt";
 
-/// Second timeout unit (str version).
-pub const TIMEOUT_UNIT: &str = "second";
+/// Env var naming the editor used to write local templates.
+pub const EDITOR_ENV_VAR: &str = "EDITOR";
 
-/// Millisecond timeout unit (str version).
-pub const TIMEOUT_MILLISECOND_UNIT: &str = "millisecond";
+/// Fallback editor used to write local templates when `EDITOR` is not set.
+pub const DEFAULT_EDITOR: &str = "vi";
 
-/// Second timeout unit (enum version).
-///
-/// `value` - TimeoutUnit::SECOND
-pub const TIMEOUT_UNIT_ENUM: TimeoutUnit = TimeoutUnit::SECOND;
+/// Env var naming the pager long output is printed through.
+pub const PAGER_ENV_VAR: &str = "PAGER";
+
+/// Fallback pager used when `PAGER` is not set.
+pub const DEFAULT_PAGER: &str = "less -R";
+
+/// Env var holding the terminal height, checked before querying the
+/// terminal itself.
+pub const LINES_ENV_VAR: &str = "LINES";
+
+/// Prefixes of the comments wrapping templates served by the remote API.
+pub const REMOTE_WRAPPER_PREFIXES: [&str; 3] = ["# Created by ", "# Edit at ", "# End of "];
@@ -1,5 +1,11 @@
 //! Define core components used to manage gitignore templates.
-use crate::{http_client::HttpClient, parser::Args};
+use std::cell::OnceCell;
+
+use clap::ValueEnum;
+
+use crate::{
+    dialect::ApiDialect, error::Error, http_client::HttpClient, layout::Layout, parser::Args,
+};
 
 mod impls;
 
@@ -18,6 +24,10 @@ pub enum ExitKind {
     /// Early program exit to print author infos.
     AuthorInfos,
 
+    /// Program exit due to a compared file differing from the generated
+    /// templates.
+    Mismatch,
+
     /// Abrupt program exit due to runtime error.
     Error,
 }
@@ -29,6 +39,37 @@ pub enum StringKind {
     Mixed,
 }
 
+/// Enum of policies for templates served by both local and remote sources.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum SourcePrecedence {
+    /// Only generate the local version of the template.
+    Local,
+
+    /// Only generate the remote version of the template.
+    Remote,
+
+    /// Generate both versions of the template, local one first.
+    Both,
+
+    /// Generate the local version of the template, completed with the rules
+    /// of the remote one it lacks.
+    Merge,
+}
+
+/// Enum of policies for the order of generated templates.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum TemplateOrder {
+    /// Order templates as given by the user.
+    Given,
+
+    /// Order templates by name.
+    Alphabetical,
+
+    /// Group templates by source, local ones first, in the order returned
+    /// by each source.
+    Source,
+}
+
 /// Template generator trait to generate string templates.
 pub trait TemplateGenerator: TemplateLister {
     /// Generates a string template matching given template names.
@@ -43,9 +84,9 @@ pub trait TemplateGenerator: TemplateLister {
     ///
     /// # Returns
     ///
-    /// A result containing the generated template on success, or a
-    /// [`ProgramExit`] on error (e.g. 4xx, network issues...).
-    fn generate(&self, template_names: &[String]) -> Result<QualifiedString, ProgramExit>;
+    /// A result containing the generated template on success, or an
+    /// [`Error`] on error (e.g. 4xx, network issues...).
+    fn generate(&self, template_names: &[String]) -> Result<QualifiedString, Error>;
 
     /// Generates a string template matching given template names, with robust
     /// template names check.
@@ -62,13 +103,13 @@ pub trait TemplateGenerator: TemplateLister {
     ///
     /// # Returns
     ///
-    /// A result containing the generated template on success, or a
-    /// [`ProgramExit`] on error (e.g. 4xx, network issues, template not
+    /// A result containing the generated template on success, or an
+    /// [`Error`] on error (e.g. 4xx, network issues, template not
     /// found...).
     fn generate_with_template_check(
         &self,
         template_names: &[String],
-    ) -> Result<QualifiedString, ProgramExit>;
+    ) -> Result<QualifiedString, Error>;
 }
 
 /// Template lister trait to list available templates.
@@ -80,17 +121,120 @@ pub trait TemplateLister {
     ///
     /// # Returns
     ///
-    /// A result containing the list of available templates on success, or a
-    /// [`ProgramExit`] on error (e.g. file system failure, insufficient
+    /// A result containing the list of available templates on success, or an
+    /// [`Error`] on error (e.g. file system failure, insufficient
     /// privilege...).
-    fn list(&self) -> Result<QualifiedString, ProgramExit>;
+    fn list(&self) -> Result<QualifiedString, Error>;
+}
+
+/// Template editor trait to manage template files.
+pub trait TemplateEditor {
+    /// Adds a new template with given content.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_name` - The name of the template to be added.
+    /// * `content` - The content of the template to be added.
+    /// * `force` - Whether to overwrite the template if it already exists.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the path of the added template file on success,
+    /// or an [`Error`] on error (e.g. template already existing while
+    /// not forced, file system failure...).
+    fn add(&self, template_name: &str, content: &str, force: bool) -> Result<String, Error>;
+
+    /// Locates an existing template.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_name` - The name of the template to be located.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the path of the template file on success, or an
+    /// [`Error`] on error (e.g. template not found, file system
+    /// failure...).
+    fn locate(&self, template_name: &str) -> Result<String, Error>;
+
+    /// Removes an existing template.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_name` - The name of the template to be removed.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the path of the removed template file on
+    /// success, or an [`Error`] on error (e.g. template not found,
+    /// file system failure...).
+    fn remove(&self, template_name: &str) -> Result<String, Error>;
+
+    /// Renames an existing template, keeping its file extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_name` - The name of the template to be renamed.
+    /// * `new_template_name` - The new name of the template.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the new path of the template file on success, or
+    /// an [`Error`] on error (e.g. template not found, new name already
+    /// taken, file system failure...).
+    fn rename(&self, template_name: &str, new_template_name: &str) -> Result<String, Error>;
 }
 
 pub trait TemplateFactory<T: TemplateManager + ?Sized> {
-    fn from_args(args: &Args) -> Result<Box<T>, ProgramExit>;
+    fn from_args(args: &Args) -> Result<Box<T>, Error>;
 }
 
-pub trait TemplateManager: TemplateGenerator {}
+/// Template manager trait to generate and list templates.
+pub trait TemplateManager: TemplateGenerator {
+    /// Generates each template matching given template names on its own,
+    /// along with the source serving it.
+    ///
+    /// Unlike [`TemplateGenerator::generate`], templates are not merged, so
+    /// that the content of each one does not depend on the other given
+    /// templates.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_names` - The template names to be generated.
+    ///
+    /// # Returns
+    ///
+    /// A result containing one template per template name and serving
+    /// source on success, or an [`Error`] on error (e.g. 4xx, network
+    /// issues, template not found...).
+    fn generate_each(&self, template_names: &[String]) -> Result<Vec<SourcedTemplate>, Error>;
+
+    /// Generates templates matching given template names, along with each
+    /// generated template on its own, so that the output can be locked
+    /// without generating templates twice.
+    ///
+    /// Behaves the same as [`TemplateGenerator::generate`], except that
+    /// each template gets generated on its own (i.e. templates served by a
+    /// remote API are each wrapped in their own comments).
+    ///
+    /// # Arguments
+    ///
+    /// * `template_names` - The template names to be generated.
+    /// * `check_template_names` - Whether to fail with a detailed error
+    ///   message if any template name is not listed, as
+    ///   [`TemplateGenerator::generate_with_template_check`] does.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the generated templates on success, or an
+    /// [`Error`] on error (e.g. 4xx, network issues, template not
+    /// found...).
+    fn generate_sourced(
+        &self,
+        template_names: &[String],
+        check_template_names: bool,
+    ) -> Result<SourcedGeneration, Error>;
+}
 
 /// DTO struct representing an early or abrupt program exit.
 #[derive(Clone, PartialEq, Debug)]
@@ -110,6 +254,36 @@ pub struct ProgramExit {
     pub kind: ExitKind,
 }
 
+/// DTO struct representing a template along with the source serving it.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SourcedTemplate {
+    /// The template name.
+    pub name: String,
+
+    /// The source serving the template.
+    pub source: StringKind,
+
+    /// The location the template is served from (i.e. the template file
+    /// path for local templates, or the url it got fetched from for remote
+    /// ones).
+    pub location: String,
+
+    /// The template content.
+    pub content: String,
+}
+
+/// DTO struct representing generated templates, along with each template
+/// on its own.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SourcedGeneration {
+    /// The generated templates, as returned by
+    /// [`TemplateGenerator::generate`].
+    pub output: QualifiedString,
+
+    /// Each generated template on its own, in output order.
+    pub templates: Vec<SourcedTemplate>,
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct QualifiedString {
     pub value: String,
@@ -121,6 +295,22 @@ pub struct QualifiedString {
 /// It can generate and list gitignore templates.
 pub struct GitignoreTemplateManager {
     template_managers: Vec<Box<dyn TemplateManager>>,
+
+    /// The layout of generated templates, used to title each section
+    /// (defaults to [`Layout::default`]).
+    layout: Layout,
+
+    /// The policy for templates served by both local and remote managers
+    /// (defaults to [`SourcePrecedence::Both`]).
+    precedence: SourcePrecedence,
+
+    /// The policy for the order of generated templates (defaults to
+    /// [`TemplateOrder::Source`]).
+    order: TemplateOrder,
+
+    /// Whether template names must match exactly, case included (defaults
+    /// to `false`).
+    strict: bool,
 }
 
 /// Manager of gitignore templates using local filesystem.
@@ -135,6 +325,25 @@ pub struct LocalGitignoreTemplateManager {
     /// are stored. Will be used in case `GITIGNORE_TEMPLATE_GENERATOR_HOME`
     /// env var is not set.
     default_template_dir: String,
+
+    /// The layout of generated templates, used to title each template
+    /// (defaults to [`Layout::default`]).
+    layout: Layout,
+
+    /// Whether template names must match exactly, case included (defaults
+    /// to `false`).
+    strict: bool,
+
+    /// The file extensions of templates (defaults to
+    /// [`constant::template_manager::TEMPLATE_EXTENSION`]).
+    extensions: Vec<String>,
+
+    /// The patterns of templates excluded from listing and generation
+    /// (defaults to none).
+    excluded: Vec<String>,
+
+    /// The template directory, resolved on first use.
+    template_dir: OnceCell<String>,
 }
 
 /// Manager of gitignore templates using remote API.
@@ -152,4 +361,20 @@ pub struct RemoteGitignoreTemplateManager {
     /// The endpoint URI to list templates (defaults to
     /// [`crate::constant::template_manager::LISTER_URI`] if None).
     lister_endpoint_uri: String,
+
+    /// The dialect spoken by the remote API (defaults to
+    /// [`crate::dialect::ToptalDialect`]).
+    api_dialect: Box<dyn ApiDialect>,
+
+    /// The base url of the remote API, only used to record where templates
+    /// got served from (defaults to an empty string).
+    server_url: String,
+
+    /// The policy for the order of generated templates (defaults to
+    /// [`TemplateOrder::Source`], leaving the order up to the remote API).
+    order: TemplateOrder,
+
+    /// Whether template names must match exactly, case included (defaults
+    /// to `false`).
+    strict: bool,
 }
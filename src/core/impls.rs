@@ -1,66 +1,91 @@
-use std::{collections::HashSet, io::ErrorKind, time::Duration};
+use std::{
+    cell::OnceCell,
+    collections::HashSet,
+    io::ErrorKind,
+    path::{Component, Path},
+};
 
-use clap::Error;
+use clap::ValueEnum;
 
 use super::{
     ExitKind, ProgramExit, QualifiedString, StringKind, TemplateGenerator, TemplateLister,
 };
 use crate::{
+    api::{GeneratorBuilder, TemplateSources},
     constant::{
-        self, error_messages, exit_status, help_texts,
-        template_manager::{DEFAULT_TEMPLATE_DIR, GENERATOR_URI, HOME_ENV_VAR, LISTER_URI},
+        self, error_messages, exit_status, help_texts, log_messages,
+        template_manager::{GENERATOR_URI, HOME_ENV_VAR, LISTER_URI},
     },
     core::{
         GitignoreTemplateManager, LocalGitignoreTemplateManager, RemoteGitignoreTemplateManager,
-        TemplateFactory, TemplateManager,
+        SourcePrecedence, SourcedGeneration, SourcedTemplate, TemplateEditor, TemplateFactory,
+        TemplateManager, TemplateOrder,
     },
+    dialect::{ApiDialect, ToptalDialect},
+    error::Error,
     fs::{DirectoryHandler, FileSystemHandler},
-    helper::{DefaultUtils, TimeoutUnit, Utils},
-    http_client::{HttpClient, UreqHttpClient},
+    helper::{DefaultUtils, Utils},
+    http_client::HttpClient,
+    layout::Layout,
+    logger::Logger,
     parser::Args,
-    printer::{Data, DataPrinter, DefaultDataPrinter},
+    verify::GitignoreFile,
 };
 
 impl ProgramExit {
-    pub fn success(message: &str, kind: &ExitKind) -> Self {
+    pub fn error(message: &str) -> Self {
         Self {
             message: message.to_string(),
-            exit_status: exit_status::SUCCESS,
+            exit_status: exit_status::GENERIC,
             styled_message: None,
-            kind: kind.clone(),
+            kind: ExitKind::Error,
         }
     }
+}
 
-    pub fn styled_success(message: &str, styled_message: &str, kind: &ExitKind) -> Self {
-        Self {
-            message: message.to_string(),
-            exit_status: exit_status::SUCCESS,
-            styled_message: Some(styled_message.to_string()),
-            kind: kind.clone(),
+impl StringKind {
+    /// Gets the lowercase name of this kind (e.g. `local`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Remote => "remote",
+            Self::Mixed => "mixed",
         }
     }
+}
 
-    pub fn error(message: &str) -> Self {
-        Self {
-            message: message.to_string(),
-            exit_status: exit_status::GENERIC,
-            styled_message: None,
-            kind: ExitKind::Error,
-        }
+impl SourcedTemplate {
+    /// Formats this template content along with its metadata (i.e. source,
+    /// location, size in bytes and rule count).
+    pub fn describe(&self) -> String {
+        help_texts::TEMPLATE_DETAILS
+            .replace("{name}", &self.name)
+            .replace("{source}", self.source.name())
+            .replace("{location}", &self.location)
+            .replace("{size}", &self.content.len().to_string())
+            .replace(
+                "{rules}",
+                &GitignoreFile::parse(&self.content).rules.len().to_string(),
+            )
+            .replace("{content}", &self.content)
     }
+}
 
-    pub fn from_clap_error(error: &Error) -> Self {
+impl SourcedGeneration {
+    /// Joins given templates, all served by given source, into one output.
+    fn join(templates: Vec<SourcedTemplate>, kind: StringKind) -> Self {
         Self {
-            message: DefaultDataPrinter::ppg(&Data::ClapError(error)),
-            exit_status: error.exit_code(),
-            styled_message: Some(DefaultDataPrinter::ppg(&Data::StyledClapError(error))),
-            kind: ExitKind::Error,
+            output: QualifiedString {
+                value: templates
+                    .iter()
+                    .map(|template| template.content.as_str())
+                    .collect::<Vec<&str>>()
+                    .join("\n\n"),
+                kind,
+            },
+            templates,
         }
     }
-
-    pub fn invalid_mock_uri(uri: &str) -> Self {
-        Self::error(&error_messages::INVALID_MAPPED_URI.replace("{uri}", uri))
-    }
 }
 
 impl QualifiedString {
@@ -74,14 +99,78 @@ impl QualifiedString {
 
 impl GitignoreTemplateManager {
     pub fn new(template_managers: Vec<Box<dyn TemplateManager>>) -> Self {
-        Self { template_managers }
+        Self {
+            template_managers,
+            layout: Layout::default(),
+            precedence: SourcePrecedence::Both,
+            order: TemplateOrder::Source,
+            strict: false,
+        }
+    }
+
+    /// Sets whether template names must match exactly, case included.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether to disable case-insensitive matching.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets the policy for the order of generated templates.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The policy to be applied when generating templates.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_order(mut self, order: TemplateOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the policy for templates served by both local and remote
+    /// managers.
+    ///
+    /// # Arguments
+    ///
+    /// * `precedence` - The policy to be applied when generating templates.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_precedence(mut self, precedence: SourcePrecedence) -> Self {
+        self.precedence = precedence;
+        self
+    }
+
+    /// Sets the layout of generated templates.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The layout to be used to title each section.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
     }
 
     fn explode_and_merge_template_results(
-        template_results: &[Result<QualifiedString, ProgramExit>],
-    ) -> Result<QualifiedString, Vec<ProgramExit>> {
+        &self,
+        template_results: &[Result<QualifiedString, Error>],
+    ) -> Result<QualifiedString, Error> {
         let mut result: String = String::new();
-        let mut errors: Vec<ProgramExit> = Vec::new();
+        let mut errors: Vec<Error> = Vec::new();
 
         for template_result in template_results.iter() {
             match template_result {
@@ -94,10 +183,13 @@ impl GitignoreTemplateManager {
                         continue;
                     }
 
-                    if let StringKind::Local = template.kind {
-                        result.push_str("## LOCAL\n\n");
-                    } else {
-                        result.push_str("## REMOTE\n\n");
+                    let source = match template.kind {
+                        StringKind::Local => StringKind::Local,
+                        _ => StringKind::Remote,
+                    };
+                    if let Some(section_header) = self.layout.section_header(source) {
+                        result.push_str(&section_header);
+                        result.push_str("\n\n");
                     }
 
                     result.push_str(&template.value);
@@ -107,13 +199,12 @@ impl GitignoreTemplateManager {
             }
         }
 
-        if errors.is_empty() {
-            Ok(QualifiedString {
+        match Error::aggregate(errors) {
+            Some(error) => Err(error),
+            None => Ok(QualifiedString {
                 value: result.trim_end().to_string(),
                 kind: StringKind::Mixed,
-            })
-        } else {
-            Err(errors)
+            }),
         }
     }
 
@@ -139,10 +230,10 @@ impl GitignoreTemplateManager {
     }
 
     fn explode_and_merge_list_results(
-        list_results: &[Result<QualifiedString, ProgramExit>],
-    ) -> Result<Vec<QualifiedString>, Vec<ProgramExit>> {
+        list_results: &[Result<QualifiedString, Error>],
+    ) -> Result<Vec<QualifiedString>, Error> {
         let mut lines_res: Vec<QualifiedString> = Vec::new();
-        let mut errors: Vec<ProgramExit> = Vec::new();
+        let mut errors: Vec<Error> = Vec::new();
 
         let flist_res = list_results.first().unwrap();
         match flist_res {
@@ -199,40 +290,383 @@ impl GitignoreTemplateManager {
             }
         }
 
-        if errors.is_empty() {
-            Ok(lines_res)
-        } else {
-            Err(errors)
+        match Error::aggregate(errors) {
+            Some(error) => Err(error),
+            None => Ok(lines_res),
         }
     }
 
-    fn build_error(errors: &Vec<ProgramExit>) -> ProgramExit {
-        let mut final_exit_status = 0;
-        let mut final_message = String::new();
-        let mut final_styled_message = String::new();
+    /// Logs the template names routed to the manager of given source, and
+    /// the requested ones it skips as overridden by given precedence.
+    fn log_routing(
+        routed_templates: &[String],
+        skipped_templates: &[String],
+        source: StringKind,
+        precedence: SourcePrecedence,
+    ) {
+        if !routed_templates.is_empty() {
+            Logger::verbose(
+                &log_messages::TEMPLATES_ROUTED
+                    .replace("{templates}", &routed_templates.join(", "))
+                    .replace("{source}", source.name()),
+            );
+        }
 
-        for error in errors {
-            final_exit_status += error.exit_status;
-            final_message.push_str(&error.message);
-            final_message.push('\n');
-            if let Some(styled_message) = &error.styled_message {
-                final_styled_message.push_str(styled_message);
-                final_styled_message.push('\n');
-            }
+        if !skipped_templates.is_empty() {
+            let precedence = precedence
+                .to_possible_value()
+                .map(|value| value.get_name().to_string())
+                .unwrap_or_default();
+
+            Logger::debug(
+                &log_messages::TEMPLATES_OVERRIDDEN
+                    .replace("{templates}", &skipped_templates.join(", "))
+                    .replace("{source}", source.name())
+                    .replace("{precedence}", &precedence),
+            );
         }
+    }
 
-        let final_styled_message = if final_styled_message.is_empty() {
-            None
-        } else {
-            Some(final_styled_message.trim_end().to_string())
+    /// Routes given template names to the managers listing them, and
+    /// applies given action to each manager along with its routed names and
+    /// the template lists of all managers, so that the action does not
+    /// need to list them again.
+    ///
+    /// Template names listed by both local and remote managers are routed
+    /// as told by given precedence policy, [`SourcePrecedence::Merge`]
+    /// routing them to local managers only.
+    ///
+    /// # Returns
+    ///
+    /// The action result of each manager, along with the template names
+    /// routed to a manager whose action succeeded.
+    fn route<T>(
+        &self,
+        template_names: &[String],
+        precedence: SourcePrecedence,
+        action: impl Fn(
+            &dyn TemplateManager,
+            &[String],
+            StringKind,
+            &[Result<QualifiedString, Error>],
+        ) -> Result<T, Error>,
+    ) -> (Vec<Result<T, Error>>, HashSet<String>) {
+        let template_lists: Vec<Result<QualifiedString, Error>> = self
+            .template_managers
+            .iter()
+            .map(|template_manager| template_manager.list())
+            .collect();
+        let listed_by = |source: StringKind| -> HashSet<&str> {
+            template_lists
+                .iter()
+                .flatten()
+                .filter(|list| list.kind == source)
+                .flat_map(|list| list.value.lines())
+                .collect()
         };
+        let local_templates = listed_by(StringKind::Local);
+        let remote_templates = listed_by(StringKind::Remote);
 
-        ProgramExit {
-            message: final_message.trim_end().to_string(),
-            exit_status: final_exit_status,
-            styled_message: final_styled_message,
-            kind: ExitKind::Error,
+        let mut processed_templates: HashSet<String> = HashSet::new();
+        let results = self
+            .template_managers
+            .iter()
+            .zip(template_lists.iter())
+            .map(|(template_manager, template_list)| match template_list {
+                Ok(supported_templates) => {
+                    let overridden_templates = match (precedence, supported_templates.kind) {
+                        (SourcePrecedence::Local | SourcePrecedence::Merge, StringKind::Remote) => {
+                            &local_templates
+                        }
+                        (SourcePrecedence::Remote, StringKind::Local) => &remote_templates,
+                        _ => &HashSet::new(),
+                    };
+                    let (templates_to_process, skipped_templates): (Vec<String>, Vec<String>) =
+                        supported_templates
+                            .value
+                            .lines()
+                            .filter(|line| self.is_requested(line, template_names))
+                            .map(|line| line.to_string())
+                            .partition(|line| {
+                                !overridden_templates.iter().any(|overridden_template| {
+                                    is_same_template(overridden_template, line, self.strict)
+                                })
+                            });
+                    Self::log_routing(
+                        &templates_to_process,
+                        &skipped_templates,
+                        supported_templates.kind,
+                        precedence,
+                    );
+
+                    let result = action(
+                        template_manager.as_ref(),
+                        &templates_to_process,
+                        supported_templates.kind,
+                        &template_lists,
+                    );
+                    if result.is_ok() {
+                        templates_to_process.iter().for_each(|template_name| {
+                            processed_templates.insert(template_name.to_string());
+                        });
+                    }
+                    result
+                }
+                Err(error) => Err(error.clone()),
+            })
+            .collect();
+
+        (results, processed_templates)
+    }
+
+    /// Generates given templates through given local manager, completing
+    /// each one with the rules of its remote version it lacks.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the merged templates, or an [`Error`] on
+    /// error (e.g. 4xx, network issues, file system failure...).
+    fn generate_merged(
+        &self,
+        local_manager: &dyn TemplateManager,
+        template_names: &[String],
+        template_lists: &[Result<QualifiedString, Error>],
+    ) -> Result<QualifiedString, Error> {
+        let templates = self.generate_each_merged(local_manager, template_names, template_lists)?;
+
+        Ok(SourcedGeneration::join(templates, StringKind::Local).output)
+    }
+
+    /// Generates each given template on its own through given local
+    /// manager, completing it with the rules of its remote version it
+    /// lacks.
+    ///
+    /// Remote versions are generated through the remote managers listing
+    /// them in given template lists, one per manager.
+    ///
+    /// # Returns
+    ///
+    /// A result containing one merged template per template name, or an
+    /// [`Error`] on error (e.g. 4xx, network issues, file system
+    /// failure...).
+    fn generate_each_merged(
+        &self,
+        local_manager: &dyn TemplateManager,
+        template_names: &[String],
+        template_lists: &[Result<QualifiedString, Error>],
+    ) -> Result<Vec<SourcedTemplate>, Error> {
+        let mut remote_templates: Vec<SourcedTemplate> = Vec::new();
+        for (template_manager, template_list) in self.template_managers.iter().zip(template_lists) {
+            let supported_templates = template_list.as_ref().map_err(Error::clone)?;
+            if supported_templates.kind != StringKind::Remote {
+                continue;
+            }
+
+            let templates_to_process: Vec<String> = supported_templates
+                .value
+                .lines()
+                .filter(|line| self.is_requested(line, template_names))
+                .map(|line| line.to_string())
+                .collect();
+            remote_templates.append(&mut template_manager.generate_each(&templates_to_process)?);
+        }
+
+        let templates = local_manager
+            .generate_each(template_names)?
+            .into_iter()
+            .map(|template| {
+                let local_rules = GitignoreFile::parse(&template.content).rules;
+                let mut missing_rules: Vec<&str> = Vec::new();
+                remote_templates
+                    .iter()
+                    .filter(|remote_template| {
+                        is_same_template(&remote_template.name, &template.name, self.strict)
+                    })
+                    .flat_map(|remote_template| remote_template.content.lines())
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .filter(|rule| !local_rules.contains(*rule))
+                    .for_each(|rule| {
+                        if !missing_rules.contains(&rule) {
+                            missing_rules.push(rule);
+                        }
+                    });
+
+                if missing_rules.is_empty() {
+                    template
+                } else {
+                    SourcedTemplate {
+                        content: format!(
+                            "{}\n\n{}",
+                            template.content.trim_end(),
+                            missing_rules.join("\n")
+                        ),
+                        ..template
+                    }
+                }
+            })
+            .collect();
+
+        Ok(templates)
+    }
+
+    /// Generates given templates through the managers listing them, in the
+    /// order told by the order policy.
+    ///
+    /// Unless templates are ordered by source, each manager generates its
+    /// templates each on its own at once, so that they can be ordered, then
+    /// consecutive templates served by a same source are grouped into one
+    /// result.
+    ///
+    /// # Returns
+    ///
+    /// The ordered results, followed by any errors that occurred, along with
+    /// the template names routed to a manager whose generation succeeded.
+    fn generate_in_order(
+        &self,
+        template_names: &[String],
+    ) -> (Vec<Result<QualifiedString, Error>>, HashSet<String>) {
+        if self.order == TemplateOrder::Source {
+            let (template_results, processed_templates) = self.route(
+                template_names,
+                self.precedence,
+                |template_manager, templates_to_process, source, template_lists| {
+                    self.generate_with_precedence(
+                        template_manager,
+                        templates_to_process,
+                        source,
+                        template_lists,
+                    )
+                },
+            );
+            let (mut ordered_results, mut errors): (Vec<_>, Vec<_>) =
+                template_results.into_iter().partition(Result::is_ok);
+            ordered_results.append(&mut errors);
+
+            return (ordered_results, processed_templates);
         }
+
+        let (template_results, processed_templates) = self.route(
+            template_names,
+            self.precedence,
+            |template_manager, templates_to_process, source, template_lists| {
+                self.generate_each_with_precedence(
+                    template_manager,
+                    templates_to_process,
+                    source,
+                    template_lists,
+                )
+            },
+        );
+
+        let mut templates: Vec<SourcedTemplate> = Vec::new();
+        let mut errors: Vec<Result<QualifiedString, Error>> = Vec::new();
+        for template_result in template_results {
+            match template_result {
+                Ok(mut generated_templates) => templates.append(&mut generated_templates),
+                Err(error) => errors.push(Err(error)),
+            }
+        }
+        self.order_templates(&mut templates, template_names);
+
+        let mut ordered_results = Self::group_by_source(&templates);
+        ordered_results.append(&mut errors);
+
+        (ordered_results, processed_templates)
+    }
+
+    /// Orders given templates as told by the order policy, templates
+    /// ordered by source being left as is.
+    fn order_templates(&self, templates: &mut [SourcedTemplate], template_names: &[String]) {
+        match self.order {
+            TemplateOrder::Given => templates.sort_by_key(|template| {
+                template_names
+                    .iter()
+                    .position(|name| is_same_template(&template.name, name, self.strict))
+            }),
+            TemplateOrder::Alphabetical => {
+                templates.sort_by(|first, second| first.name.cmp(&second.name))
+            }
+            TemplateOrder::Source => {}
+        }
+    }
+
+    /// Groups consecutive given templates served by a same source into one
+    /// result.
+    fn group_by_source(templates: &[SourcedTemplate]) -> Vec<Result<QualifiedString, Error>> {
+        let mut template_results: Vec<Result<QualifiedString, Error>> = Vec::new();
+        for template in templates {
+            match template_results.last_mut() {
+                Some(Ok(last_template)) if last_template.kind == template.source => {
+                    last_template.value.push_str("\n\n");
+                    last_template.value.push_str(&template.content);
+                }
+                _ => template_results.push(Ok(QualifiedString {
+                    value: template.content.clone(),
+                    kind: template.source,
+                })),
+            }
+        }
+
+        template_results
+    }
+
+    /// Generates given templates through given manager, as told by the
+    /// precedence policy.
+    fn generate_with_precedence(
+        &self,
+        template_manager: &dyn TemplateManager,
+        template_names: &[String],
+        source: StringKind,
+        template_lists: &[Result<QualifiedString, Error>],
+    ) -> Result<QualifiedString, Error> {
+        match (self.precedence, source) {
+            (SourcePrecedence::Merge, StringKind::Local) => {
+                self.generate_merged(template_manager, template_names, template_lists)
+            }
+            _ => template_manager.generate(template_names),
+        }
+    }
+
+    /// Generates each given template on its own through given manager, as
+    /// told by the precedence policy.
+    fn generate_each_with_precedence(
+        &self,
+        template_manager: &dyn TemplateManager,
+        template_names: &[String],
+        source: StringKind,
+        template_lists: &[Result<QualifiedString, Error>],
+    ) -> Result<Vec<SourcedTemplate>, Error> {
+        match (self.precedence, source) {
+            (SourcePrecedence::Merge, StringKind::Local) => {
+                self.generate_each_merged(template_manager, template_names, template_lists)
+            }
+            _ => template_manager.generate_each(template_names),
+        }
+    }
+
+    /// Tells whether given listed template name is among given template
+    /// names.
+    fn is_requested(&self, listed_template: &str, template_names: &[String]) -> bool {
+        template_names
+            .iter()
+            .any(|template_name| is_same_template(listed_template, template_name, self.strict))
+    }
+
+    fn has_unprocessed_templates(
+        &self,
+        template_names: &[String],
+        processed_templates: &HashSet<String>,
+    ) -> bool {
+        template_names.iter().any(|template_name| {
+            !processed_templates.iter().any(|processed_template| {
+                is_same_template(processed_template, template_name, self.strict)
+            })
+        })
+    }
+
+    fn unsupported_template_error() -> Error {
+        Error::TemplateNotFound(constant::error_messages::UNSUPPORTED_TEMPLATE.to_string())
     }
 
     fn postprocess_template_list_result(template_list_result: &str) -> String {
@@ -256,42 +690,172 @@ impl LocalGitignoreTemplateManager {
     pub fn new(default_template_dir: Option<String>) -> Self {
         Self {
             default_template_dir: default_template_dir.unwrap_or_default(),
+            layout: Layout::default(),
+            strict: false,
+            extensions: vec![constant::template_manager::TEMPLATE_EXTENSION.to_string()],
+            excluded: Vec::new(),
+            template_dir: OnceCell::new(),
+        }
+    }
+
+    /// Sets the file extensions of templates.
+    ///
+    /// # Arguments
+    ///
+    /// * `extensions` - The extensions of files to be read as templates.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_extensions(mut self, extensions: &[String]) -> Self {
+        self.extensions = extensions.to_vec();
+        self
+    }
+
+    /// Sets the patterns of excluded templates.
+    ///
+    /// # Arguments
+    ///
+    /// * `excluded` - The patterns of templates to be neither listed nor
+    ///   generated.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_excluded(mut self, excluded: &[String]) -> Self {
+        self.excluded = excluded.to_vec();
+        self
+    }
+
+    /// Sets whether template names must match exactly, case included.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether to disable case-insensitive matching.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets the layout of generated templates.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The layout to be used to title each template.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Resolves the template directory, preferring the one given by the
+    /// `GITIGNORE_TEMPLATE_GENERATOR_HOME` env var over the default one.
+    ///
+    /// The directory is resolved and logged once, then reused.
+    fn template_dir(&self) -> &str {
+        self.template_dir.get_or_init(|| {
+            let (template_dir, log_message) = match std::env::var(HOME_ENV_VAR) {
+                Ok(directory_path) => (directory_path, log_messages::TEMPLATE_DIR_FROM_ENV),
+                Err(_) => (
+                    self.default_template_dir.clone(),
+                    log_messages::DEFAULT_TEMPLATE_DIR,
+                ),
+            };
+
+            Logger::verbose(
+                &log_message
+                    .replace("{path}", &template_dir)
+                    .replace("{name}", HOME_ENV_VAR),
+            );
+            template_dir
+        })
+    }
+
+    /// Builds the local template manager from given args, reading its
+    /// default template directory from `$HOME`.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The parsed cli args.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the local template manager, or an
+    /// [`Error`] if `$HOME` could not be read.
+    pub fn try_from_args(args: &Args) -> Result<Self, Error> {
+        GeneratorBuilder::from(args).build_local_manager()
+    }
+
+    /// Finds the file of given template, if any.
+    fn find_template_file(
+        &self,
+        directory_handler: &DirectoryHandler,
+        template_name: &str,
+    ) -> Result<Option<String>, Error> {
+        match directory_handler.find_file(template_name) {
+            Ok(file_name) => Ok(Some(file_name)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(edition_error(template_name, error)),
         }
     }
 
+    /// Finds the file of given template, failing if there is none.
+    fn find_existing_template_file(
+        &self,
+        directory_handler: &DirectoryHandler,
+        template_name: &str,
+    ) -> Result<String, Error> {
+        self.find_template_file(directory_handler, template_name)?
+            .ok_or_else(|| {
+                Error::TemplateNotFound(
+                    error_messages::TEMPLATE_NOT_FOUND.replace("{name}", template_name),
+                )
+            })
+    }
+
+    fn directory_handler<'a>(&self, template_dir: &'a str) -> DirectoryHandler<'a> {
+        DirectoryHandler::new(template_dir)
+            .with_extensions(&self.extensions)
+            .with_excluded(&self.excluded)
+            .with_strict(self.strict)
+    }
+
     fn map_template_names_to_their_content(
+        &self,
         template_dir: &str,
         template_names: &[String],
-    ) -> Result<Vec<String>, ProgramExit> {
-        let directory_handler = DirectoryHandler::new(template_dir);
+    ) -> Result<Vec<String>, Error> {
+        let directory_handler = self.directory_handler(template_dir);
         let mut templates = Vec::new();
 
         for template_name in template_names {
-            let file_name = format!("{template_name}.txt");
-            match directory_handler.fetch_content(&file_name) {
-                Ok(template) => templates.push(format!(
-                    "### *{} ###\n{}",
-                    DefaultUtils::capitalize(template_name),
-                    template
-                )),
+            match directory_handler
+                .find_file(template_name)
+                .and_then(|file_name| directory_handler.fetch_content(&file_name))
+            {
+                Ok(template) => templates.push(match self.layout.template_title(template_name) {
+                    Some(template_title) => format!("{template_title}\n{template}"),
+                    None => template,
+                }),
+                Err(error) if error.kind() == ErrorKind::NotFound => {
+                    return Err(Error::TemplateNotFound(format!(
+                        "{}: {}",
+                        constant::error_messages::LOCAL_GENERATION,
+                        constant::error_messages::UNSUPPORTED_TEMPLATE
+                    )));
+                }
                 Err(error) => {
-                    let error_message = match error.kind() {
-                        ErrorKind::NotFound => {
-                            constant::error_messages::UNSUPPORTED_TEMPLATE.into()
-                        }
-                        _ => error.to_string(),
-                    };
-
-                    return Err(ProgramExit {
-                        message: format!(
-                            "{}: {}",
-                            constant::error_messages::LOCAL_GENERATION,
-                            error_message
-                        ),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    });
+                    return Err(Error::io(
+                        &format!("{}: {}", constant::error_messages::LOCAL_GENERATION, error),
+                        error,
+                    ));
                 }
             };
         }
@@ -301,6 +865,39 @@ impl LocalGitignoreTemplateManager {
 }
 
 impl RemoteGitignoreTemplateManager {
+    /// Strips the comments wrapping a template served by the remote API
+    /// (e.g. `# Created by ...`, `# End of ...`), along with its leading
+    /// title, so that it can be saved as a local template.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - A single template as generated by the remote API.
+    ///
+    /// # Returns
+    ///
+    /// The bare template content, ending with a newline.
+    pub fn strip_wrapper(template: &str) -> String {
+        let mut lines = template
+            .lines()
+            .filter(|line| {
+                !constant::template_manager::REMOTE_WRAPPER_PREFIXES
+                    .iter()
+                    .any(|prefix| line.starts_with(prefix))
+            })
+            .skip_while(|line| line.trim().is_empty())
+            .peekable();
+
+        if lines
+            .peek()
+            .is_some_and(|line| line.starts_with("### ") && line.ends_with(" ###"))
+        {
+            lines.next();
+        }
+
+        let content = lines.collect::<Vec<&str>>().join("\n");
+        format!("{}\n", content.trim())
+    }
+
     pub fn new(
         http_client: Box<dyn HttpClient>,
         generator_endpoint_uri: Option<String>,
@@ -310,19 +907,132 @@ impl RemoteGitignoreTemplateManager {
             http_client,
             generator_endpoint_uri: generator_endpoint_uri.unwrap_or(GENERATOR_URI.to_string()),
             lister_endpoint_uri: lister_endpoint_uri.unwrap_or(LISTER_URI.to_string()),
+            api_dialect: Box::new(ToptalDialect),
+            server_url: String::new(),
+            order: TemplateOrder::Source,
+            strict: false,
         }
     }
 
-    fn parse_template_list_from_api(template_list: String) -> String {
-        template_list.replace(',', "\n")
+    /// Sets whether template names must match exactly, case included.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether to disable case-insensitive matching.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets the policy for the order of generated templates.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The policy to be applied when generating templates.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_order(mut self, order: TemplateOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the base url of the remote API, only used to tell where
+    /// templates are served from (see [`TemplateManager::generate_each`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `server_url` - The base url of the remote API.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_server_url(mut self, server_url: &str) -> Self {
+        self.server_url = server_url.to_string();
+        self
+    }
+
+    /// Sets the dialect spoken by the remote API.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_dialect` - The dialect to be used to list and generate
+    ///   templates.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_api_dialect(mut self, api_dialect: Box<dyn ApiDialect>) -> Self {
+        self.api_dialect = api_dialect;
+        self
     }
 }
 
-impl TemplateManager for GitignoreTemplateManager {}
+impl TemplateManager for GitignoreTemplateManager {
+    fn generate_each(&self, template_names: &[String]) -> Result<Vec<SourcedTemplate>, Error> {
+        let (template_results, processed_templates) = self.route(
+            template_names,
+            self.precedence,
+            |template_manager, templates_to_process, source, template_lists| {
+                self.generate_each_with_precedence(
+                    template_manager,
+                    templates_to_process,
+                    source,
+                    template_lists,
+                )
+            },
+        );
+
+        if template_results.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let errors: Vec<Error> = template_results
+            .iter()
+            .filter_map(|result| result.clone().err())
+            .collect();
+        if let Some(error) = Error::aggregate(errors) {
+            return Err(error);
+        }
+
+        if self.has_unprocessed_templates(template_names, &processed_templates) {
+            return Err(Self::unsupported_template_error());
+        }
+
+        Ok(template_results.into_iter().flatten().flatten().collect())
+    }
+
+    fn generate_sourced(
+        &self,
+        template_names: &[String],
+        check_template_names: bool,
+    ) -> Result<SourcedGeneration, Error> {
+        if check_template_names {
+            ensure_available_templates(
+                &Self::postprocess_template_list_result(&self.list()?.value),
+                template_names,
+                self.strict,
+            )?;
+        }
+
+        let mut templates = self.generate_each(template_names)?;
+        self.order_templates(&mut templates, template_names);
+
+        Ok(SourcedGeneration {
+            output: self.explode_and_merge_template_results(&Self::group_by_source(&templates))?,
+            templates,
+        })
+    }
+}
 
 impl TemplateLister for GitignoreTemplateManager {
-    fn list(&self) -> Result<QualifiedString, ProgramExit> {
-        let template_list_results: Vec<Result<QualifiedString, ProgramExit>> = self
+    fn list(&self) -> Result<QualifiedString, Error> {
+        let template_list_results: Vec<Result<QualifiedString, Error>> = self
             .template_managers
             .iter()
             .map(|template_manager| template_manager.list())
@@ -351,47 +1061,22 @@ impl TemplateLister for GitignoreTemplateManager {
                     .to_string(),
                 kind: StringKind::Mixed,
             }),
-            Err(errors) => Err(Self::build_error(&errors)),
+            Err(error) => Err(error),
         }
     }
 }
 
 impl TemplateFactory<dyn TemplateManager> for GitignoreTemplateManager {
-    fn from_args(args: &Args) -> Result<Box<dyn TemplateManager>, ProgramExit> {
-        let local_manager = LocalGitignoreTemplateManager::from_args(args)?;
-        let remote_manager = RemoteGitignoreTemplateManager::from_args(args)?;
-        let managers: Vec<Box<dyn TemplateManager>> = vec![local_manager, remote_manager];
-
-        Ok(Box::new(GitignoreTemplateManager::new(managers)))
+    fn from_args(args: &Args) -> Result<Box<dyn TemplateManager>, Error> {
+        GeneratorBuilder::from(args)
+            .sources(TemplateSources::Both)
+            .build_manager()
     }
 }
 
 impl TemplateGenerator for GitignoreTemplateManager {
-    fn generate(&self, template_names: &[String]) -> Result<QualifiedString, ProgramExit> {
-        let mut processed_templates: HashSet<String> = HashSet::new();
-        let template_results: Vec<Result<QualifiedString, ProgramExit>> = self
-            .template_managers
-            .iter()
-            .map(|template_manager| match template_manager.list() {
-                Ok(supported_templates) => {
-                    let templates_to_process: Vec<String> = supported_templates
-                        .value
-                        .lines()
-                        .filter(|line| template_names.contains(&line.to_string()))
-                        .map(|line| line.to_string())
-                        .collect();
-
-                    let result = template_manager.generate(&templates_to_process);
-                    if result.is_ok() {
-                        templates_to_process.iter().for_each(|template_name| {
-                            processed_templates.insert(template_name.to_string());
-                        });
-                    }
-                    result
-                }
-                Err(error) => Err(error),
-            })
-            .collect();
+    fn generate(&self, template_names: &[String]) -> Result<QualifiedString, Error> {
+        let (template_results, processed_templates) = self.generate_in_order(template_names);
 
         if template_results.is_empty() {
             return Ok(QualifiedString {
@@ -401,55 +1086,29 @@ impl TemplateGenerator for GitignoreTemplateManager {
         }
 
         if template_results.iter().all(|result| result.clone().is_ok())
-            && !HashSet::from_iter(template_names.iter().map(|tt| tt.to_string()))
-                .difference(&processed_templates)
-                .collect::<HashSet<&String>>()
-                .is_empty()
+            && self.has_unprocessed_templates(template_names, &processed_templates)
         {
-            return Err(ProgramExit {
-                message: constant::error_messages::UNSUPPORTED_TEMPLATE.to_string(),
-                exit_status: constant::exit_status::GENERIC,
-                styled_message: None,
-                kind: ExitKind::Error,
-            });
+            return Err(Self::unsupported_template_error());
         }
 
-        match Self::explode_and_merge_template_results(&template_results) {
-            Ok(result) => Ok(result),
-            Err(errors) => Err(Self::build_error(&errors)),
-        }
+        self.explode_and_merge_template_results(&template_results)
     }
 
     fn generate_with_template_check(
         &self,
         template_names: &[String],
-    ) -> Result<QualifiedString, ProgramExit> {
+    ) -> Result<QualifiedString, Error> {
         let available_templates = self.list();
         available_templates.clone()?;
 
         let invalid_template_names = find_invalid_templates(
             &Self::postprocess_template_list_result(&available_templates.unwrap().value),
             template_names,
+            self.strict,
         );
 
         if invalid_template_names.is_empty() {
-            let template_results: Vec<Result<QualifiedString, ProgramExit>> = self
-                .template_managers
-                .iter()
-                .map(|tpl_mgr| match tpl_mgr.list() {
-                    Ok(list) => {
-                        let templates_to_process: Vec<String> = list
-                            .value
-                            .lines()
-                            .filter(|line| template_names.contains(&line.to_string()))
-                            .map(|line| line.to_string())
-                            .collect();
-
-                        tpl_mgr.generate(&templates_to_process)
-                    }
-                    Err(error) => Err(error),
-                })
-                .collect();
+            let (template_results, _) = self.generate_in_order(template_names);
 
             if template_results.is_empty() {
                 return Ok(QualifiedString {
@@ -458,32 +1117,64 @@ impl TemplateGenerator for GitignoreTemplateManager {
                 });
             }
 
-            match Self::explode_and_merge_template_results(&template_results) {
-                Ok(result) => Ok(result),
-                Err(errors) => Err(Self::build_error(&errors)),
-            }
+            self.explode_and_merge_template_results(&template_results)
         } else {
-            Err(ProgramExit {
-                message: constant::error_messages::INEXISTENT_TEMPLATE_NAMES
+            Err(Error::TemplateNotFound(
+                constant::error_messages::INEXISTENT_TEMPLATE_NAMES
                     .replace("{templates}", invalid_template_names.join(", ").as_str()),
-                exit_status: constant::exit_status::GENERIC,
-                styled_message: None,
-                kind: ExitKind::Error,
-            })
+            ))
         }
     }
 }
 
-impl TemplateManager for LocalGitignoreTemplateManager {}
+impl TemplateManager for LocalGitignoreTemplateManager {
+    fn generate_each(&self, template_names: &[String]) -> Result<Vec<SourcedTemplate>, Error> {
+        let template_dir = self.template_dir();
+        let directory_handler = self.directory_handler(template_dir);
 
-impl TemplateLister for LocalGitignoreTemplateManager {
-    fn list(&self) -> Result<QualifiedString, ProgramExit> {
-        let template_dir = match std::env::var(HOME_ENV_VAR) {
-            Ok(directory_path) => directory_path,
-            Err(_) => self.default_template_dir.clone(),
+        template_names
+            .iter()
+            .map(|template_name| {
+                let template = self.generate(std::slice::from_ref(template_name))?;
+                let file_name = directory_handler
+                    .find_file(template_name)
+                    .unwrap_or_else(|_| template_name.to_string());
+
+                Ok(SourcedTemplate {
+                    name: template_name.to_string(),
+                    source: StringKind::Local,
+                    location: format!("{template_dir}/{file_name}"),
+                    content: template.value,
+                })
+            })
+            .collect()
+    }
+
+    fn generate_sourced(
+        &self,
+        template_names: &[String],
+        check_template_names: bool,
+    ) -> Result<SourcedGeneration, Error> {
+        let template_names = if check_template_names {
+            let available_templates = self.list()?.value;
+            ensure_available_templates(&available_templates, template_names, self.strict)?;
+            canonicalize_templates(&available_templates, template_names, self.strict)
+        } else {
+            template_names.to_vec()
         };
 
-        let directory_handler = DirectoryHandler::new(&template_dir);
+        Ok(SourcedGeneration::join(
+            self.generate_each(&template_names)?,
+            StringKind::Local,
+        ))
+    }
+}
+
+impl TemplateLister for LocalGitignoreTemplateManager {
+    fn list(&self) -> Result<QualifiedString, Error> {
+        let template_dir = self.template_dir();
+
+        let directory_handler = self.directory_handler(template_dir);
         match directory_handler.list_files() {
             Ok(mut template_names) => {
                 template_names.sort();
@@ -497,41 +1188,105 @@ impl TemplateLister for LocalGitignoreTemplateManager {
                     value: String::new(),
                     kind: StringKind::Local,
                 }),
-                _ => Err(ProgramExit {
-                    message: format!("{}: {}", constant::error_messages::LOCAL_LISTING, error),
-                    exit_status: constant::exit_status::GENERIC,
-                    styled_message: None,
-                    kind: ExitKind::Error,
-                }),
+                _ => Err(Error::io(
+                    &format!("{}: {}", constant::error_messages::LOCAL_LISTING, error),
+                    error,
+                )),
             },
         }
     }
 }
 
 impl TemplateFactory<dyn TemplateManager> for LocalGitignoreTemplateManager {
-    fn from_args(_args: &Args) -> Result<Box<dyn TemplateManager>, ProgramExit> {
-        match std::env::var("HOME") {
-            Ok(home_path) => Ok(Box::new(LocalGitignoreTemplateManager::new(Some(
-                DEFAULT_TEMPLATE_DIR.replace("{home}", &home_path),
-            )))),
-            Err(error) => Err(ProgramExit {
-                message: error_messages::READ_HOME_ENV_VAR.replace("{error}", &error.to_string()),
-                exit_status: exit_status::GENERIC,
-                styled_message: None,
-                kind: ExitKind::Error,
-            }),
+    fn from_args(args: &Args) -> Result<Box<dyn TemplateManager>, Error> {
+        Ok(Box::new(Self::try_from_args(args)?))
+    }
+}
+
+impl TemplateEditor for LocalGitignoreTemplateManager {
+    fn add(&self, template_name: &str, content: &str, force: bool) -> Result<String, Error> {
+        let template_dir = self.template_dir();
+        let directory_handler = self.directory_handler(template_dir);
+
+        ensure_relative_template_name(template_name)?;
+        let file_name = match self.find_template_file(&directory_handler, template_name)? {
+            Some(file_name) if force => file_name,
+            Some(_) => {
+                return Err(Error::TemplateEdition(
+                    error_messages::TEMPLATE_ALREADY_EXISTS.replace("{name}", template_name),
+                ));
+            }
+            None => {
+                let extension = self
+                    .extensions
+                    .first()
+                    .map(|extension| extension.trim_start_matches('.'))
+                    .unwrap_or(constant::template_manager::TEMPLATE_EXTENSION);
+
+                format!("{template_name}.{extension}")
+            }
+        };
+
+        directory_handler
+            .write_content(&file_name, content)
+            .map_err(|error| edition_error(template_name, error))?;
+
+        Ok(format!("{template_dir}/{file_name}"))
+    }
+
+    fn locate(&self, template_name: &str) -> Result<String, Error> {
+        let template_dir = self.template_dir();
+        let directory_handler = self.directory_handler(template_dir);
+        let file_name = self.find_existing_template_file(&directory_handler, template_name)?;
+
+        Ok(format!("{template_dir}/{file_name}"))
+    }
+
+    fn remove(&self, template_name: &str) -> Result<String, Error> {
+        let template_dir = self.template_dir();
+        let directory_handler = self.directory_handler(template_dir);
+        let file_name = self.find_existing_template_file(&directory_handler, template_name)?;
+
+        directory_handler
+            .remove_file(&file_name)
+            .map_err(|error| edition_error(template_name, error))?;
+
+        Ok(format!("{template_dir}/{file_name}"))
+    }
+
+    fn rename(&self, template_name: &str, new_template_name: &str) -> Result<String, Error> {
+        let template_dir = self.template_dir();
+        let directory_handler = self.directory_handler(template_dir);
+        let file_name = self.find_existing_template_file(&directory_handler, template_name)?;
+
+        ensure_relative_template_name(new_template_name)?;
+        if self
+            .find_template_file(&directory_handler, new_template_name)?
+            .is_some()
+        {
+            return Err(Error::TemplateEdition(
+                error_messages::TEMPLATE_ALREADY_EXISTS.replace("{name}", new_template_name),
+            ));
         }
+
+        let new_file_name = match Path::new(&file_name).extension() {
+            Some(extension) => format!("{new_template_name}.{}", extension.to_string_lossy()),
+            None => new_template_name.to_string(),
+        };
+
+        directory_handler
+            .rename_file(&file_name, &new_file_name)
+            .map_err(|error| edition_error(template_name, error))?;
+
+        Ok(format!("{template_dir}/{new_file_name}"))
     }
 }
 
 impl TemplateGenerator for LocalGitignoreTemplateManager {
-    fn generate(&self, template_names: &[String]) -> Result<QualifiedString, ProgramExit> {
-        let template_dir = match std::env::var(HOME_ENV_VAR) {
-            Ok(directory_path) => directory_path,
-            Err(_) => self.default_template_dir.clone(),
-        };
+    fn generate(&self, template_names: &[String]) -> Result<QualifiedString, Error> {
+        let template_dir = self.template_dir();
 
-        let templates = Self::map_template_names_to_their_content(&template_dir, template_names)?;
+        let templates = self.map_template_names_to_their_content(template_dir, template_names)?;
 
         Ok(QualifiedString {
             value: templates.join("\n\n"),
@@ -542,60 +1297,100 @@ impl TemplateGenerator for LocalGitignoreTemplateManager {
     fn generate_with_template_check(
         &self,
         template_names: &[String],
-    ) -> Result<QualifiedString, ProgramExit> {
+    ) -> Result<QualifiedString, Error> {
         let available_templates = self.list();
         available_templates.clone()?;
 
+        let available_templates = available_templates.unwrap().value;
         let invalid_template_names =
-            find_invalid_templates(&available_templates.unwrap().value, template_names);
+            find_invalid_templates(&available_templates, template_names, self.strict);
 
         if invalid_template_names.is_empty() {
-            self.generate(template_names)
+            self.generate(&canonicalize_templates(
+                &available_templates,
+                template_names,
+                self.strict,
+            ))
         } else {
-            Err(ProgramExit {
-                message: constant::error_messages::INEXISTENT_TEMPLATE_NAMES
+            Err(Error::TemplateNotFound(
+                constant::error_messages::INEXISTENT_TEMPLATE_NAMES
                     .replace("{templates}", invalid_template_names.join(", ").as_str()),
-                exit_status: constant::exit_status::GENERIC,
-                styled_message: None,
-                kind: ExitKind::Error,
-            })
+            ))
         }
     }
 }
 
-impl TemplateManager for RemoteGitignoreTemplateManager {}
+impl TemplateManager for RemoteGitignoreTemplateManager {
+    fn generate_each(&self, template_names: &[String]) -> Result<Vec<SourcedTemplate>, Error> {
+        if template_names.is_empty() {
+            return Ok(Vec::new());
+        }
 
-impl TemplateLister for RemoteGitignoreTemplateManager {
-    fn list(&self) -> Result<QualifiedString, ProgramExit> {
-        match self.http_client.get(&self.lister_endpoint_uri) {
-            Ok(result) => Ok(QualifiedString {
-                value: Self::parse_template_list_from_api(result),
-                kind: StringKind::Remote,
-            }),
-            Err(error) => Err(error),
+        let templates = self.api_dialect.generate_each(
+            self.http_client.as_ref(),
+            &self.generator_endpoint_uri,
+            &self.lister_endpoint_uri,
+            template_names,
+        )?;
+
+        Ok(template_names
+            .iter()
+            .zip(templates)
+            .map(|(template_name, template)| SourcedTemplate {
+                name: template_name.to_string(),
+                source: StringKind::Remote,
+                location: format!("{}{}", self.server_url, template.uri),
+                content: template.content,
+            })
+            .collect())
+    }
+
+    fn generate_sourced(
+        &self,
+        template_names: &[String],
+        check_template_names: bool,
+    ) -> Result<SourcedGeneration, Error> {
+        let mut template_names = if check_template_names {
+            let available_templates = self.list()?.value;
+            ensure_available_templates(&available_templates, template_names, self.strict)?;
+            canonicalize_templates(&available_templates, template_names, self.strict)
+        } else {
+            template_names.to_vec()
+        };
+        if self.order == TemplateOrder::Alphabetical {
+            template_names.sort();
         }
+
+        Ok(SourcedGeneration::join(
+            self.generate_each(&template_names)?,
+            StringKind::Remote,
+        ))
+    }
+}
+
+impl TemplateLister for RemoteGitignoreTemplateManager {
+    fn list(&self) -> Result<QualifiedString, Error> {
+        let template_names = self
+            .api_dialect
+            .list(self.http_client.as_ref(), &self.lister_endpoint_uri)?;
+
+        Ok(QualifiedString {
+            value: template_names.join("\n"),
+            kind: StringKind::Remote,
+        })
     }
 }
 
 impl TemplateFactory<dyn TemplateManager> for RemoteGitignoreTemplateManager {
-    fn from_args(args: &Args) -> Result<Box<dyn TemplateManager>, ProgramExit> {
-        Ok(Box::new(Self {
-            http_client: Box::new(UreqHttpClient {
-                server_url: args.server_url.to_string(),
-                global_timeout: if args.timeout_unit == TimeoutUnit::SECOND {
-                    Some(Duration::from_secs(args.timeout))
-                } else {
-                    Some(Duration::from_millis(args.timeout))
-                },
-            }),
-            generator_endpoint_uri: args.generator_uri.clone(),
-            lister_endpoint_uri: args.lister_uri.clone(),
-        }))
+    fn from_args(args: &Args) -> Result<Box<dyn TemplateManager>, Error> {
+        GeneratorBuilder::from(args)
+            .sources(TemplateSources::Remote)
+            .build_manager()
     }
 }
 
 impl TemplateGenerator for RemoteGitignoreTemplateManager {
-    fn generate(&self, template_names: &[String]) -> Result<QualifiedString, ProgramExit> {
+    fn generate(&self, template_names: &[String]) -> Result<QualifiedString, Error> {
         if template_names.is_empty() {
             return Ok(QualifiedString {
                 value: String::new(),
@@ -603,48 +1398,159 @@ impl TemplateGenerator for RemoteGitignoreTemplateManager {
             });
         }
 
-        let path_param = template_names.join(",");
-        let full_uri = format!("{}/{path_param}", self.generator_endpoint_uri);
+        let template = match self.order {
+            TemplateOrder::Source => self.api_dialect.generate(
+                self.http_client.as_ref(),
+                &self.generator_endpoint_uri,
+                &self.lister_endpoint_uri,
+                template_names,
+            )?,
+            order => {
+                let mut ordered_names = template_names.to_vec();
+                if order == TemplateOrder::Alphabetical {
+                    ordered_names.sort();
+                }
 
-        match self.http_client.get(&full_uri) {
-            Ok(result) => Ok(QualifiedString {
-                value: result,
-                kind: StringKind::Remote,
-            }),
-            Err(error) => Err(error),
-        }
+                self.api_dialect
+                    .generate_each(
+                        self.http_client.as_ref(),
+                        &self.generator_endpoint_uri,
+                        &self.lister_endpoint_uri,
+                        &ordered_names,
+                    )?
+                    .into_iter()
+                    .map(|template| template.content)
+                    .collect::<Vec<String>>()
+                    .join("\n\n")
+            }
+        };
+
+        Ok(QualifiedString {
+            value: template,
+            kind: StringKind::Remote,
+        })
     }
 
     fn generate_with_template_check(
         &self,
         template_names: &[String],
-    ) -> Result<QualifiedString, ProgramExit> {
+    ) -> Result<QualifiedString, Error> {
         let available_templates = self.list();
         available_templates.clone()?;
 
+        let available_templates = available_templates.unwrap().value;
         let invalid_template_names =
-            find_invalid_templates(&available_templates.unwrap().value, template_names);
+            find_invalid_templates(&available_templates, template_names, self.strict);
 
         if invalid_template_names.is_empty() {
-            self.generate(template_names)
+            self.generate(&canonicalize_templates(
+                &available_templates,
+                template_names,
+                self.strict,
+            ))
         } else {
-            Err(ProgramExit {
-                message: constant::error_messages::INEXISTENT_TEMPLATE_NAMES
+            Err(Error::TemplateNotFound(
+                constant::error_messages::INEXISTENT_TEMPLATE_NAMES
                     .replace("{templates}", invalid_template_names.join(", ").as_str()),
-                exit_status: constant::exit_status::GENERIC,
-                styled_message: None,
-                kind: ExitKind::Error,
-            })
+            ))
         }
     }
 }
 
-fn find_invalid_templates(available: &str, provided: &[String]) -> Vec<String> {
-    let available_set: HashSet<&str> = available.lines().collect();
-
+fn find_invalid_templates(available: &str, provided: &[String], strict: bool) -> Vec<String> {
     provided
         .iter()
-        .filter(|name| !available_set.contains(name.as_str()))
+        .filter(|name| {
+            !available
+                .lines()
+                .any(|line| is_same_template(line, name, strict))
+        })
         .map(|name| name.into())
         .collect()
 }
+
+/// Ensures each provided template name is among available ones.
+///
+/// # Returns
+///
+/// An empty result, or an [`Error`] listing the template names not
+/// available.
+fn ensure_available_templates(
+    available: &str,
+    provided: &[String],
+    strict: bool,
+) -> Result<(), Error> {
+    let invalid_template_names = find_invalid_templates(available, provided, strict);
+
+    if invalid_template_names.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::TemplateNotFound(
+            constant::error_messages::INEXISTENT_TEMPLATE_NAMES
+                .replace("{templates}", invalid_template_names.join(", ").as_str()),
+        ))
+    }
+}
+
+/// Replaces each provided template name by the matching available one, so
+/// that templates are generated and titled using their canonical name.
+fn canonicalize_templates(available: &str, provided: &[String], strict: bool) -> Vec<String> {
+    provided
+        .iter()
+        .map(|name| {
+            available
+                .lines()
+                .find(|line| line == name)
+                .or_else(|| {
+                    available
+                        .lines()
+                        .find(|line| is_same_template(line, name, strict))
+                })
+                .unwrap_or(name)
+                .to_string()
+        })
+        .collect()
+}
+
+/// Tells whether given template names designate the same template, ignoring
+/// case unless strict. A nested template (e.g. `languages/rust`) is also
+/// designated by its bare name (e.g. `rust`).
+fn is_same_template(first: &str, second: &str, strict: bool) -> bool {
+    let is_equal = |first: &str, second: &str| {
+        if strict {
+            first == second
+        } else {
+            first.eq_ignore_ascii_case(second)
+        }
+    };
+    let bare_name = |name: &'_ str| name.rsplit('/').next().unwrap_or_default().to_string();
+
+    is_equal(first, second)
+        || (!first.contains('/') && is_equal(first, &bare_name(second)))
+        || (!second.contains('/') && is_equal(&bare_name(first), second))
+}
+
+/// Ensures given template name designates a file inside the template
+/// directory (i.e. neither absolute nor going up the directory tree).
+fn ensure_relative_template_name(template_name: &str) -> Result<(), Error> {
+    let is_relative = Path::new(template_name)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+
+    if is_relative {
+        Ok(())
+    } else {
+        Err(Error::TemplateEdition(
+            error_messages::TEMPLATE_OUTSIDE_DIR.replace("{name}", template_name),
+        ))
+    }
+}
+
+fn edition_error(template_name: &str, error: std::io::Error) -> Error {
+    Error::io(
+        &error_messages::LOCAL_EDITION
+            .replace("{name}", template_name)
+            .replace("{error}", &error.to_string()),
+        error,
+    )
+}
@@ -12,7 +12,8 @@ use super::*;
 use crate::{
     constant,
     core::{LocalGitignoreTemplateManager, RemoteGitignoreTemplateManager},
-    http_client::{MockEndpointHttpClient, MockHttpClient},
+    http_client::{HttpError, HttpResponse, MockEndpointHttpClient, MockHttpClient},
+    layout::Layout,
     test_helper::{DefaultTestUtils, EnvTestContext, TestUtils},
 };
 
@@ -89,6 +90,57 @@ mod local_gitignore_template_manager {
                 let actual_template = actual_template.unwrap();
                 assert_eq!(actual_template, expected_template);
             }
+
+            #[rstest]
+            #[serial]
+            fn it_generates_nested_template_by_its_bare_name(_ctx: EnvTestContext) {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let template_names = DefaultTestUtils::to_string_list("rust vim");
+                let generator = LocalGitignoreTemplateManager::new(Some(template_dir.clone()))
+                    .with_extensions(&DefaultTestUtils::to_string_list("txt gitignore"));
+
+                let expected_template = QualifiedString {
+                    value: format!(
+                        "### *Rust ###\n{}\n\n### *Vim ###\n{}",
+                        DefaultTestUtils::load_resource_file(
+                            "categorized_templates/languages/rust.txt"
+                        ),
+                        DefaultTestUtils::load_resource_file(
+                            "categorized_templates/tools/vim.gitignore"
+                        ),
+                    ),
+                    kind: StringKind::Local,
+                };
+                let actual_template = generator.generate(&template_names);
+
+                assert!(actual_template.is_ok());
+
+                let actual_template = actual_template.unwrap();
+                assert_eq!(actual_template, expected_template);
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_titles_templates_using_given_layout(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let template_names = DefaultTestUtils::to_string_list("rust");
+                let generator = LocalGitignoreTemplateManager::new(Some(template_dir.clone()))
+                    .with_layout(Layout {
+                        template_title: String::from("# {name} ({source})"),
+                        ..Layout::default()
+                    });
+
+                let actual_template = generator.generate(&template_names);
+
+                assert!(actual_template.is_ok());
+                assert!(
+                    actual_template
+                        .unwrap()
+                        .value
+                        .starts_with("# Rust (LOCAL)\n# Generated by Cargo\n")
+                );
+            }
         }
 
         mod failure {
@@ -111,7 +163,9 @@ mod local_gitignore_template_manager {
                     styled_message: None,
                     kind: ExitKind::Error,
                 };
-                let actual_error = generator.generate(&template_names);
+                let actual_error = generator
+                    .generate(&template_names)
+                    .map_err(ProgramExit::from);
 
                 assert!(actual_error.is_err());
 
@@ -135,7 +189,9 @@ mod local_gitignore_template_manager {
                     styled_message: None,
                     kind: ExitKind::Error,
                 };
-                let actual_error = generator.generate(&template_names);
+                let actual_error = generator
+                    .generate(&template_names)
+                    .map_err(ProgramExit::from);
 
                 assert!(actual_error.is_err());
 
@@ -212,7 +268,9 @@ mod local_gitignore_template_manager {
                     styled_message: None,
                     kind: ExitKind::Error,
                 };
-                let actual_error = generator.generate_with_template_check(&template_names);
+                let actual_error = generator
+                    .generate_with_template_check(&template_names)
+                    .map_err(ProgramExit::from);
 
                 assert!(actual_error.is_err());
 
@@ -243,7 +301,9 @@ mod local_gitignore_template_manager {
                     styled_message: None,
                     kind: ExitKind::Error,
                 };
-                let actual_error = generator.generate_with_template_check(&template_names);
+                let actual_error = generator
+                    .generate_with_template_check(&template_names)
+                    .map_err(ProgramExit::from);
 
                 assert!(actual_error.is_err());
 
@@ -299,6 +359,27 @@ mod local_gitignore_template_manager {
                 assert_eq!(actual_list, expected_list);
             }
 
+            #[rstest]
+            #[serial]
+            fn it_lists_nested_templates_except_excluded_ones(_ctx: EnvTestContext) {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let lister = LocalGitignoreTemplateManager::new(Some(template_dir.clone()))
+                    .with_extensions(&DefaultTestUtils::to_string_list("txt gitignore"))
+                    .with_excluded(&DefaultTestUtils::to_string_list("drafts/*"));
+
+                let expected_list = QualifiedString {
+                    value: "languages/rust\ntools/vim".to_string(),
+                    kind: StringKind::Local,
+                };
+                let actual_list = lister.list();
+
+                assert!(actual_list.is_ok());
+
+                let actual_list = actual_list.unwrap();
+                assert_eq!(actual_list, expected_list);
+            }
+
             #[rstest]
             #[serial]
             fn it_returns_empty_string_when_inexistent_dir(_ctx: EnvTestContext) {
@@ -336,7 +417,7 @@ mod local_gitignore_template_manager {
                     styled_message: None,
                     kind: ExitKind::Error,
                 };
-                let actual_error = lister.list();
+                let actual_error = lister.list().map_err(ProgramExit::from);
 
                 assert!(actual_error.is_err());
 
@@ -345,6 +426,143 @@ mod local_gitignore_template_manager {
             }
         }
     }
+
+    mod template_editor {
+        use super::*;
+
+        fn temp_template_dir(name: &str) -> String {
+            let template_dir = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_editor_{name}_{}",
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&template_dir);
+
+            template_dir.to_string_lossy().to_string()
+        }
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[serial]
+            fn it_adds_template_with_first_extension(_ctx: EnvTestContext) {
+                let template_dir = temp_template_dir("add");
+                let editor = LocalGitignoreTemplateManager::new(Some(template_dir.clone()))
+                    .with_extensions(&DefaultTestUtils::to_string_list("gitignore txt"));
+
+                let actual_path = editor.add("tools/vim", "*.swp", false);
+
+                assert_eq!(
+                    actual_path,
+                    Ok(format!("{template_dir}/tools/vim.gitignore"))
+                );
+                assert_eq!(
+                    editor.generate(&DefaultTestUtils::to_string_list("vim")),
+                    Ok(QualifiedString {
+                        value: String::from("### *Vim ###\n*.swp"),
+                        kind: StringKind::Local,
+                    })
+                );
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_overwrites_existing_template_when_forced(_ctx: EnvTestContext) {
+                let template_dir = temp_template_dir("force");
+                let editor = LocalGitignoreTemplateManager::new(Some(template_dir.clone()))
+                    .with_layout(Layout {
+                        headers: false,
+                        ..Layout::default()
+                    });
+                editor.add("tools/vim", "*.swp", false).unwrap();
+
+                let actual_path = editor.add("vim", "*.swo", true);
+
+                assert_eq!(actual_path, Ok(format!("{template_dir}/tools/vim.txt")));
+                assert_eq!(
+                    editor.generate(&DefaultTestUtils::to_string_list("vim")),
+                    Ok(QualifiedString {
+                        value: String::from("*.swo"),
+                        kind: StringKind::Local,
+                    })
+                );
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_renames_template_keeping_its_extension(_ctx: EnvTestContext) {
+                let template_dir = temp_template_dir("rename");
+                let editor = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                editor.add("vim", "*.swp", false).unwrap();
+
+                let actual_path = editor.rename("vim", "tools/vim");
+
+                assert_eq!(actual_path, Ok(format!("{template_dir}/tools/vim.txt")));
+                assert_eq!(editor.list().unwrap().value, String::from("tools/vim"));
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_removes_template_by_its_bare_name(_ctx: EnvTestContext) {
+                let template_dir = temp_template_dir("remove");
+                let editor = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                editor.add("tools/vim", "*.swp", false).unwrap();
+
+                let actual_path = editor.remove("vim");
+
+                assert_eq!(actual_path, Ok(format!("{template_dir}/tools/vim.txt")));
+                assert_eq!(editor.list().unwrap().value, String::new());
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[rstest]
+            #[serial]
+            fn it_fails_adding_already_existing_template(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let editor = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+
+                let expected_error = ProgramExit::error(
+                    &constant::error_messages::TEMPLATE_ALREADY_EXISTS.replace("{name}", "rust"),
+                );
+                let actual_error = editor
+                    .add("rust", "target/", false)
+                    .map_err(ProgramExit::from);
+
+                assert_eq!(actual_error, Err(expected_error));
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_fails_removing_inexistent_template(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let editor = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+
+                let expected_error = ProgramExit::error(
+                    &constant::error_messages::TEMPLATE_NOT_FOUND.replace("{name}", "vim"),
+                );
+                let actual_error = editor.remove("vim").map_err(ProgramExit::from);
+
+                assert_eq!(actual_error, Err(expected_error));
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_fails_renaming_template_outside_template_dir(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let editor = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+
+                let expected_error = ProgramExit::error(
+                    &constant::error_messages::TEMPLATE_OUTSIDE_DIR.replace("{name}", "../rust"),
+                );
+                let actual_error = editor.rename("rust", "../rust").map_err(ProgramExit::from);
+
+                assert_eq!(actual_error, Err(expected_error));
+            }
+        }
+    }
 }
 
 mod remote_gitignore_template_manager {
@@ -361,9 +579,7 @@ mod remote_gitignore_template_manager {
             fn it_generates_template_using_provided_client() {
                 let template_names = DefaultTestUtils::to_string_list("rust python");
                 let generated_template = "all good";
-                let http_client = MockHttpClient {
-                    response: Ok(String::from(generated_template)),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok(generated_template)));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(
@@ -379,7 +595,7 @@ mod remote_gitignore_template_manager {
                 );
 
                 let actual = generator.generate(&template_names);
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: String::from(generated_template),
                     kind: StringKind::Remote,
                 });
@@ -387,14 +603,38 @@ mod remote_gitignore_template_manager {
                 assert_eq!(actual, expected);
             }
 
+            #[test]
+            #[parallel]
+            fn it_generates_each_template_in_alphabetical_order() {
+                let template_names = DefaultTestUtils::to_string_list("rust python");
+                let http_client = MockEndpointHttpClient::new(HashMap::from([(
+                    format!("{}/python,rust", constant::template_manager::GENERATOR_URI),
+                    Ok(HttpResponse::ok(
+                        "# Created by api\n\n### Rust ###\ntarget/\n\n### Python ###\n__pycache__/\n\n# End of api",
+                    )),
+                )]));
+                let generator = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                )
+                .with_order(TemplateOrder::Alphabetical);
+
+                let actual = generator.generate(&template_names);
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
+                    value: String::from("### Python ###\n__pycache__/\n\n### Rust ###\ntarget/"),
+                    kind: StringKind::Remote,
+                });
+
+                assert_eq!(actual, expected);
+            }
+
             #[test]
             #[parallel]
             fn it_works_with_none_endpoint_uri() {
                 let template_names = DefaultTestUtils::to_string_list("rust python");
                 let generated_template = "all good";
-                let http_client = MockHttpClient {
-                    response: Ok(String::from(generated_template)),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok(generated_template)));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     None,
@@ -402,7 +642,7 @@ mod remote_gitignore_template_manager {
                 );
 
                 let actual = generator.generate(&template_names);
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: String::from(generated_template),
                     kind: StringKind::Remote,
                 });
@@ -413,9 +653,7 @@ mod remote_gitignore_template_manager {
             #[test]
             #[parallel]
             fn it_generates_empty_template_when_no_template_names() {
-                let http_client = MockHttpClient {
-                    response: Ok(String::from("all good")),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok("all good")));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     None,
@@ -443,24 +681,21 @@ mod remote_gitignore_template_manager {
             fn it_propagates_error_from_client_if_any() {
                 let template_names = DefaultTestUtils::to_string_list("rust pyth");
                 let error_message = "all bad";
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from(error_message),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from(error_message))));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
                     Some(constant::template_manager::LISTER_URI.to_string()),
                 );
 
-                let actual = generator.generate(&template_names);
+                let actual = generator
+                    .generate(&template_names)
+                    .map_err(ProgramExit::from);
                 let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: String::from(error_message),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", error_message),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
@@ -483,15 +718,13 @@ mod remote_gitignore_template_manager {
                 let generated_template = "all good";
                 let generator_url =
                     format!("{}/rust,python", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from(generated_template))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from("rust\npython")),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok(generated_template))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("rust\npython")),
+                    ),
+                ]));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
@@ -499,7 +732,7 @@ mod remote_gitignore_template_manager {
                 );
 
                 let actual = generator.generate_with_template_check(&template_names);
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: String::from(generated_template),
                     kind: StringKind::Remote,
                 });
@@ -514,15 +747,13 @@ mod remote_gitignore_template_manager {
                 let generated_template = "all good";
                 let generator_url =
                     format!("{}/rust,python", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from(generated_template))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from("rust\npython")),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok(generated_template))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("rust\npython")),
+                    ),
+                ]));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     None,
@@ -530,7 +761,7 @@ mod remote_gitignore_template_manager {
                 );
 
                 let actual = generator.generate_with_template_check(&template_names);
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: String::from(generated_template),
                     kind: StringKind::Remote,
                 });
@@ -545,15 +776,13 @@ mod remote_gitignore_template_manager {
                 let generated_template = "all good";
                 let generator_url =
                     format!("{}/rust,python", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from(generated_template))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from("rust\npython")),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok(generated_template))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("rust\npython")),
+                    ),
+                ]));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
@@ -561,7 +790,7 @@ mod remote_gitignore_template_manager {
                 );
 
                 let actual = generator.generate_with_template_check(&template_names);
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: String::from(generated_template),
                     kind: StringKind::Remote,
                 });
@@ -576,20 +805,18 @@ mod remote_gitignore_template_manager {
                 let generated_template = "all good";
                 let generator_url =
                     format!("{}/rust,python", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from(generated_template))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from("rust\npython")),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok(generated_template))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("rust\npython")),
+                    ),
+                ]));
                 let generator =
                     RemoteGitignoreTemplateManager::new(Box::new(http_client), None, None);
 
                 let actual = generator.generate_with_template_check(&template_names);
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: String::from(generated_template),
                     kind: StringKind::Remote,
                 });
@@ -608,33 +835,29 @@ mod remote_gitignore_template_manager {
                 let error_message = "all bad";
                 let generator_url =
                     format!("{}/rust,python", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (
-                            generator_url,
-                            Err(ProgramExit {
-                                message: String::from(error_message),
-                                exit_status: constant::exit_status::GENERIC,
-                                styled_message: None,
-                                kind: ExitKind::Error,
-                            }),
-                        ),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from("rust\npython")),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (
+                        generator_url,
+                        Err(HttpError::Transport(String::from(error_message))),
+                    ),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("rust\npython")),
+                    ),
+                ]));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
                     Some(constant::template_manager::LISTER_URI.to_string()),
                 );
 
-                let actual = generator.generate_with_template_check(&template_names);
+                let actual = generator
+                    .generate_with_template_check(&template_names)
+                    .map_err(ProgramExit::from);
                 let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: String::from(error_message),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", error_message),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
@@ -650,30 +873,26 @@ mod remote_gitignore_template_manager {
                 let generated_template = "all good";
                 let generator_url =
                     format!("{}/rust,python", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from(generated_template))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Err(ProgramExit {
-                                message: String::from(error_message),
-                                exit_status: constant::exit_status::GENERIC,
-                                styled_message: None,
-                                kind: ExitKind::Error,
-                            }),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok(generated_template))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Err(HttpError::Transport(String::from(error_message))),
+                    ),
+                ]));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
                     Some(constant::template_manager::LISTER_URI.to_string()),
                 );
 
-                let actual = generator.generate_with_template_check(&template_names);
+                let actual = generator
+                    .generate_with_template_check(&template_names)
+                    .map_err(ProgramExit::from);
                 let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: String::from(error_message),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", error_message),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
@@ -689,22 +908,22 @@ mod remote_gitignore_template_manager {
                 let generator_url =
                     format!("{}/rust,python", constant::template_manager::GENERATOR_URI);
                 let template_list = "rust\npython";
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from(generated_template))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from(template_list)),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok(generated_template))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok(template_list)),
+                    ),
+                ]));
                 let generator = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
                     Some(constant::template_manager::LISTER_URI.to_string()),
                 );
 
-                let actual = generator.generate_with_template_check(&template_names);
+                let actual = generator
+                    .generate_with_template_check(&template_names)
+                    .map_err(ProgramExit::from);
                 let expected_error_message =
                     String::from("Following template names are not supported: pyth.\n")
                         + "For the list of available template names, try "
@@ -731,9 +950,7 @@ mod remote_gitignore_template_manager {
             #[parallel]
             fn it_lists_template_using_provided_client() {
                 let template_list = "rust\npython";
-                let http_client = MockHttpClient {
-                    response: Ok(String::from(template_list)),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok(template_list)));
                 let lister = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
@@ -741,7 +958,7 @@ mod remote_gitignore_template_manager {
                 );
 
                 let actual = lister.list();
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: String::from(template_list),
                     kind: StringKind::Remote,
                 });
@@ -753,9 +970,7 @@ mod remote_gitignore_template_manager {
             #[parallel]
             fn it_works_with_none_endpoint_uri() {
                 let template_list = "rust\npython";
-                let http_client = MockHttpClient {
-                    response: Ok(String::from(template_list)),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok(template_list)));
                 let lister = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
@@ -763,7 +978,7 @@ mod remote_gitignore_template_manager {
                 );
 
                 let actual = lister.list();
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: String::from(template_list),
                     kind: StringKind::Remote,
                 });
@@ -779,24 +994,148 @@ mod remote_gitignore_template_manager {
             #[parallel]
             fn it_propagates_error_from_client_if_any() {
                 let error_message = "all bad";
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from(error_message),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from(error_message))));
                 let lister = RemoteGitignoreTemplateManager::new(
                     Box::new(http_client),
                     Some(constant::template_manager::GENERATOR_URI.to_string()),
                     Some(constant::template_manager::LISTER_URI.to_string()),
                 );
 
-                let actual = lister.list();
+                let actual = lister.list().map_err(ProgramExit::from);
                 let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: String::from(error_message),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", error_message),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                });
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod generate_each {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            #[parallel]
+            fn it_generates_templates_at_once_and_splits_them() {
+                let http_client = MockEndpointHttpClient::new(HashMap::from([(
+                    format!("{}/rust,python", constant::template_manager::GENERATOR_URI),
+                    Ok(HttpResponse::ok(
+                        "# Created by api\n\n### Python ###\n__pycache__/\n\n### Python Patch ###\n.venv/\n\n### Rust ###\ntarget/\n\n# End of api",
+                    )),
+                )]));
+                let manager = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                )
+                .with_server_url(constant::template_manager::BASE_URL);
+
+                let location = format!(
+                    "{}{}",
+                    constant::template_manager::BASE_URL,
+                    constant::template_manager::GENERATOR_URI
+                );
+                let actual =
+                    manager.generate_each(&DefaultTestUtils::to_string_list("rust python"));
+                let expected = Ok(vec![
+                    SourcedTemplate {
+                        name: String::from("rust"),
+                        source: StringKind::Remote,
+                        location: format!("{location}/rust,python"),
+                        content: String::from("### Rust ###\ntarget/"),
+                    },
+                    SourcedTemplate {
+                        name: String::from("python"),
+                        source: StringKind::Remote,
+                        location: format!("{location}/rust,python"),
+                        content: String::from(
+                            "### Python ###\n__pycache__/\n\n### Python Patch ###\n.venv/",
+                        ),
+                    },
+                ]);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            #[parallel]
+            fn it_generates_each_template_on_its_own_when_templates_cannot_be_split() {
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (
+                        format!("{}/rust,python", constant::template_manager::GENERATOR_URI),
+                        Ok(HttpResponse::ok("target/\n__pycache__/")),
+                    ),
+                    (
+                        format!("{}/rust", constant::template_manager::GENERATOR_URI),
+                        Ok(HttpResponse::ok("target/")),
+                    ),
+                    (
+                        format!("{}/python", constant::template_manager::GENERATOR_URI),
+                        Ok(HttpResponse::ok("__pycache__/")),
+                    ),
+                ]));
+                let manager = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                )
+                .with_server_url(constant::template_manager::BASE_URL);
+
+                let location = format!(
+                    "{}{}",
+                    constant::template_manager::BASE_URL,
+                    constant::template_manager::GENERATOR_URI
+                );
+                let actual =
+                    manager.generate_each(&DefaultTestUtils::to_string_list("rust python"));
+                let expected = Ok(vec![
+                    SourcedTemplate {
+                        name: String::from("rust"),
+                        source: StringKind::Remote,
+                        location: format!("{location}/rust"),
+                        content: String::from("target/"),
+                    },
+                    SourcedTemplate {
+                        name: String::from("python"),
+                        source: StringKind::Remote,
+                        location: format!("{location}/python"),
+                        content: String::from("__pycache__/"),
+                    },
+                ]);
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            #[parallel]
+            fn it_propagates_error_from_client_if_any() {
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from("all bad"))));
+                let manager = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+
+                let actual = manager
+                    .generate_each(&DefaultTestUtils::to_string_list("rust"))
+                    .map_err(ProgramExit::from);
+                let expected: Result<Vec<SourcedTemplate>, ProgramExit> = Err(ProgramExit {
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", "all bad"),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
@@ -805,6 +1144,25 @@ mod remote_gitignore_template_manager {
             }
         }
     }
+
+    mod strip_wrapper {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_strips_api_comments_and_title() {
+                let template = DefaultTestUtils::load_expectation_file("rust_template");
+
+                let expected_template = DefaultTestUtils::load_resource_file("templates/rust.txt");
+                let actual_template = RemoteGitignoreTemplateManager::strip_wrapper(&template);
+
+                assert_eq!(actual_template.trim_end(), expected_template.trim_end());
+                assert!(!actual_template.contains("toptal.com"));
+            }
+        }
+    }
 }
 
 mod gitignore_template_manager {
@@ -821,15 +1179,13 @@ mod gitignore_template_manager {
             fn it_generates_template_from_all_provided_managers(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates");
                 let generator_url = format!("{}/python", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from("all good"))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from("python")),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok("all good"))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("python")),
+                    ),
+                ]));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -842,7 +1198,7 @@ mod gitignore_template_manager {
                     vec![Box::new(local_generator), Box::new(remote_generator)];
                 let generator = GitignoreTemplateManager::new(manager_list);
 
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: DefaultTestUtils::load_expectation_file(
                         "local_remote_python_rust_template",
                     ),
@@ -854,37 +1210,220 @@ mod gitignore_template_manager {
             }
 
             #[rstest]
+            #[case(SourcePrecedence::Remote)]
+            #[case(SourcePrecedence::Merge)]
             #[serial]
-            fn it_generates_empty_template_when_no_managers(_ctx: EnvTestContext) {
-                let generator = GitignoreTemplateManager::new(vec![]);
-
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
-                    value: String::new(),
-                    kind: StringKind::Mixed,
-                });
-                let actual = generator.generate(&DefaultTestUtils::to_string_list("python rust"));
-
-                assert_eq!(actual, expected);
-            }
-        }
-
-        mod failure {
-            use super::*;
+            fn it_applies_precedence_to_templates_from_both_managers(
+                _ctx: EnvTestContext,
+                #[case] precedence: SourcePrecedence,
+            ) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let generator_url = format!("{}/rust", constant::template_manager::GENERATOR_URI);
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok("target/\nextra/"))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("rust")),
+                    ),
+                ]));
+
+                let local_generator =
+                    LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                let local_template = local_generator
+                    .generate(&DefaultTestUtils::to_string_list("rust"))
+                    .unwrap()
+                    .value;
+                let remote_generator = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+                let manager_list: Vec<Box<dyn TemplateManager>> =
+                    vec![Box::new(local_generator), Box::new(remote_generator)];
+                let generator =
+                    GitignoreTemplateManager::new(manager_list).with_precedence(precedence);
+
+                let expected_value = match precedence {
+                    SourcePrecedence::Remote => String::from("## REMOTE\n\ntarget/\nextra/"),
+                    _ => format!("## LOCAL\n\n{}\n\nextra/", local_template.trim_end()),
+                };
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
+                    value: expected_value,
+                    kind: StringKind::Mixed,
+                });
+                let actual = generator.generate(&DefaultTestUtils::to_string_list("rust"));
+
+                assert_eq!(actual, expected);
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_generates_templates_in_given_order_across_managers(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let generator_url = format!("{}/go", constant::template_manager::GENERATOR_URI);
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok("go.work"))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("go")),
+                    ),
+                ]));
+
+                let local_generator =
+                    LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                let local_template = |template_name: &str| {
+                    local_generator
+                        .generate(&DefaultTestUtils::to_string_list(template_name))
+                        .unwrap()
+                        .value
+                };
+                let expected_value = format!(
+                    "## LOCAL\n\n{}\n\n## REMOTE\n\ngo.work\n\n## LOCAL\n\n{}",
+                    local_template("rust"),
+                    local_template("python")
+                );
+                let remote_generator = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+                let manager_list: Vec<Box<dyn TemplateManager>> =
+                    vec![Box::new(local_generator), Box::new(remote_generator)];
+                let generator =
+                    GitignoreTemplateManager::new(manager_list).with_order(TemplateOrder::Given);
+
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
+                    value: expected_value.trim_end().to_string(),
+                    kind: StringKind::Mixed,
+                });
+                let actual =
+                    generator.generate(&DefaultTestUtils::to_string_list("rust go python"));
+
+                assert_eq!(actual, expected);
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_generates_one_section_per_source_when_ordering_templates(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let generator_url =
+                    format!("{}/go,node", constant::template_manager::GENERATOR_URI);
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (
+                        generator_url,
+                        Ok(HttpResponse::ok(
+                            "### Go ###\ngo.work\n\n### Node ###\nnode_modules/",
+                        )),
+                    ),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("go\nnode")),
+                    ),
+                ]));
+
+                let local_generator =
+                    LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                let local_template = |template_name: &str| {
+                    local_generator
+                        .generate(&DefaultTestUtils::to_string_list(template_name))
+                        .unwrap()
+                        .value
+                };
+                let expected_value = format!(
+                    "## REMOTE\n\n### Go ###\ngo.work\n\n### Node ###\nnode_modules/\n\n## LOCAL\n\n{}\n\n{}",
+                    local_template("python").trim_end(),
+                    local_template("rust")
+                );
+                let remote_generator = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+                let manager_list: Vec<Box<dyn TemplateManager>> =
+                    vec![Box::new(local_generator), Box::new(remote_generator)];
+                let generator = GitignoreTemplateManager::new(manager_list)
+                    .with_order(TemplateOrder::Alphabetical);
+
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
+                    value: expected_value.trim_end().to_string(),
+                    kind: StringKind::Mixed,
+                });
+                let actual =
+                    generator.generate(&DefaultTestUtils::to_string_list("rust go python node"));
+
+                assert_eq!(actual, expected);
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_matches_template_names_ignoring_case(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let generator_url = format!("{}/go", constant::template_manager::GENERATOR_URI);
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok("go.work"))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("go")),
+                    ),
+                ]));
+
+                let local_generator =
+                    LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                let expected_value = format!(
+                    "## LOCAL\n\n{}\n\n## REMOTE\n\ngo.work",
+                    local_generator
+                        .generate(&DefaultTestUtils::to_string_list("rust"))
+                        .unwrap()
+                        .value
+                );
+                let remote_generator = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+                let manager_list: Vec<Box<dyn TemplateManager>> =
+                    vec![Box::new(local_generator), Box::new(remote_generator)];
+                let generator = GitignoreTemplateManager::new(manager_list);
+
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
+                    value: expected_value,
+                    kind: StringKind::Mixed,
+                });
+                let actual = generator.generate(&DefaultTestUtils::to_string_list("Rust GO"));
+
+                assert_eq!(actual, expected);
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_generates_empty_template_when_no_managers(_ctx: EnvTestContext) {
+                let generator = GitignoreTemplateManager::new(vec![]);
+
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
+                    value: String::new(),
+                    kind: StringKind::Mixed,
+                });
+                let actual = generator.generate(&DefaultTestUtils::to_string_list("python rust"));
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
 
             #[rstest]
             #[serial]
             fn it_fails_when_unsupported_template_names_from_all_managers(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates");
                 let generator_url = format!("{}/rust", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from("all good"))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from("rust")),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok("all good"))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("rust")),
+                    ),
+                ]));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -903,7 +1442,9 @@ mod gitignore_template_manager {
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
-                let actual = generator.generate(&DefaultTestUtils::to_string_list("go"));
+                let actual = generator
+                    .generate(&DefaultTestUtils::to_string_list("go"))
+                    .map_err(ProgramExit::from);
 
                 assert_eq!(actual, expected);
             }
@@ -912,14 +1453,8 @@ mod gitignore_template_manager {
             #[serial]
             fn it_propagates_error_from_remote_manager_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates");
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from("all bad"),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from("all bad"))));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -933,12 +1468,15 @@ mod gitignore_template_manager {
                 let generator = GitignoreTemplateManager::new(manager_list);
 
                 let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: String::from("all bad"),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", "all bad"),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
-                let actual = generator.generate(&DefaultTestUtils::to_string_list("rust python"));
+                let actual = generator
+                    .generate(&DefaultTestUtils::to_string_list("rust python"))
+                    .map_err(ProgramExit::from);
 
                 assert_eq!(actual, expected);
             }
@@ -947,9 +1485,7 @@ mod gitignore_template_manager {
             #[serial]
             fn it_propagates_error_from_local_manager_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates/python.txt");
-                let http_client = MockHttpClient {
-                    response: Ok(String::from("all good")),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok("all good")));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -970,7 +1506,9 @@ mod gitignore_template_manager {
                         kind: ExitKind::Error,
                     },
                 );
-                let actual = generator.generate(&DefaultTestUtils::to_string_list("rust python"));
+                let actual = generator
+                    .generate(&DefaultTestUtils::to_string_list("rust python"))
+                    .map_err(ProgramExit::from);
 
                 assert_eq!(actual, expected);
             }
@@ -979,14 +1517,8 @@ mod gitignore_template_manager {
             #[serial]
             fn it_combines_errors_from_all_managers_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates/python.txt");
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from("all bad"),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from("all bad"))));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -999,17 +1531,23 @@ mod gitignore_template_manager {
                     vec![Box::new(local_generator), Box::new(remote_lister)];
                 let generator = GitignoreTemplateManager::new(manager_list);
 
-                let expected: Result<QualifiedString, ProgramExit> = Err(
-                    ProgramExit {
-                        message: "An error occurred while listing templates from local file system: Not a directory (os error 20)\nall bad".to_string(),
-                        exit_status: constant::exit_status::GENERIC * 2,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    },
-                );
+                let expected: Result<QualifiedString, Error> = Err(Error::Aggregate(vec![
+                    Error::io(
+                        &format!(
+                            "{}: Not a directory (os error 20)",
+                            constant::error_messages::LOCAL_LISTING
+                        ),
+                        std::io::Error::from_raw_os_error(20),
+                    ),
+                    Error::Http(HttpError::Transport(String::from("all bad"))),
+                ]));
                 let actual = generator.generate(&DefaultTestUtils::to_string_list("rust python"));
 
                 assert_eq!(actual, expected);
+                assert_eq!(
+                    actual.unwrap_err().exit_status(),
+                    constant::exit_status::GENERIC
+                );
             }
         }
     }
@@ -1026,15 +1564,13 @@ mod gitignore_template_manager {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates");
                 let generator_url =
                     format!("{}/python,rust", constant::template_manager::GENERATOR_URI);
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (generator_url, Ok(String::from("all good"))),
-                        (
-                            constant::template_manager::LISTER_URI.to_string(),
-                            Ok(String::from("python\nrust")),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (generator_url, Ok(HttpResponse::ok("all good"))),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("python\nrust")),
+                    ),
+                ]));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -1047,7 +1583,7 @@ mod gitignore_template_manager {
                     vec![Box::new(local_generator), Box::new(remote_generator)];
                 let generator = GitignoreTemplateManager::new(manager_list);
 
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: DefaultTestUtils::load_expectation_file(
                         "local_remote_python_rust_template",
                     ),
@@ -1063,18 +1599,46 @@ mod gitignore_template_manager {
         mod failure {
             use super::*;
 
+            #[rstest]
+            #[serial]
+            fn it_fails_when_template_names_case_differs_in_strict_mode(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let http_client = MockEndpointHttpClient::new(HashMap::from([(
+                    constant::template_manager::LISTER_URI.to_string(),
+                    Ok(HttpResponse::ok("go")),
+                )]));
+
+                let local_generator =
+                    LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                let remote_generator = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+                let manager_list: Vec<Box<dyn TemplateManager>> =
+                    vec![Box::new(local_generator), Box::new(remote_generator)];
+                let generator = GitignoreTemplateManager::new(manager_list).with_strict(true);
+
+                let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
+                    message: constant::error_messages::INEXISTENT_TEMPLATE_NAMES
+                        .replace("{templates}", "Rust"),
+                    exit_status: constant::exit_status::GENERIC,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                });
+                let actual = generator
+                    .generate_with_template_check(&DefaultTestUtils::to_string_list("Rust go"))
+                    .map_err(ProgramExit::from);
+
+                assert_eq!(actual, expected);
+            }
+
             #[rstest]
             #[serial]
             fn it_propagates_error_from_remote_manager_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates");
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from("all bad"),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from("all bad"))));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -1088,13 +1652,15 @@ mod gitignore_template_manager {
                 let generator = GitignoreTemplateManager::new(manager_list);
 
                 let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: String::from("all bad"),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", "all bad"),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
                 let actual = generator
-                    .generate_with_template_check(&DefaultTestUtils::to_string_list("rust python"));
+                    .generate_with_template_check(&DefaultTestUtils::to_string_list("rust python"))
+                    .map_err(ProgramExit::from);
 
                 assert_eq!(actual, expected);
             }
@@ -1103,9 +1669,7 @@ mod gitignore_template_manager {
             #[serial]
             fn it_propagates_error_from_local_manager_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates");
-                let http_client = MockHttpClient {
-                    response: Ok(String::from("all good")),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok("all good")));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -1127,7 +1691,8 @@ mod gitignore_template_manager {
                     },
                 );
                 let actual = generator
-                    .generate_with_template_check(&DefaultTestUtils::to_string_list("rust pyth"));
+                    .generate_with_template_check(&DefaultTestUtils::to_string_list("rust pyth"))
+                    .map_err(ProgramExit::from);
 
                 assert_eq!(actual, expected);
             }
@@ -1136,14 +1701,8 @@ mod gitignore_template_manager {
             #[serial]
             fn it_combines_errors_from_all_managers_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates/python.txt");
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from("all bad"),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from("all bad"))));
 
                 let local_generator =
                     LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
@@ -1156,19 +1715,24 @@ mod gitignore_template_manager {
                     vec![Box::new(local_generator), Box::new(remote_lister)];
                 let generator = GitignoreTemplateManager::new(manager_list);
 
-                let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: format!(
-                        "{}: Not a directory (os error 20)\nall bad",
-                        constant::error_messages::LOCAL_LISTING
+                let expected: Result<QualifiedString, Error> = Err(Error::Aggregate(vec![
+                    Error::io(
+                        &format!(
+                            "{}: Not a directory (os error 20)",
+                            constant::error_messages::LOCAL_LISTING
+                        ),
+                        std::io::Error::from_raw_os_error(20),
                     ),
-                    exit_status: constant::exit_status::GENERIC * 2,
-                    styled_message: None,
-                    kind: ExitKind::Error,
-                });
+                    Error::Http(HttpError::Transport(String::from("all bad"))),
+                ]));
                 let actual = generator
                     .generate_with_template_check(&DefaultTestUtils::to_string_list("rust python"));
 
                 assert_eq!(actual, expected);
+                assert_eq!(
+                    actual.unwrap_err().exit_status(),
+                    constant::exit_status::GENERIC
+                );
             }
         }
     }
@@ -1183,9 +1747,7 @@ mod gitignore_template_manager {
             #[serial]
             fn it_lists_templates_from_all_provided_managers(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates");
-                let http_client = MockHttpClient {
-                    response: Ok(String::from("go\ncpp\nwo")),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok("go\ncpp\nwo")));
 
                 let local_lister = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
                 let remote_lister = RemoteGitignoreTemplateManager::new(
@@ -1197,7 +1759,7 @@ mod gitignore_template_manager {
                     vec![Box::new(local_lister), Box::new(remote_lister)];
                 let lister = GitignoreTemplateManager::new(manager_list);
 
-                let expected: Result<QualifiedString, ProgramExit> = Ok(QualifiedString {
+                let expected: Result<QualifiedString, Error> = Ok(QualifiedString {
                     value: "cpp\ngo\n*python\n*rust\nwo".to_string(),
                     kind: StringKind::Mixed,
                 });
@@ -1214,14 +1776,8 @@ mod gitignore_template_manager {
             #[serial]
             fn it_propagates_error_from_remote_manager_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates");
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from("all bad"),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from("all bad"))));
 
                 let local_lister = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
                 let remote_lister = RemoteGitignoreTemplateManager::new(
@@ -1234,12 +1790,13 @@ mod gitignore_template_manager {
                 let lister = GitignoreTemplateManager::new(manager_list);
 
                 let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: String::from("all bad"),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", "all bad"),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
-                let actual = lister.list();
+                let actual = lister.list().map_err(ProgramExit::from);
 
                 assert_eq!(actual, expected);
             }
@@ -1248,9 +1805,7 @@ mod gitignore_template_manager {
             #[serial]
             fn it_propagates_error_from_local_manager_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates/python.txt");
-                let http_client = MockHttpClient {
-                    response: Ok(String::from("go\ncpp")),
-                };
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok("go\ncpp")));
 
                 let local_lister = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
                 let remote_lister = RemoteGitignoreTemplateManager::new(
@@ -1271,7 +1826,7 @@ mod gitignore_template_manager {
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
-                let actual = lister.list();
+                let actual = lister.list().map_err(ProgramExit::from);
 
                 assert_eq!(actual, expected);
             }
@@ -1280,14 +1835,8 @@ mod gitignore_template_manager {
             #[serial]
             fn it_combines_errors_from_all_managers_if_any(_ctx: EnvTestContext) {
                 let template_dir = DefaultTestUtils::get_resource_file_path("templates/python.txt");
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from("all bad"),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client =
+                    MockHttpClient::new(Err(HttpError::Transport(String::from("all bad"))));
 
                 let local_lister = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
                 let remote_lister = RemoteGitignoreTemplateManager::new(
@@ -1299,16 +1848,266 @@ mod gitignore_template_manager {
                     vec![Box::new(local_lister), Box::new(remote_lister)];
                 let lister = GitignoreTemplateManager::new(manager_list);
 
-                let expected: Result<QualifiedString, ProgramExit> = Err(ProgramExit {
-                    message: format!(
-                        "{}: Not a directory (os error 20)\nall bad",
-                        constant::error_messages::LOCAL_LISTING
+                let expected: Result<QualifiedString, Error> = Err(Error::Aggregate(vec![
+                    Error::io(
+                        &format!(
+                            "{}: Not a directory (os error 20)",
+                            constant::error_messages::LOCAL_LISTING
+                        ),
+                        std::io::Error::from_raw_os_error(20),
+                    ),
+                    Error::Http(HttpError::Transport(String::from("all bad"))),
+                ]));
+                let actual = lister.list();
+
+                assert_eq!(actual, expected);
+                assert_eq!(
+                    actual.unwrap_err().exit_status(),
+                    constant::exit_status::GENERIC
+                );
+            }
+        }
+    }
+
+    mod generate_each {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[serial]
+            fn it_generates_each_template_from_the_manager_serving_it(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (
+                        format!("{}/python", constant::template_manager::GENERATOR_URI),
+                        Ok(HttpResponse::ok("all good")),
+                    ),
+                    (
+                        constant::template_manager::LISTER_URI.to_string(),
+                        Ok(HttpResponse::ok("python")),
                     ),
-                    exit_status: constant::exit_status::GENERIC * 2,
+                ]));
+
+                let local_manager = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                let remote_manager = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+                let local_rust_template = local_manager
+                    .generate(&DefaultTestUtils::to_string_list("rust"))
+                    .unwrap();
+                let local_python_template = local_manager
+                    .generate(&DefaultTestUtils::to_string_list("python"))
+                    .unwrap();
+                let manager_list: Vec<Box<dyn TemplateManager>> =
+                    vec![Box::new(local_manager), Box::new(remote_manager)];
+                let manager = GitignoreTemplateManager::new(manager_list);
+
+                let actual =
+                    manager.generate_each(&DefaultTestUtils::to_string_list("python rust"));
+                let expected = Ok(vec![
+                    SourcedTemplate {
+                        name: String::from("python"),
+                        source: StringKind::Local,
+                        location: format!("{template_dir}/python.txt"),
+                        content: local_python_template.value,
+                    },
+                    SourcedTemplate {
+                        name: String::from("rust"),
+                        source: StringKind::Local,
+                        location: format!("{template_dir}/rust.txt"),
+                        content: local_rust_template.value,
+                    },
+                    SourcedTemplate {
+                        name: String::from("python"),
+                        source: StringKind::Remote,
+                        location: format!("{}/python", constant::template_manager::GENERATOR_URI),
+                        content: String::from("all good"),
+                    },
+                ]);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[rstest]
+            #[serial]
+            fn it_applies_precedence_to_templates_from_both_managers(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let http_client = MockEndpointHttpClient::new(HashMap::from([(
+                    constant::template_manager::LISTER_URI.to_string(),
+                    Ok(HttpResponse::ok("python")),
+                )]));
+
+                let local_manager = LocalGitignoreTemplateManager::new(Some(template_dir.clone()));
+                let remote_manager = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+                let local_python_template = local_manager
+                    .generate(&DefaultTestUtils::to_string_list("python"))
+                    .unwrap();
+                let manager_list: Vec<Box<dyn TemplateManager>> =
+                    vec![Box::new(local_manager), Box::new(remote_manager)];
+                let manager = GitignoreTemplateManager::new(manager_list)
+                    .with_precedence(SourcePrecedence::Local);
+
+                let actual = manager.generate_each(&DefaultTestUtils::to_string_list("python"));
+                let expected = Ok(vec![SourcedTemplate {
+                    name: String::from("python"),
+                    source: StringKind::Local,
+                    location: format!("{template_dir}/python.txt"),
+                    content: local_python_template.value,
+                }]);
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[rstest]
+            #[serial]
+            fn it_fails_when_template_not_served_by_any_manager(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok("python")));
+
+                let local_manager = LocalGitignoreTemplateManager::new(Some(template_dir));
+                let remote_manager = RemoteGitignoreTemplateManager::new(
+                    Box::new(http_client),
+                    Some(constant::template_manager::GENERATOR_URI.to_string()),
+                    Some(constant::template_manager::LISTER_URI.to_string()),
+                );
+                let manager_list: Vec<Box<dyn TemplateManager>> =
+                    vec![Box::new(local_manager), Box::new(remote_manager)];
+                let manager = GitignoreTemplateManager::new(manager_list);
+
+                let actual = manager
+                    .generate_each(&DefaultTestUtils::to_string_list("rust foo"))
+                    .map_err(ProgramExit::from);
+                let expected: Result<Vec<SourcedTemplate>, ProgramExit> = Err(ProgramExit {
+                    message: constant::error_messages::UNSUPPORTED_TEMPLATE.to_string(),
+                    exit_status: constant::exit_status::GENERIC,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
-                let actual = lister.list();
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod generate_sourced {
+        use super::*;
+
+        fn build_manager(template_dir: &str) -> GitignoreTemplateManager {
+            let http_client = MockEndpointHttpClient::new(HashMap::from([
+                (
+                    format!("{}/go", constant::template_manager::GENERATOR_URI),
+                    Ok(HttpResponse::ok("go.work")),
+                ),
+                (
+                    constant::template_manager::LISTER_URI.to_string(),
+                    Ok(HttpResponse::ok("go")),
+                ),
+            ]));
+
+            let local_manager = LocalGitignoreTemplateManager::new(Some(template_dir.to_string()));
+            let remote_manager = RemoteGitignoreTemplateManager::new(
+                Box::new(http_client),
+                Some(constant::template_manager::GENERATOR_URI.to_string()),
+                Some(constant::template_manager::LISTER_URI.to_string()),
+            );
+            let manager_list: Vec<Box<dyn TemplateManager>> =
+                vec![Box::new(local_manager), Box::new(remote_manager)];
+
+            GitignoreTemplateManager::new(manager_list).with_order(TemplateOrder::Given)
+        }
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case(false)]
+            #[case(true)]
+            #[serial]
+            fn it_generates_output_along_with_each_template(
+                _ctx: EnvTestContext,
+                #[case] check_template_names: bool,
+            ) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let template_names = DefaultTestUtils::to_string_list("rust go python");
+                let manager = build_manager(&template_dir);
+
+                let actual = manager
+                    .generate_sourced(&template_names, check_template_names)
+                    .unwrap();
+                let expected_output = manager.generate(&template_names).unwrap();
+                let expected_template_names = ["rust", "go", "python"];
+
+                assert_eq!(actual.output, expected_output);
+                assert_eq!(
+                    actual
+                        .templates
+                        .iter()
+                        .map(|template| template.name.as_str())
+                        .collect::<Vec<&str>>(),
+                    expected_template_names
+                );
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[rstest]
+            #[serial]
+            fn it_fails_when_checked_template_not_listed(_ctx: EnvTestContext) {
+                let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+                let manager = build_manager(&template_dir);
+
+                let actual = manager
+                    .generate_sourced(&DefaultTestUtils::to_string_list("rust foo"), true)
+                    .map_err(ProgramExit::from);
+                let expected: Result<SourcedGeneration, ProgramExit> = Err(ProgramExit {
+                    message: constant::error_messages::INEXISTENT_TEMPLATE_NAMES
+                        .replace("{templates}", "foo"),
+                    exit_status: constant::exit_status::GENERIC,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                });
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod sourced_template {
+    use super::*;
+
+    mod describe {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            #[parallel]
+            fn it_describes_template_with_its_metadata() {
+                let template = SourcedTemplate {
+                    name: String::from("rust"),
+                    source: StringKind::Remote,
+                    location: String::from("https://test/api/rust"),
+                    content: String::from("### Rust ###\ntarget/\n*.pdb"),
+                };
+
+                let actual = template.describe();
+                let expected = "==> rust (remote)\nLocation: https://test/api/rust\nSize: 26 bytes\nRules: 2\n\n### Rust ###\ntarget/\n*.pdb";
 
                 assert_eq!(actual, expected);
             }
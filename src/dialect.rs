@@ -0,0 +1,126 @@
+//! Define components to talk to the various template API dialects.
+use clap::ValueEnum;
+
+use crate::{error::Error, http_client::HttpClient};
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Template API dialect trait to list and generate templates through a
+/// remote API.
+///
+/// Each implementation knows how a given API exposes its template list and
+/// its templates, and how to turn them into the format expected by
+/// [`crate::core::RemoteGitignoreTemplateManager`].
+pub trait ApiDialect {
+    /// Lists the templates available through the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The http client to be used to make the API calls.
+    /// * `lister_uri` - The endpoint URI to list templates.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the template names, or an [`Error`] on
+    /// error (e.g. 4xx, malformed body...).
+    fn list(&self, http_client: &dyn HttpClient, lister_uri: &str) -> Result<Vec<String>, Error>;
+
+    /// Generates the given templates through the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The http client to be used to make the API calls.
+    /// * `generator_uri` - The endpoint URI to generate templates.
+    /// * `lister_uri` - The endpoint URI to list templates, for dialects
+    ///   needing the list to locate templates.
+    /// * `template_names` - The non-empty list of template names.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the generated template, or an [`Error`] on
+    /// error (e.g. 4xx, malformed body...).
+    fn generate(
+        &self,
+        http_client: &dyn HttpClient,
+        generator_uri: &str,
+        lister_uri: &str,
+        template_names: &[String],
+    ) -> Result<String, Error>;
+
+    /// Generates the given templates through the API, each on its own.
+    ///
+    /// Templates are fetched with as few API calls as the dialect allows,
+    /// then split per template.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The http client to be used to make the API calls.
+    /// * `generator_uri` - The endpoint URI to generate templates.
+    /// * `lister_uri` - The endpoint URI to list templates, for dialects
+    ///   needing the list to locate templates.
+    /// * `template_names` - The non-empty list of template names.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the generated templates, in the order of the
+    /// given names, along with the endpoint URI each was fetched from, or an
+    /// [`Error`] on error (e.g. 4xx, malformed body...).
+    fn generate_each(
+        &self,
+        http_client: &dyn HttpClient,
+        generator_uri: &str,
+        lister_uri: &str,
+        template_names: &[String],
+    ) -> Result<Vec<FetchedTemplate>, Error>;
+}
+
+/// DTO struct representing a template generated on its own through an API.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FetchedTemplate {
+    /// The endpoint URI the template was fetched from, relative to the
+    /// server url (e.g. `/api/rust,python` for templates generated at
+    /// once).
+    pub uri: String,
+
+    /// The template content.
+    pub content: String,
+}
+
+/// Enum of supported template API dialects.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum ApiDialectKind {
+    /// See [`ToptalDialect`].
+    Toptal,
+
+    /// See [`JsonDialect`].
+    Json,
+
+    /// See [`PerTemplateDialect`].
+    PerTemplate,
+}
+
+/// Dialect of the Toptal API.
+///
+/// * The template list is a comma or newline separated list of names.
+/// * The templates are generated at once by the `{generator_uri}/{a,b,c}`
+///   endpoint, and split per template along their `### Title ###` section
+///   headers when generated each on its own.
+pub struct ToptalDialect;
+
+/// Dialect of APIs exposing their template list as JSON.
+///
+/// * The template list is a JSON object whose keys are the template names,
+///   each mapped to an object holding the template display `name` and the
+///   `path` of its file, relative to the server url.
+/// * Each template is fetched from its file path and merged client-side.
+pub struct JsonDialect;
+
+/// Dialect of APIs exposing one endpoint per template.
+///
+/// * The template list is a comma or newline separated list of names.
+/// * Each template is fetched from the `{generator_uri}/{name}` endpoint and
+///   merged client-side.
+pub struct PerTemplateDialect;
@@ -0,0 +1,274 @@
+use serde_json::Value;
+
+use super::{
+    ApiDialect, ApiDialectKind, FetchedTemplate, JsonDialect, PerTemplateDialect, ToptalDialect,
+};
+use crate::{
+    constant::{error_messages, template_manager},
+    error::Error,
+    helper::{DefaultUtils, Utils},
+    http_client::HttpClient,
+};
+
+impl ApiDialectKind {
+    /// Builds the dialect matching this kind.
+    pub fn to_dialect(&self) -> Box<dyn ApiDialect> {
+        match self {
+            Self::Toptal => Box::new(ToptalDialect),
+            Self::Json => Box::new(JsonDialect),
+            Self::PerTemplate => Box::new(PerTemplateDialect),
+        }
+    }
+}
+
+fn parse_name_list(template_list: &str) -> Vec<String> {
+    template_list
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn title_template(title: &str, template: &str) -> String {
+    format!("### {title} ###\n{template}")
+}
+
+fn body_parsing_error(error: &str) -> Error {
+    Error::InvalidResponse(format!("{}: {error}", error_messages::BODY_PARSING_ISSUE))
+}
+
+impl ToptalDialect {
+    /// Splits templates generated at once into one template per given name,
+    /// along their `### Title ###` section headers, leaving out the comments
+    /// wrapping them (e.g. `# Created by ...`).
+    ///
+    /// Sections not titled after a given template (e.g.
+    /// `### Python Patch ###`) belong to the previous one.
+    ///
+    /// # Returns
+    ///
+    /// The templates in the order of the given names, or `None` if any of
+    /// them has no section.
+    fn split_templates(templates: &str, template_names: &[String]) -> Option<Vec<String>> {
+        let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+
+        for line in templates.lines() {
+            if template_manager::REMOTE_WRAPPER_PREFIXES
+                .iter()
+                .any(|prefix| line.starts_with(prefix))
+            {
+                continue;
+            }
+
+            let section_name = line
+                .trim()
+                .strip_prefix("### ")
+                .and_then(|line| line.strip_suffix(" ###"))
+                .map(|title| title.replace(' ', "").to_lowercase())
+                .filter(|title| {
+                    template_names
+                        .iter()
+                        .any(|template_name| template_name.to_lowercase() == *title)
+                });
+
+            match (section_name, sections.last_mut()) {
+                (Some(section_name), _) => sections.push((section_name, vec![line])),
+                (None, Some((_, section_lines))) => section_lines.push(line),
+                (None, None) => {}
+            }
+        }
+
+        template_names
+            .iter()
+            .map(|template_name| {
+                sections
+                    .iter()
+                    .find(|(section_name, _)| *section_name == template_name.to_lowercase())
+                    .map(|(_, section_lines)| section_lines.join("\n").trim_end().to_string())
+            })
+            .collect()
+    }
+}
+
+impl JsonDialect {
+    /// Parses the JSON template list into (key, display name, path)
+    /// triplets, paths being made relative to the server base url whether
+    /// they start with a slash or not.
+    fn parse_entries(template_list: &str) -> Result<Vec<(String, String, String)>, Error> {
+        let value: Value = serde_json::from_str(template_list)
+            .map_err(|error| body_parsing_error(&error.to_string()))?;
+        let entries = value
+            .as_object()
+            .ok_or_else(|| body_parsing_error(error_messages::JSON_LIST_NOT_OBJECT))?;
+
+        entries
+            .iter()
+            .map(|(key, entry)| {
+                let field = |name: &str| {
+                    entry.get(name).and_then(Value::as_str).ok_or_else(|| {
+                        body_parsing_error(
+                            &error_messages::JSON_LIST_MISSING_FIELD
+                                .replace("{field}", name)
+                                .replace("{key}", key),
+                        )
+                    })
+                };
+
+                Ok((
+                    key.clone(),
+                    field("name")?.to_string(),
+                    format!("/{}", field("path")?.trim_start_matches('/')),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl ApiDialect for ToptalDialect {
+    fn list(&self, http_client: &dyn HttpClient, lister_uri: &str) -> Result<Vec<String>, Error> {
+        Ok(parse_name_list(&http_client.get(lister_uri)?))
+    }
+
+    fn generate(
+        &self,
+        http_client: &dyn HttpClient,
+        generator_uri: &str,
+        _lister_uri: &str,
+        template_names: &[String],
+    ) -> Result<String, Error> {
+        http_client.get(&format!("{generator_uri}/{}", template_names.join(",")))
+    }
+
+    fn generate_each(
+        &self,
+        http_client: &dyn HttpClient,
+        generator_uri: &str,
+        _lister_uri: &str,
+        template_names: &[String],
+    ) -> Result<Vec<FetchedTemplate>, Error> {
+        let uri = format!("{generator_uri}/{}", template_names.join(","));
+        let templates = http_client.get(&uri)?;
+
+        match Self::split_templates(&templates, template_names) {
+            Some(templates) => Ok(templates
+                .into_iter()
+                .map(|content| FetchedTemplate {
+                    uri: uri.clone(),
+                    content,
+                })
+                .collect()),
+            None if template_names.len() == 1 => Ok(vec![FetchedTemplate {
+                uri,
+                content: templates,
+            }]),
+            None => template_names
+                .iter()
+                .map(|template_name| {
+                    let uri = format!("{generator_uri}/{template_name}");
+                    http_client
+                        .get(&uri)
+                        .map(|content| FetchedTemplate { uri, content })
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ApiDialect for JsonDialect {
+    fn list(&self, http_client: &dyn HttpClient, lister_uri: &str) -> Result<Vec<String>, Error> {
+        let entries = Self::parse_entries(&http_client.get(lister_uri)?)?;
+
+        Ok(entries.into_iter().map(|(key, _, _)| key).collect())
+    }
+
+    fn generate(
+        &self,
+        http_client: &dyn HttpClient,
+        generator_uri: &str,
+        lister_uri: &str,
+        template_names: &[String],
+    ) -> Result<String, Error> {
+        Ok(self
+            .generate_each(http_client, generator_uri, lister_uri, template_names)?
+            .into_iter()
+            .map(|template| template.content)
+            .collect::<Vec<String>>()
+            .join("\n\n"))
+    }
+
+    fn generate_each(
+        &self,
+        http_client: &dyn HttpClient,
+        _generator_uri: &str,
+        lister_uri: &str,
+        template_names: &[String],
+    ) -> Result<Vec<FetchedTemplate>, Error> {
+        let entries = Self::parse_entries(&http_client.get(lister_uri)?)?;
+        let find_entry =
+            |template_name: &str| entries.iter().find(|(key, _, _)| key == template_name);
+        let missing_names: Vec<&str> = template_names
+            .iter()
+            .map(String::as_str)
+            .filter(|template_name| find_entry(template_name).is_none())
+            .collect();
+
+        if !missing_names.is_empty() {
+            return Err(Error::TemplateNotFound(
+                error_messages::INEXISTENT_TEMPLATE_NAMES
+                    .replace("{templates}", &missing_names.join(", ")),
+            ));
+        }
+
+        template_names
+            .iter()
+            .filter_map(|template_name| find_entry(template_name))
+            .map(|(_, name, path)| {
+                http_client.get(path).map(|template| FetchedTemplate {
+                    uri: path.clone(),
+                    content: title_template(name, &template),
+                })
+            })
+            .collect()
+    }
+}
+
+impl ApiDialect for PerTemplateDialect {
+    fn list(&self, http_client: &dyn HttpClient, lister_uri: &str) -> Result<Vec<String>, Error> {
+        Ok(parse_name_list(&http_client.get(lister_uri)?))
+    }
+
+    fn generate(
+        &self,
+        http_client: &dyn HttpClient,
+        generator_uri: &str,
+        lister_uri: &str,
+        template_names: &[String],
+    ) -> Result<String, Error> {
+        Ok(self
+            .generate_each(http_client, generator_uri, lister_uri, template_names)?
+            .into_iter()
+            .map(|template| template.content)
+            .collect::<Vec<String>>()
+            .join("\n\n"))
+    }
+
+    fn generate_each(
+        &self,
+        http_client: &dyn HttpClient,
+        generator_uri: &str,
+        _lister_uri: &str,
+        template_names: &[String],
+    ) -> Result<Vec<FetchedTemplate>, Error> {
+        template_names
+            .iter()
+            .map(|template_name| {
+                let uri = format!("{generator_uri}/{template_name}");
+                http_client.get(&uri).map(|template| FetchedTemplate {
+                    uri,
+                    content: title_template(&DefaultUtils::capitalize(template_name), &template),
+                })
+            })
+            .collect()
+    }
+}
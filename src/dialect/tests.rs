@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+
+use crate::{
+    constant,
+    core::{ExitKind, ProgramExit},
+    dialect::{ApiDialect, FetchedTemplate, JsonDialect, PerTemplateDialect, ToptalDialect},
+    error::Error,
+    http_client::{HttpResponse, MockEndpointHttpClient},
+    test_helper::{DefaultTestUtils, TestUtils},
+};
+
+const JSON_LIST: &str = r#"{
+    "rust": {"name": "Rust", "path": "/templates/Rust.gitignore"},
+    "python": {"name": "Python", "path": "/templates/Python.gitignore"}
+}"#;
+
+fn mock_client(responses: &[(&str, &str)]) -> MockEndpointHttpClient {
+    MockEndpointHttpClient::new(HashMap::from_iter(
+        responses
+            .iter()
+            .map(|(uri, body)| (uri.to_string(), Ok(HttpResponse::ok(body)))),
+    ))
+}
+
+mod toptal_dialect {
+    use super::*;
+
+    mod list {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_parses_comma_and_newline_separated_names() {
+                let http_client = mock_client(&[("/list", "rust,python\ngo")]);
+
+                let actual = ToptalDialect.list(&http_client, "/list");
+                let expected: Result<Vec<String>, Error> =
+                    Ok(DefaultTestUtils::to_string_list("rust python go"));
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod generate {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_generates_all_templates_at_once() {
+                let http_client = mock_client(&[("/api/rust,python", "rust python template")]);
+
+                let actual = ToptalDialect.generate(
+                    &http_client,
+                    "/api",
+                    "/list",
+                    &DefaultTestUtils::to_string_list("rust python"),
+                );
+                let expected: Result<String, Error> = Ok(String::from("rust python template"));
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod generate_each {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_generates_all_templates_at_once_and_splits_them() {
+                let http_client = mock_client(&[(
+                    "/api/rust,python",
+                    "# Created by api\n\n### Python ###\n__pycache__/\n\n### Rust ###\ntarget/\n\n# End of api",
+                )]);
+
+                let actual = ToptalDialect.generate_each(
+                    &http_client,
+                    "/api",
+                    "/list",
+                    &DefaultTestUtils::to_string_list("rust python"),
+                );
+                let expected: Result<Vec<FetchedTemplate>, Error> = Ok(vec![
+                    FetchedTemplate {
+                        uri: String::from("/api/rust,python"),
+                        content: String::from("### Rust ###\ntarget/"),
+                    },
+                    FetchedTemplate {
+                        uri: String::from("/api/rust,python"),
+                        content: String::from("### Python ###\n__pycache__/"),
+                    },
+                ]);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_generates_each_template_on_its_own_when_templates_cannot_be_split() {
+                let http_client = mock_client(&[
+                    ("/api/rust,python", "target/\n__pycache__/"),
+                    ("/api/rust", "target/"),
+                    ("/api/python", "__pycache__/"),
+                ]);
+
+                let actual = ToptalDialect.generate_each(
+                    &http_client,
+                    "/api",
+                    "/list",
+                    &DefaultTestUtils::to_string_list("rust python"),
+                );
+                let expected: Result<Vec<FetchedTemplate>, Error> = Ok(vec![
+                    FetchedTemplate {
+                        uri: String::from("/api/rust"),
+                        content: String::from("target/"),
+                    },
+                    FetchedTemplate {
+                        uri: String::from("/api/python"),
+                        content: String::from("__pycache__/"),
+                    },
+                ]);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod json_dialect {
+    use super::*;
+
+    mod list {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_parses_keys_of_json_list() {
+                let http_client = mock_client(&[("/list?format=json", JSON_LIST)]);
+
+                let actual = JsonDialect.list(&http_client, "/list?format=json");
+                let expected: Result<Vec<String>, Error> =
+                    Ok(DefaultTestUtils::to_string_list("python rust"));
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            fn it_fails_when_entry_misses_path() {
+                let http_client =
+                    mock_client(&[("/list?format=json", r#"{"rust": {"name": "Rust"}}"#)]);
+
+                let actual = JsonDialect
+                    .list(&http_client, "/list?format=json")
+                    .map_err(ProgramExit::from);
+                let expected: Result<Vec<String>, ProgramExit> = Err(ProgramExit {
+                    message: format!(
+                        "{}: {}",
+                        constant::error_messages::BODY_PARSING_ISSUE,
+                        constant::error_messages::JSON_LIST_MISSING_FIELD
+                            .replace("{field}", "path")
+                            .replace("{key}", "rust")
+                    ),
+                    exit_status: constant::exit_status::BODY_PARSING_ISSUE,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                });
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fails_when_list_is_not_json_object() {
+                let http_client = mock_client(&[("/list?format=json", "[\"rust\"]")]);
+
+                let actual = JsonDialect
+                    .list(&http_client, "/list?format=json")
+                    .map_err(|error| error.exit_status());
+                let expected: Result<Vec<String>, i32> =
+                    Err(constant::exit_status::BODY_PARSING_ISSUE);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod generate {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_fetches_each_template_file_and_merges_them() {
+                let http_client = mock_client(&[
+                    ("/list?format=json", JSON_LIST),
+                    ("/templates/Rust.gitignore", "target/"),
+                    ("/templates/Python.gitignore", "__pycache__/"),
+                ]);
+
+                let actual = JsonDialect.generate(
+                    &http_client,
+                    "/api",
+                    "/list?format=json",
+                    &DefaultTestUtils::to_string_list("rust python"),
+                );
+                let expected: Result<String, Error> = Ok(String::from(
+                    "### Rust ###\ntarget/\n\n### Python ###\n__pycache__/",
+                ));
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fetches_template_files_whose_path_lacks_leading_slash() {
+                let http_client = mock_client(&[
+                    (
+                        "/list?format=json",
+                        r#"{"rust": {"name": "Rust", "path": "templates/Rust.gitignore"}}"#,
+                    ),
+                    ("/templates/Rust.gitignore", "target/"),
+                ]);
+
+                let actual = JsonDialect.generate(
+                    &http_client,
+                    "/api",
+                    "/list?format=json",
+                    &DefaultTestUtils::to_string_list("rust"),
+                );
+                let expected: Result<String, Error> = Ok(String::from("### Rust ###\ntarget/"));
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            fn it_fails_when_template_not_listed() {
+                let http_client = mock_client(&[("/list?format=json", JSON_LIST)]);
+
+                let actual = JsonDialect
+                    .generate(
+                        &http_client,
+                        "/api",
+                        "/list?format=json",
+                        &DefaultTestUtils::to_string_list("rust go"),
+                    )
+                    .map_err(ProgramExit::from);
+                let expected: Result<String, ProgramExit> = Err(ProgramExit::error(
+                    &constant::error_messages::INEXISTENT_TEMPLATE_NAMES
+                        .replace("{templates}", "go"),
+                ));
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod generate_each {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_fetches_each_template_file_from_its_listed_path() {
+                let http_client = mock_client(&[
+                    ("/list?format=json", JSON_LIST),
+                    ("/templates/Rust.gitignore", "target/"),
+                    ("/templates/Python.gitignore", "__pycache__/"),
+                ]);
+
+                let actual = JsonDialect.generate_each(
+                    &http_client,
+                    "/api",
+                    "/list?format=json",
+                    &DefaultTestUtils::to_string_list("rust python"),
+                );
+                let expected: Result<Vec<FetchedTemplate>, Error> = Ok(vec![
+                    FetchedTemplate {
+                        uri: String::from("/templates/Rust.gitignore"),
+                        content: String::from("### Rust ###\ntarget/"),
+                    },
+                    FetchedTemplate {
+                        uri: String::from("/templates/Python.gitignore"),
+                        content: String::from("### Python ###\n__pycache__/"),
+                    },
+                ]);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod per_template_dialect {
+    use super::*;
+
+    mod generate {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_fetches_each_template_and_merges_them() {
+                let http_client = mock_client(&[
+                    ("/templates/rust", "target/"),
+                    ("/templates/python", "__pycache__/"),
+                ]);
+
+                let actual = PerTemplateDialect.generate(
+                    &http_client,
+                    "/templates",
+                    "/list",
+                    &DefaultTestUtils::to_string_list("rust python"),
+                );
+                let expected: Result<String, Error> = Ok(String::from(
+                    "### Rust ###\ntarget/\n\n### Python ###\n__pycache__/",
+                ));
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            fn it_fails_when_any_template_fetch_fails() {
+                let http_client = mock_client(&[("/templates/rust", "target/")]);
+
+                let actual = PerTemplateDialect
+                    .generate(
+                        &http_client,
+                        "/templates",
+                        "/list",
+                        &DefaultTestUtils::to_string_list("rust python"),
+                    )
+                    .map_err(|error| error.exit_status());
+                let expected: Result<String, i32> = Err(constant::exit_status::HTTP_CLIENT_STATUS);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Define components to compare file contents.
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Content differ trait to compare an actual content with an expected one.
+pub trait ContentDiffer {
+    /// Computes the differences between given contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `actual` - The content currently in place (e.g. the content of a
+    ///   file on disk).
+    /// * `expected` - The content that should be in place (e.g. freshly
+    ///   generated templates).
+    ///
+    /// # Returns
+    ///
+    /// The differences between both contents, or `None` if they are equal.
+    fn diff(&self, actual: &str, expected: &str) -> Option<String>;
+}
+
+/// Content differ implementation producing unified diffs.
+///
+/// Lines only found in the actual content are prefixed by `-`, while lines
+/// only found in the expected content are prefixed by `+`.
+pub struct UnifiedDiffer {
+    /// The name of the actual content, shown in the `---` diff header.
+    pub actual_label: String,
+
+    /// The name of the expected content, shown in the `+++` diff header.
+    pub expected_label: String,
+}
@@ -0,0 +1,32 @@
+use similar::TextDiff;
+
+use crate::diff::{ContentDiffer, UnifiedDiffer};
+
+impl UnifiedDiffer {
+    pub fn new(actual_label: &str, expected_label: &str) -> Self {
+        Self {
+            actual_label: actual_label.to_string(),
+            expected_label: expected_label.to_string(),
+        }
+    }
+}
+
+impl ContentDiffer for UnifiedDiffer {
+    /// Computes a line-based unified diff between given contents, with
+    /// three lines of context around each change.
+    ///
+    /// See [`ContentDiffer::diff`] for more infos.
+    fn diff(&self, actual: &str, expected: &str) -> Option<String> {
+        if actual == expected {
+            return None;
+        }
+
+        let diff = TextDiff::from_lines(actual, expected)
+            .unified_diff()
+            .context_radius(3)
+            .header(&self.actual_label, &self.expected_label)
+            .to_string();
+
+        Some(diff.trim_end().to_string())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::diff::{ContentDiffer, UnifiedDiffer};
+
+mod unified_differ {
+    use super::*;
+
+    mod diff {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_returns_none_when_equal_contents() {
+                let differ = UnifiedDiffer::new(".gitignore", "generated");
+
+                let actual = differ.diff("target/\n", "target/\n");
+                let expected = None;
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_returns_unified_diff_when_different_contents() {
+                let differ = UnifiedDiffer::new(".gitignore", "generated");
+
+                let actual = differ.diff("target/\n*.log\n", "target/\nCargo.lock\n");
+                let expected = Some(String::from(
+                    "--- .gitignore\n+++ generated\n@@ -1,2 +1,2 @@\n target/\n-*.log\n+Cargo.lock",
+                ));
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_reports_missing_trailing_newline() {
+                let differ = UnifiedDiffer::new(".gitignore", "generated");
+
+                let actual = differ.diff("target/", "target/\n");
+
+                assert!(actual.is_some());
+            }
+        }
+    }
+}
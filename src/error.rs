@@ -0,0 +1,82 @@
+//! Define the typed errors raised while managing gitignore templates.
+//!
+//! Library components report failures as [`Error`], each variant mapping to
+//! a deterministic exit status (see [`Error::exit_status`]). The CLI turns
+//! them into a [`ProgramExit`](crate::core::ProgramExit) right before
+//! exiting.
+use std::{env::VarError, io, sync::Arc};
+
+use crate::{core::ExitKind, http_client::HttpError};
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Enum for errors raised while managing gitignore templates.
+///
+/// Messages are rendered from the [`error_messages`](crate::constant::error_messages)
+/// constants, and already describe the underlying cause, if any. That cause
+/// is nonetheless kept, and exposed through [`std::error::Error::source`]
+/// (the first cause for an [`Error::Aggregate`], see [`Error::causes`] for
+/// all of them).
+#[derive(Clone, Debug)]
+pub enum Error {
+    /// The HTTP call to the remote API failed.
+    Http(HttpError),
+
+    /// The remote API answered with content that could not be parsed.
+    InvalidResponse(String),
+
+    /// Some template names are not served by any template source.
+    TemplateNotFound(String),
+
+    /// A local template could not be edited (e.g. already existing, located
+    /// outside of the template directory...).
+    TemplateEdition(String),
+
+    /// A file system operation failed.
+    Io {
+        message: String,
+        source: Arc<io::Error>,
+    },
+
+    /// An environment variable could not be read.
+    Env { message: String, source: VarError },
+
+    /// Given input is invalid (e.g. malformed lockfile, invalid search
+    /// pattern...).
+    InvalidInput(String),
+
+    /// Some locked templates changed upstream.
+    LockMismatch(String),
+
+    /// A compared file, verified file or linted template does not match
+    /// expectations.
+    ///
+    /// Holds the report explaining the mismatch, meant to be printed on
+    /// stdout.
+    Mismatch { report: String, exit_status: i32 },
+
+    /// Given cli args are invalid, as rendered by [`clap`] along with its
+    /// ANSI-styled version.
+    Cli {
+        message: String,
+        styled_message: String,
+        exit_status: i32,
+    },
+
+    /// The program exits early to print infos (i.e. help, version or author
+    /// infos), exiting successfully.
+    EarlyExit {
+        message: String,
+        styled_message: Option<String>,
+        kind: ExitKind,
+    },
+
+    /// Several errors occurred (e.g. one per failing template source).
+    ///
+    /// Built through [`Error::aggregate`], which guarantees it holds at
+    /// least two causes, none of them being an aggregate.
+    Aggregate(Vec<Error>),
+}
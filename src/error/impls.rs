@@ -0,0 +1,253 @@
+use std::{io, sync::Arc};
+
+use clap::{Error as ClapError, error::ErrorKind as ClapErrorKind};
+
+use crate::{
+    constant::exit_status,
+    core::{ExitKind, ProgramExit},
+    error::Error,
+    http_client::HttpError,
+    printer::{Data, DataPrinter, DefaultDataPrinter},
+};
+
+impl Error {
+    /// Builds a file system error holding given io error as its cause.
+    pub fn io(message: &str, source: io::Error) -> Self {
+        Self::Io {
+            message: message.to_string(),
+            source: Arc::new(source),
+        }
+    }
+
+    /// Builds an error out of given [`clap`] error.
+    ///
+    /// A help display request turns into an [`Error::EarlyExit`], while
+    /// any other error turns into an [`Error::Cli`] hinting at the help
+    /// option.
+    pub fn from_clap_error(error: &ClapError) -> Self {
+        if error.kind() == ClapErrorKind::DisplayHelp {
+            let rendered_help = error.render();
+
+            return Self::EarlyExit {
+                message: rendered_help.to_string().trim_end().to_string(),
+                styled_message: Some(rendered_help.ansi().to_string().trim_end().to_string()),
+                kind: ExitKind::HelpInfos,
+            };
+        }
+
+        Self::Cli {
+            message: DefaultDataPrinter::ppg(&Data::ClapError(error)),
+            styled_message: DefaultDataPrinter::ppg(&Data::StyledClapError(error)),
+            exit_status: error.exit_code(),
+        }
+    }
+
+    /// Combines given errors into a single one.
+    ///
+    /// Nested aggregates are flattened, and a single error is returned as
+    /// is.
+    ///
+    /// # Returns
+    ///
+    /// The combined error, or `None` if given errors are empty.
+    pub fn aggregate(errors: Vec<Error>) -> Option<Self> {
+        let mut causes: Vec<Error> = errors
+            .into_iter()
+            .flat_map(|error| match error {
+                Self::Aggregate(causes) => causes,
+                error => vec![error],
+            })
+            .collect();
+
+        match causes.len() {
+            0 => None,
+            1 => Some(causes.remove(0)),
+            _ => Some(Self::Aggregate(causes)),
+        }
+    }
+
+    /// Gets the underlying errors of this error, i.e. the aggregated ones
+    /// for an [`Error::Aggregate`], or this error alone otherwise.
+    pub fn causes(&self) -> &[Error] {
+        match self {
+            Self::Aggregate(causes) => causes,
+            error => std::slice::from_ref(error),
+        }
+    }
+
+    /// Gets the ANSI-styled message of this error, if any.
+    ///
+    /// Same as the displayed message but styled.
+    pub fn styled_message(&self) -> Option<&str> {
+        match self {
+            Self::Cli { styled_message, .. } => Some(styled_message),
+            Self::EarlyExit { styled_message, .. } => styled_message.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Gets the exit status matching this error.
+    ///
+    /// An aggregate exits with the status shared by all of its causes, or
+    /// with [`exit_status::GENERIC`] if they disagree.
+    pub fn exit_status(&self) -> i32 {
+        match self {
+            Self::Http(error) => error.exit_status(),
+            Self::InvalidResponse(_) => exit_status::BODY_PARSING_ISSUE,
+            Self::TemplateNotFound(_)
+            | Self::TemplateEdition(_)
+            | Self::Io { .. }
+            | Self::Env { .. }
+            | Self::InvalidInput(_) => exit_status::GENERIC,
+            Self::LockMismatch(_) => exit_status::LOCK_MISMATCH,
+            Self::Mismatch { exit_status, .. } | Self::Cli { exit_status, .. } => *exit_status,
+            Self::EarlyExit { .. } => exit_status::SUCCESS,
+            Self::Aggregate(causes) => {
+                let mut exit_statuses = causes.iter().map(Error::exit_status);
+                let first_exit_status = exit_statuses.next().unwrap_or(exit_status::GENERIC);
+
+                if exit_statuses.all(|exit_status| exit_status == first_exit_status) {
+                    first_exit_status
+                } else {
+                    exit_status::GENERIC
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Http(error) => write!(f, "{error}"),
+            Self::InvalidResponse(message)
+            | Self::TemplateNotFound(message)
+            | Self::TemplateEdition(message)
+            | Self::Io { message, .. }
+            | Self::Env { message, .. }
+            | Self::InvalidInput(message)
+            | Self::LockMismatch(message)
+            | Self::Mismatch {
+                report: message, ..
+            }
+            | Self::Cli { message, .. }
+            | Self::EarlyExit { message, .. } => write!(f, "{message}"),
+            Self::Aggregate(causes) => {
+                let messages: Vec<String> = causes.iter().map(Error::to_string).collect();
+                write!(f, "{}", messages.join("\n"))
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Http(error) => Some(error),
+            Self::Io { source, .. } => Some(source.as_ref()),
+            Self::Env { source, .. } => Some(source),
+            Self::Aggregate(causes) => causes
+                .first()
+                .map(|cause| cause as &(dyn std::error::Error + 'static)),
+            _ => None,
+        }
+    }
+}
+
+/// Compares errors by variant and message, io errors being compared by
+/// kind only.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Http(error), Self::Http(other_error)) => error == other_error,
+            (Self::InvalidResponse(message), Self::InvalidResponse(other_message))
+            | (Self::TemplateNotFound(message), Self::TemplateNotFound(other_message))
+            | (Self::TemplateEdition(message), Self::TemplateEdition(other_message))
+            | (Self::InvalidInput(message), Self::InvalidInput(other_message))
+            | (Self::LockMismatch(message), Self::LockMismatch(other_message)) => {
+                message == other_message
+            }
+            (
+                Self::Mismatch {
+                    report,
+                    exit_status,
+                },
+                Self::Mismatch {
+                    report: other_report,
+                    exit_status: other_exit_status,
+                },
+            ) => report == other_report && exit_status == other_exit_status,
+            (
+                Self::Cli {
+                    message,
+                    styled_message,
+                    exit_status,
+                },
+                Self::Cli {
+                    message: other_message,
+                    styled_message: other_styled_message,
+                    exit_status: other_exit_status,
+                },
+            ) => {
+                message == other_message
+                    && styled_message == other_styled_message
+                    && exit_status == other_exit_status
+            }
+            (
+                Self::EarlyExit {
+                    message,
+                    styled_message,
+                    kind,
+                },
+                Self::EarlyExit {
+                    message: other_message,
+                    styled_message: other_styled_message,
+                    kind: other_kind,
+                },
+            ) => {
+                message == other_message
+                    && styled_message == other_styled_message
+                    && kind == other_kind
+            }
+            (
+                Self::Io { message, source },
+                Self::Io {
+                    message: other_message,
+                    source: other_source,
+                },
+            ) => message == other_message && source.kind() == other_source.kind(),
+            (
+                Self::Env { message, source },
+                Self::Env {
+                    message: other_message,
+                    source: other_source,
+                },
+            ) => message == other_message && source == other_source,
+            (Self::Aggregate(causes), Self::Aggregate(other_causes)) => causes == other_causes,
+            _ => false,
+        }
+    }
+}
+
+impl From<HttpError> for Error {
+    fn from(error: HttpError) -> Self {
+        Self::Http(error)
+    }
+}
+
+impl From<Error> for ProgramExit {
+    fn from(error: Error) -> Self {
+        let kind = match &error {
+            Error::EarlyExit { kind, .. } => kind.clone(),
+            Error::Mismatch { .. } => ExitKind::Mismatch,
+            _ => ExitKind::Error,
+        };
+
+        Self {
+            message: error.to_string(),
+            exit_status: error.exit_status(),
+            styled_message: error.styled_message().map(String::from),
+            kind,
+        }
+    }
+}
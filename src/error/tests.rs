@@ -0,0 +1,218 @@
+use std::{error::Error as _, io};
+
+use rstest::rstest;
+
+use crate::{
+    constant,
+    core::{ExitKind, ProgramExit},
+    error::Error,
+    http_client::HttpError,
+};
+
+mod error {
+    use super::*;
+
+    mod aggregate {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_returns_single_error_as_is() {
+                let error = Error::InvalidInput(String::from("oops"));
+
+                let actual = Error::aggregate(vec![error.clone()]);
+
+                assert_eq!(actual, Some(error));
+            }
+
+            #[test]
+            fn it_keeps_each_cause_and_flattens_nested_aggregates() {
+                let first_error = Error::InvalidInput(String::from("first"));
+                let second_error = Error::Http(HttpError::Timeout(String::from("second")));
+                let third_error = Error::TemplateNotFound(String::from("third"));
+
+                let actual = Error::aggregate(vec![
+                    Error::aggregate(vec![first_error.clone(), second_error.clone()]).unwrap(),
+                    third_error.clone(),
+                ])
+                .unwrap();
+                let expected = vec![first_error, second_error, third_error];
+
+                assert_eq!(actual.causes(), expected.as_slice());
+                assert_eq!(actual.to_string(), "first\nsecond\nthird");
+            }
+
+            #[test]
+            fn it_returns_none_for_empty_errors() {
+                let actual = Error::aggregate(Vec::new());
+
+                assert_eq!(actual, None);
+            }
+        }
+    }
+
+    mod exit_status {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case(
+                Error::Http(HttpError::DnsFailure(String::from("oops"))),
+                constant::exit_status::DNS_FAILURE
+            )]
+            #[case(
+                Error::Http(HttpError::ServerStatus { status: 500, body: String::new() }),
+                constant::exit_status::HTTP_SERVER_STATUS
+            )]
+            #[case(
+                Error::InvalidResponse(String::from("oops")),
+                constant::exit_status::BODY_PARSING_ISSUE
+            )]
+            #[case(
+                Error::TemplateNotFound(String::from("oops")),
+                constant::exit_status::GENERIC
+            )]
+            #[case(
+                Error::io("oops", io::Error::from(io::ErrorKind::PermissionDenied)),
+                constant::exit_status::GENERIC
+            )]
+            fn it_maps_error_to_its_exit_status(#[case] error: Error, #[case] expected: i32) {
+                let actual = error.exit_status();
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_keeps_exit_status_shared_by_all_aggregated_errors() {
+                let error = Error::aggregate(vec![
+                    Error::InvalidInput(String::from("first")),
+                    Error::TemplateEdition(String::from("second")),
+                ])
+                .unwrap();
+
+                let actual = error.exit_status();
+
+                assert_eq!(actual, constant::exit_status::GENERIC);
+            }
+
+            #[test]
+            fn it_falls_back_to_generic_exit_status_for_mixed_aggregated_errors() {
+                let error = Error::aggregate(vec![
+                    Error::InvalidInput(String::from("first")),
+                    Error::Http(HttpError::Transport(String::from("second"))),
+                ])
+                .unwrap();
+
+                let actual = error.exit_status();
+
+                assert_eq!(actual, constant::exit_status::GENERIC);
+            }
+        }
+    }
+
+    mod source {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_exposes_underlying_io_error() {
+                let error = Error::io(
+                    "Failed to read",
+                    io::Error::new(io::ErrorKind::PermissionDenied, "denied"),
+                );
+
+                let actual = error.source().map(ToString::to_string);
+
+                assert_eq!(actual, Some(String::from("denied")));
+            }
+
+            #[test]
+            fn it_exposes_first_aggregated_error() {
+                let error = Error::aggregate(vec![
+                    Error::InvalidInput(String::from("first")),
+                    Error::TemplateNotFound(String::from("second")),
+                ])
+                .unwrap();
+
+                let actual = error.source().map(ToString::to_string);
+
+                assert_eq!(actual, Some(String::from("first")));
+            }
+
+            #[test]
+            fn it_has_no_source_for_plain_error() {
+                let error = Error::TemplateNotFound(String::from("oops"));
+
+                let actual = error.source();
+
+                assert!(actual.is_none());
+            }
+        }
+    }
+
+    mod into_program_exit {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_renders_message_and_exit_status() {
+                let error = Error::Http(HttpError::Transport(String::from("all bad")));
+
+                let actual = ProgramExit::from(error);
+                let expected = ProgramExit {
+                    message: String::from("An error occurred during the API call: all bad"),
+                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                };
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_renders_mismatch_report_as_mismatch() {
+                let error = Error::Mismatch {
+                    report: String::from("- foo\n+ bar"),
+                    exit_status: constant::exit_status::DIFF_MISMATCH,
+                };
+
+                let actual = ProgramExit::from(error);
+                let expected = ProgramExit {
+                    message: String::from("- foo\n+ bar"),
+                    exit_status: constant::exit_status::DIFF_MISMATCH,
+                    styled_message: None,
+                    kind: ExitKind::Mismatch,
+                };
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_renders_early_exit_as_success() {
+                let error = Error::EarlyExit {
+                    message: String::from("infos"),
+                    styled_message: Some(String::from("styled infos")),
+                    kind: ExitKind::VersionInfos,
+                };
+
+                let actual = ProgramExit::from(error);
+                let expected = ProgramExit {
+                    message: String::from("infos"),
+                    exit_status: constant::exit_status::SUCCESS,
+                    styled_message: Some(String::from("styled infos")),
+                    kind: ExitKind::VersionInfos,
+                };
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
@@ -25,10 +25,11 @@ pub trait FileSystemHandler {
     /// privilege...).
     fn fetch_content(&self, file_name: &str) -> Result<String, Error>;
 
-    /// List files.
+    /// List files, without their extension.
     ///
     /// File location is not taken into consideration here. It is up to
-    /// the struct implementing this trait to take that decision.
+    /// the struct implementing this trait to take that decision. Nested
+    /// files are listed with their path (e.g. `languages/rust`).
     ///
     /// # Returns
     ///
@@ -36,4 +37,61 @@ pub trait FileSystemHandler {
     /// [`std::io::Error`] on error (e.g. file system failure, insufficient
     /// privilege...).
     fn list_files(&self) -> Result<Vec<String>, Error>;
+
+    /// Finds the file matching given name without extension.
+    ///
+    /// An exact match is preferred. Otherwise, a match ignoring case is
+    /// looked up unless strict, then a nested file with the same trailing
+    /// name (e.g. `rust` matches `languages/rust`).
+    ///
+    /// # Arguments
+    ///
+    /// * `file_stem` - The name of the file to be found, without extension
+    ///
+    /// # Returns
+    ///
+    /// A result containing the found file name, extension included, or a
+    /// [`std::io::Error`] on error (e.g. file not found, file system
+    /// failure...).
+    fn find_file(&self, file_stem: &str) -> Result<String, Error>;
+
+    /// Writes given content to the file, creating it along with its parent
+    /// directories if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The name of the file to be written
+    /// * `content` - The content to be written
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or a [`std::io::Error`] on error (e.g. file system
+    /// failure, insufficient privilege...).
+    fn write_content(&self, file_name: &str, content: &str) -> Result<(), Error>;
+
+    /// Removes the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The name of the file to be removed
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or a [`std::io::Error`] on error (e.g. file not
+    /// found, insufficient privilege...).
+    fn remove_file(&self, file_name: &str) -> Result<(), Error>;
+
+    /// Renames the file, creating parent directories of the new name if
+    /// needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The name of the file to be renamed
+    /// * `new_file_name` - The new name of the file
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or a [`std::io::Error`] on error (e.g. file not
+    /// found, insufficient privilege...).
+    fn rename_file(&self, file_name: &str, new_file_name: &str) -> Result<(), Error>;
 }
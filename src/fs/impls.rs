@@ -1,14 +1,145 @@
-use std::{fs, io::Error};
+use std::{
+    fs,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+};
 
 use super::FileSystemHandler;
+use crate::matcher::GitignoreMatcher;
 
 pub struct DirectoryHandler<'a> {
     pub directory_path: &'a str,
+    pub extensions: Vec<String>,
+    pub excluded: GitignoreMatcher,
+    pub strict: bool,
 }
 
 impl<'a> DirectoryHandler<'a> {
     pub fn new(directory_path: &'a str) -> Self {
-        Self { directory_path }
+        Self {
+            directory_path,
+            extensions: Vec::new(),
+            excluded: GitignoreMatcher::default(),
+            strict: false,
+        }
+    }
+
+    /// Restricts handled files to the ones having any of given extensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `extensions` - The file extensions to be handled, with or without
+    ///   leading dot. All files are handled if empty.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_extensions(mut self, extensions: &[String]) -> Self {
+        self.extensions = extensions
+            .iter()
+            .map(|extension| extension.trim_start_matches('.').to_string())
+            .collect();
+        self
+    }
+
+    /// Excludes files whose name matches any of given patterns.
+    ///
+    /// # Arguments
+    ///
+    /// * `excluded` - The gitignore patterns of files to be excluded,
+    ///   matched against the file path relative to the directory, without
+    ///   extension (see [`GitignoreMatcher`]).
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_excluded(mut self, excluded: &[String]) -> Self {
+        self.excluded = GitignoreMatcher::parse(&excluded.join("\n"));
+        self
+    }
+
+    /// Sets whether files must be found by their exact name, case included.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether to disable case-insensitive matching.
+    ///
+    /// # Returns
+    ///
+    /// The mutated instance.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Lists handled files of the directory and its sub-directories, as
+    /// pairs of file path without extension and file path, both relative
+    /// to the directory, sorted by path. Hidden files and directories are
+    /// skipped.
+    fn list_entries(&self) -> Result<Vec<(String, String)>, Error> {
+        let mut entries = Vec::new();
+        self.collect_entries(Path::new(self.directory_path), "", &mut entries)?;
+        entries.sort();
+
+        Ok(entries)
+    }
+
+    fn collect_entries(
+        &self,
+        directory: &Path,
+        prefix: &str,
+        entries: &mut Vec<(String, String)>,
+    ) -> Result<(), Error> {
+        for entry in fs::read_dir(directory)? {
+            let entry_path = entry?.path();
+            let Some(file_name) = entry_path.file_name().map(|name| name.to_string_lossy()) else {
+                continue;
+            };
+
+            if file_name.starts_with('.') {
+                continue;
+            }
+
+            if entry_path.is_dir() {
+                self.collect_entries(&entry_path, &format!("{prefix}{file_name}/"), entries)?;
+            } else if entry_path.is_file()
+                && self.has_handled_extension(&entry_path)
+                && let Some(file_stem) = entry_path.file_stem()
+            {
+                let file_stem = format!("{prefix}{}", file_stem.to_string_lossy());
+
+                if !self.is_excluded(&file_stem) {
+                    entries.push((file_stem, format!("{prefix}{file_name}")));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn has_handled_extension(&self, file_path: &Path) -> bool {
+        self.extensions.is_empty()
+            || file_path.extension().is_some_and(|extension| {
+                self.extensions
+                    .iter()
+                    .any(|handled| handled.as_str() == extension.to_string_lossy())
+            })
+    }
+
+    /// Resolves given file name against the directory, creating parent
+    /// directories of the file if needed.
+    fn prepare_file_path(&self, file_name: &str) -> Result<PathBuf, Error> {
+        let file_path = Path::new(self.directory_path).join(file_name);
+
+        if let Some(parent_directory) = file_path.parent() {
+            fs::create_dir_all(parent_directory)?;
+        }
+
+        Ok(file_path)
+    }
+
+    fn is_excluded(&self, file_stem: &str) -> bool {
+        self.excluded.explain(file_stem).is_ignored()
     }
 }
 
@@ -18,18 +149,54 @@ impl FileSystemHandler for DirectoryHandler<'_> {
     }
 
     fn list_files(&self) -> Result<Vec<String>, Error> {
-        let mut result: Vec<String> = Vec::new();
-
-        for entry in fs::read_dir(self.directory_path)? {
-            let entry_path = entry?.path();
+        Ok(self
+            .list_entries()?
+            .into_iter()
+            .map(|(file_stem, _)| file_stem)
+            .collect())
+    }
 
-            if entry_path.is_file() {
-                if let Some(file_stem) = entry_path.file_stem() {
-                    result.push(file_stem.to_string_lossy().to_string());
-                }
+    fn find_file(&self, file_stem: &str) -> Result<String, Error> {
+        let entries = self.list_entries()?;
+        let normalize = |stem: &str| {
+            if self.strict {
+                stem.to_string()
+            } else {
+                stem.to_ascii_lowercase()
             }
-        }
+        };
+        let normalized_stem = normalize(file_stem);
+        let nested_suffix = format!("/{normalized_stem}");
+
+        entries
+            .iter()
+            .find(|(entry_stem, _)| entry_stem == file_stem)
+            .or_else(|| {
+                entries
+                    .iter()
+                    .find(|(entry_stem, _)| normalize(entry_stem) == normalized_stem)
+            })
+            .or_else(|| {
+                entries
+                    .iter()
+                    .find(|(entry_stem, _)| normalize(entry_stem).ends_with(&nested_suffix))
+            })
+            .map(|(_, file_name)| file_name.clone())
+            .ok_or_else(|| Error::from(ErrorKind::NotFound))
+    }
+
+    fn write_content(&self, file_name: &str, content: &str) -> Result<(), Error> {
+        fs::write(self.prepare_file_path(file_name)?, content)
+    }
+
+    fn remove_file(&self, file_name: &str) -> Result<(), Error> {
+        fs::remove_file(Path::new(self.directory_path).join(file_name))
+    }
 
-        Ok(result)
+    fn rename_file(&self, file_name: &str, new_file_name: &str) -> Result<(), Error> {
+        fs::rename(
+            Path::new(self.directory_path).join(file_name),
+            self.prepare_file_path(new_file_name)?,
+        )
     }
 }
@@ -1,4 +1,4 @@
-use std::io::ErrorKind;
+use std::{env, fs, io::ErrorKind};
 
 use crate::{
     fs::{FileSystemHandler, impls::DirectoryHandler},
@@ -8,6 +8,16 @@ use crate::{
 mod directory_handler {
     use super::*;
 
+    fn temp_directory(name: &str) -> String {
+        let directory_path = env::temp_dir().join(format!(
+            "gitignore_template_generator_fs_{name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory_path);
+
+        directory_path.to_string_lossy().to_string()
+    }
+
     mod fetch_content {
         use super::*;
 
@@ -69,6 +79,39 @@ mod directory_handler {
                 let actual_list = actual_list.unwrap();
                 assert_eq!(actual_list, expected_list);
             }
+
+            #[test]
+            fn it_lists_nested_files_having_given_extensions() {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let directory_handler = DirectoryHandler::new(&template_dir)
+                    .with_extensions(&DefaultTestUtils::to_string_list("txt .gitignore"));
+
+                let expected_list =
+                    DefaultTestUtils::to_string_list("drafts/wip languages/rust tools/vim");
+                let actual_list = directory_handler.list_files();
+
+                assert!(actual_list.is_ok());
+
+                let actual_list = actual_list.unwrap();
+                assert_eq!(actual_list, expected_list);
+            }
+
+            #[test]
+            fn it_skips_files_matching_excluded_patterns() {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let directory_handler = DirectoryHandler::new(&template_dir)
+                    .with_excluded(&DefaultTestUtils::to_string_list("drafts/* READ?E"));
+
+                let expected_list = DefaultTestUtils::to_string_list("languages/rust tools/vim");
+                let actual_list = directory_handler.list_files();
+
+                assert!(actual_list.is_ok());
+
+                let actual_list = actual_list.unwrap();
+                assert_eq!(actual_list, expected_list);
+            }
         }
 
         mod failure {
@@ -89,4 +132,182 @@ mod directory_handler {
             }
         }
     }
+
+    mod find_file {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_finds_file_by_its_path_without_extension() {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let directory_handler = DirectoryHandler::new(&template_dir);
+
+                let actual_file_name = directory_handler.find_file("tools/vim");
+
+                assert!(actual_file_name.is_ok());
+                assert_eq!(actual_file_name.unwrap(), "tools/vim.gitignore");
+            }
+
+            #[test]
+            fn it_finds_nested_file_by_its_bare_name() {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let directory_handler = DirectoryHandler::new(&template_dir);
+
+                let actual_file_name = directory_handler.find_file("rust");
+
+                assert!(actual_file_name.is_ok());
+                assert_eq!(actual_file_name.unwrap(), "languages/rust.txt");
+            }
+
+            #[test]
+            fn it_finds_file_ignoring_case() {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let directory_handler = DirectoryHandler::new(&template_dir);
+
+                let actual_file_name = directory_handler.find_file("Tools/Vim");
+
+                assert!(actual_file_name.is_ok());
+                assert_eq!(actual_file_name.unwrap(), "tools/vim.gitignore");
+            }
+
+            #[test]
+            fn it_finds_nested_file_by_its_bare_name_ignoring_case() {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let directory_handler = DirectoryHandler::new(&template_dir);
+
+                let actual_file_name = directory_handler.find_file("Rust");
+
+                assert!(actual_file_name.is_ok());
+                assert_eq!(actual_file_name.unwrap(), "languages/rust.txt");
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            fn it_fails_if_file_has_unhandled_extension() {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let directory_handler = DirectoryHandler::new(&template_dir)
+                    .with_extensions(&DefaultTestUtils::to_string_list("txt"));
+
+                let expected_error_kind = ErrorKind::NotFound;
+                let actual_error = directory_handler.find_file("README");
+
+                assert!(actual_error.is_err());
+
+                let actual_error = actual_error.unwrap_err();
+                assert_eq!(actual_error.kind(), expected_error_kind);
+            }
+
+            #[test]
+            fn it_fails_if_case_differs_when_strict() {
+                let template_dir =
+                    DefaultTestUtils::get_resource_file_path("categorized_templates");
+                let directory_handler = DirectoryHandler::new(&template_dir).with_strict(true);
+
+                let expected_error_kind = ErrorKind::NotFound;
+                let actual_error = directory_handler.find_file("Rust");
+
+                assert!(actual_error.is_err());
+
+                let actual_error = actual_error.unwrap_err();
+                assert_eq!(actual_error.kind(), expected_error_kind);
+            }
+        }
+    }
+
+    mod write_content {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_writes_content_creating_parent_directories() {
+                let template_dir = temp_directory("write");
+                let directory_handler = DirectoryHandler::new(&template_dir);
+
+                let actual_result = directory_handler.write_content("tools/vim.txt", "*.swp\n");
+
+                assert!(actual_result.is_ok());
+                assert_eq!(
+                    directory_handler.fetch_content("tools/vim.txt").unwrap(),
+                    "*.swp\n"
+                );
+            }
+        }
+    }
+
+    mod remove_file {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_removes_file() {
+                let template_dir = temp_directory("remove");
+                let directory_handler = DirectoryHandler::new(&template_dir);
+                directory_handler
+                    .write_content("vim.txt", "*.swp\n")
+                    .unwrap();
+
+                let actual_result = directory_handler.remove_file("vim.txt");
+
+                assert!(actual_result.is_ok());
+                assert!(directory_handler.list_files().unwrap().is_empty());
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            fn it_fails_if_file_does_not_exist() {
+                let template_dir = temp_directory("remove_inexistent");
+                let directory_handler = DirectoryHandler::new(&template_dir);
+
+                let expected_error_kind = ErrorKind::NotFound;
+                let actual_error = directory_handler.remove_file("vim.txt");
+
+                assert!(actual_error.is_err());
+
+                let actual_error = actual_error.unwrap_err();
+                assert_eq!(actual_error.kind(), expected_error_kind);
+            }
+        }
+    }
+
+    mod rename_file {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_renames_file_into_new_directory() {
+                let template_dir = temp_directory("rename");
+                let directory_handler = DirectoryHandler::new(&template_dir);
+                directory_handler
+                    .write_content("vim.txt", "*.swp\n")
+                    .unwrap();
+
+                let actual_result = directory_handler.rename_file("vim.txt", "tools/vim.txt");
+
+                assert!(actual_result.is_ok());
+                assert_eq!(
+                    directory_handler.list_files().unwrap(),
+                    DefaultTestUtils::to_string_list("tools/vim")
+                );
+            }
+        }
+    }
 }
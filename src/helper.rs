@@ -1,8 +1,6 @@
 //! Define components to help in other modules.
 //!
 //! Generic place to put helper code.
-use clap::ValueEnum;
-
 use crate::core::QualifiedString;
 
 mod impls;
@@ -10,12 +8,10 @@ mod impls;
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Copy, Debug, ValueEnum, PartialEq)]
-pub enum TimeoutUnit {
-    MILLISECOND,
-    SECOND,
-}
-
+/// Short and long specifiers of a cli option.
+///
+/// An empty `short` specifier means the option can only be given in its
+/// long form.
 pub struct CliOptionName {
     pub short: &'static str,
     pub long: &'static str,
@@ -25,6 +21,17 @@ pub trait Utils {
     fn capitalize(s: &str) -> String;
     fn insert_at(l: &mut Vec<QualifiedString>, idx: usize, val: QualifiedString);
     fn to_char(s: &str) -> char;
+
+    /// Formats given unix timestamp as an UTC date (e.g. `2025-04-20`).
+    fn format_date(unix_seconds: u64) -> String;
+
+    /// Joins given url path to given base url with a single slash in
+    /// between, whatever the slashes they start or end with (e.g.
+    /// `https://localhost/` and `api/list` give `https://localhost/api/list`).
+    ///
+    /// An empty base url leaves given path untouched, so that a full url
+    /// can be given as path.
+    fn join_url(base_url: &str, path: &str) -> String;
 }
 
 pub struct DefaultUtils;
@@ -25,4 +25,37 @@ impl Utils for DefaultUtils {
             _ => '\u{000}',
         }
     }
+
+    fn format_date(unix_seconds: u64) -> String {
+        // civil date from days since epoch, see
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = (unix_seconds / 86_400) as i64 + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+
+    fn join_url(base_url: &str, path: &str) -> String {
+        if base_url.is_empty() {
+            return path.to_string();
+        }
+
+        format!(
+            "{}/{}",
+            base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
 }
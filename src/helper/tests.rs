@@ -1 +1,54 @@
+use crate::helper::{DefaultUtils, Utils};
 
+mod default_utils {
+    use super::*;
+
+    mod format_date {
+        use super::*;
+
+        mod success {
+            use rstest::rstest;
+
+            use super::*;
+
+            #[rstest]
+            #[case(0, "1970-01-01")]
+            #[case(951_782_400, "2000-02-29")]
+            #[case(1_745_150_400, "2025-04-20")]
+            fn it_formats_unix_timestamp_as_utc_date(
+                #[case] unix_seconds: u64,
+                #[case] expected: &str,
+            ) {
+                let actual = DefaultUtils::format_date(unix_seconds);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod join_url {
+        use super::*;
+
+        mod success {
+            use rstest::rstest;
+
+            use super::*;
+
+            #[rstest]
+            #[case("https://localhost", "/api/list", "https://localhost/api/list")]
+            #[case("https://localhost/", "/api/list", "https://localhost/api/list")]
+            #[case("https://localhost", "api/list", "https://localhost/api/list")]
+            #[case("https://localhost/", "api/list", "https://localhost/api/list")]
+            #[case("", "https://localhost/api/list", "https://localhost/api/list")]
+            fn it_joins_path_to_base_url_with_single_slash(
+                #[case] base_url: &str,
+                #[case] path: &str,
+                #[case] expected: &str,
+            ) {
+                let actual = DefaultUtils::join_url(base_url, path);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
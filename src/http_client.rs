@@ -1,7 +1,7 @@
 //! Define components to make HTTP calls.
-use std::{collections::HashMap, time::Duration};
+use std::{cell::RefCell, collections::HashMap, time::Duration};
 
-use crate::core::ProgramExit;
+use crate::{cache::ResponseCache, error::Error};
 
 mod impls;
 
@@ -10,16 +10,102 @@ mod tests;
 
 /// Http client trait to make HTTP calls.
 pub trait HttpClient {
+    /// Make a GET HTTP call to given url with given extra request headers.
+    ///
+    /// Any response received from the server is returned as is, whatever
+    /// its status code. Use [`HttpResponse::error_for_status`] to turn 4xx
+    /// and 5xx responses into errors.
+    ///
+    /// # Arguments
+    /// * `url` - the url to which to make the HTTP call.
+    /// * `headers` - the extra request headers, as name/value pairs.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the response of HTTP call if one was received,
+    /// or an [`HttpError`] on error (e.g. network issues, timeout...).
+    fn fetch(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, HttpError>;
+
     /// Make a GET HTTP call to given url.
     ///
+    /// Rely on [`HttpClient::fetch`], decoding the response body as a
+    /// trimmed utf-8 string.
+    ///
     /// # Arguments
     /// * `url` - the url to which to make the HTTP call.
     ///
     /// # Returns
     ///
     /// A result containing the response body of HTTP call if successful, or
-    /// a [`ProgramExit`] on error (e.g. 4xx, network issues...).
-    fn get(&self, url: &str) -> Result<String, ProgramExit>;
+    /// an [`Error::Http`] on error (e.g. 4xx, network issues...).
+    fn get(&self, url: &str) -> Result<String, Error> {
+        self.fetch(url, &[])
+            .and_then(HttpResponse::error_for_status)
+            .and_then(|response| response.text())
+            .map(|body| body.trim().to_string())
+            .map_err(Error::from)
+    }
+}
+
+/// DTO struct representing a response to an HTTP call.
+#[derive(Clone, PartialEq, Debug)]
+pub struct HttpResponse {
+    /// The response status code.
+    pub status: u16,
+
+    /// The response headers, keyed by lowercase header name.
+    pub headers: HashMap<String, String>,
+
+    /// The raw response body.
+    pub body: Vec<u8>,
+
+    /// The url of the response, after any redirect got followed.
+    pub url: String,
+}
+
+/// DTO struct representing an HTTP call received by a mock client.
+#[derive(Clone, PartialEq, Debug)]
+pub struct HttpRequest {
+    /// The url to which the call was made.
+    pub url: String,
+
+    /// The extra request headers, keyed by header name.
+    pub headers: HashMap<String, String>,
+}
+
+/// Enum for errors that can occur while making an HTTP call.
+///
+/// Each variant is mapped to a dedicated error message and exit status
+/// (see [`HttpError::exit_status`]).
+#[derive(Clone, PartialEq, Debug)]
+pub enum HttpError {
+    /// The server host name could not be resolved.
+    DnsFailure(String),
+
+    /// The server actively refused the connection.
+    ConnectionRefused(String),
+
+    /// The TLS handshake with the server failed.
+    TlsFailure(String),
+
+    /// The HTTP call exceeded one of the configured timeouts.
+    Timeout(String),
+
+    /// The server answered with a 4xx status code.
+    ///
+    /// The `body` field holds the response body sent by the server, if any.
+    ClientStatus { status: u16, body: String },
+
+    /// The server answered with a 5xx status code.
+    ///
+    /// The `body` field holds the response body sent by the server, if any.
+    ServerStatus { status: u16, body: String },
+
+    /// The response body could not be decoded (e.g. invalid utf-8).
+    BodyDecoding(String),
+
+    /// Any other error raised by the underlying HTTP client.
+    Transport(String),
 }
 
 /// Http client implementation relying on [`ureq`].
@@ -27,23 +113,60 @@ pub trait HttpClient {
 pub struct UreqHttpClient {
     /// The base url of the HTTP server to reach.
     ///
-    /// Used as base url when calling [`UreqHttpClient::get`] method.
+    /// Used as base url when calling [`UreqHttpClient::fetch`] method.
     pub server_url: String,
 
-    /// The timeout for the entire HTTP call.
+    /// The timeout for the entire HTTP call (i.e. total deadline).
     pub global_timeout: Option<Duration>,
+
+    /// The timeout for establishing the connection to the HTTP server.
+    pub connect_timeout: Option<Duration>,
+
+    /// The timeout for receiving the response status, headers and body.
+    pub read_timeout: Option<Duration>,
+}
+
+/// Http client implementation caching the responses of another client.
+///
+/// Each call is made conditional using the `ETag` and `Last-Modified`
+/// validators of the cached response, if any, so that a `304 Not Modified`
+/// answer can be served from the cache.
+pub struct CachedHttpClient {
+    /// The client used to reach the HTTP server.
+    pub http_client: Box<dyn HttpClient>,
+
+    /// The base url of the HTTP server reached by the linked client.
+    ///
+    /// Prefixed to the called urls to key the cached responses, so that
+    /// responses of different servers never get mixed up.
+    pub server_url: String,
+
+    /// The cache in which responses are stored, keyed by full url.
+    pub cache: Box<dyn ResponseCache>,
 }
 
 /// Http client implementation to mock a response.
+///
+/// Every received call is recorded so that tests can assert on the sent
+/// headers.
 pub struct MockHttpClient {
-    /// The mocked response to be returned when calling [`MockHttpClient::get`]
-    /// method.
-    pub response: Result<String, ProgramExit>,
+    /// The mocked response to be returned when calling
+    /// [`MockHttpClient::fetch`] method.
+    pub response: Result<HttpResponse, HttpError>,
+
+    /// The calls received so far.
+    pub requests: RefCell<Vec<HttpRequest>>,
 }
 
-/// Http client implementation to mock a response.
+/// Http client implementation to mock a response per url.
+///
+/// Every received call is recorded so that tests can assert on the sent
+/// headers.
 pub struct MockEndpointHttpClient {
-    /// The mocked response to be returned when calling [`MockHttpClient::get`]
-    /// method.
-    pub response: HashMap<String, Result<String, ProgramExit>>,
+    /// The mocked responses to be returned when calling
+    /// [`MockEndpointHttpClient::fetch`] method, keyed by url.
+    pub response: HashMap<String, Result<HttpResponse, HttpError>>,
+
+    /// The calls received so far.
+    pub requests: RefCell<Vec<HttpRequest>>,
 }
@@ -1,51 +1,366 @@
-use std::time::Duration;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    io::ErrorKind,
+    time::{Duration, Instant},
+};
 
-use ureq::Agent;
+use ureq::{
+    Agent, Error, ResponseExt,
+    config::Config,
+    http::Uri,
+    unversioned::{
+        resolver::{DefaultResolver, ResolvedSocketAddrs, Resolver},
+        transport::{DefaultConnector, NextTimeout},
+    },
+};
 
 use crate::{
-    constant::{error_messages, exit_status, template_manager},
-    core::{ExitKind, ProgramExit},
-    http_client::{HttpClient, MockEndpointHttpClient, MockHttpClient, UreqHttpClient},
+    cache::CachedResponse,
+    constant::{error_messages, exit_status, log_messages, template_manager},
+    helper::{DefaultUtils, Utils},
+    http_client::{
+        CachedHttpClient, HttpClient, HttpError, HttpRequest, HttpResponse, MockEndpointHttpClient,
+        MockHttpClient, UreqHttpClient,
+    },
+    logger::Logger,
 };
 
+/// Resolver reporting host lookup failures as [`Error::HostNotFound`].
+///
+/// The default resolver forwards the io error raised by the system
+/// resolver, whose kind and message depend on the platform.
+#[derive(Debug, Default)]
+struct LookupResolver(DefaultResolver);
+
+impl Resolver for LookupResolver {
+    fn resolve(
+        &self,
+        uri: &Uri,
+        config: &Config,
+        timeout: NextTimeout,
+    ) -> Result<ResolvedSocketAddrs, Error> {
+        self.0
+            .resolve(uri, config, timeout)
+            .map_err(|error| match error {
+                Error::Io(_) => Error::HostNotFound,
+                error => error,
+            })
+    }
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::DnsFailure(error) => error_messages::DNS_FAILURE.replace("{error}", error),
+            Self::ConnectionRefused(error) => {
+                error_messages::CONNECTION_REFUSED.replace("{error}", error)
+            }
+            Self::TlsFailure(error) => error_messages::TLS_FAILURE.replace("{error}", error),
+            Self::Timeout(error) | Self::BodyDecoding(error) => error.clone(),
+            Self::ClientStatus { status, body } => error_messages::API_CALL_FAILURE
+                .replace("{error}", &Self::status_message(*status, body)),
+            Self::ServerStatus { status, body } => error_messages::API_SERVER_FAILURE
+                .replace("{error}", &Self::status_message(*status, body)),
+            Self::Transport(error) => error_messages::API_CALL_FAILURE.replace("{error}", error),
+        };
+
+        write!(f, "{message}")
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl HttpError {
+    /// Gets the exit status matching this error.
+    pub fn exit_status(&self) -> i32 {
+        match self {
+            Self::DnsFailure(_) => exit_status::DNS_FAILURE,
+            Self::ConnectionRefused(_) => exit_status::CONNECTION_REFUSED,
+            Self::TlsFailure(_) => exit_status::TLS_FAILURE,
+            Self::Timeout(_) => exit_status::TIMEOUT,
+            Self::ClientStatus { .. } => exit_status::HTTP_CLIENT_STATUS,
+            Self::ServerStatus { .. } => exit_status::HTTP_SERVER_STATUS,
+            Self::BodyDecoding(_) => exit_status::BODY_PARSING_ISSUE,
+            Self::Transport(_) => exit_status::HTTP_CLIENT_ERROR,
+        }
+    }
+
+    fn status_message(status: u16, body: &str) -> String {
+        let message = error_messages::HTTP_STATUS.replace("{status}", &status.to_string());
+
+        match body.trim() {
+            "" => message,
+            body => error_messages::SERVER_RESPONSE
+                .replace("{error}", &message)
+                .replace("{body}", body),
+        }
+    }
+
+    /// Classifies an error raised by [`ureq`] while sending the request and
+    /// receiving the response head.
+    pub(crate) fn from_call_error(error: Error) -> Self {
+        match error {
+            Error::Timeout(_) => Self::Timeout(error.to_string()),
+            Error::HostNotFound => Self::DnsFailure(error.to_string()),
+            Error::Io(ref io_error) if io_error.kind() == ErrorKind::ConnectionRefused => {
+                Self::ConnectionRefused(error.to_string())
+            }
+            Error::ConnectionFailed => Self::ConnectionRefused(error.to_string()),
+            // rustls reports handshake failures as io errors wrapping its own
+            Error::Io(ref io_error)
+                if io_error
+                    .get_ref()
+                    .is_some_and(|inner| inner.is::<rustls::Error>()) =>
+            {
+                Self::TlsFailure(error.to_string())
+            }
+            Error::Tls(_) | Error::Pem(_) | Error::Rustls(_) | Error::TlsRequired => {
+                Self::TlsFailure(error.to_string())
+            }
+            error => Self::Transport(error.to_string()),
+        }
+    }
+
+    /// Classifies an error raised by [`ureq`] while reading the response
+    /// body.
+    fn from_body_error(error: Error) -> Self {
+        match error {
+            Error::Timeout(_) => Self::Timeout(error.to_string()),
+            Error::Io(ref io_error) if io_error.kind() == ErrorKind::InvalidData => {
+                Self::BodyDecoding(error.to_string())
+            }
+            error => Self::Transport(error.to_string()),
+        }
+    }
+}
+
+impl HttpResponse {
+    /// Creates a successful response with given body and no headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The response body.
+    ///
+    /// # Returns
+    ///
+    /// A response with a `200` status code.
+    pub fn ok(body: &str) -> Self {
+        Self {
+            status: 200,
+            headers: HashMap::new(),
+            body: body.as_bytes().to_vec(),
+            url: String::new(),
+        }
+    }
+
+    /// Gets the value of given response header.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// The header value if the response has such header, `None` otherwise.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Decodes the response body as an utf-8 string.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the decoded body, or an
+    /// [`HttpError::BodyDecoding`] if the body is not valid utf-8.
+    pub fn text(&self) -> Result<String, HttpError> {
+        String::from_utf8(self.body.clone())
+            .map_err(|_| HttpError::BodyDecoding(error_messages::INVALID_ENCODING.to_string()))
+    }
+
+    /// Turns 4xx and 5xx responses into errors.
+    ///
+    /// # Returns
+    ///
+    /// A result containing this response if its status code denotes no
+    /// error, or an [`HttpError::ClientStatus`] / [`HttpError::ServerStatus`]
+    /// holding the response body otherwise.
+    pub fn error_for_status(self) -> Result<Self, HttpError> {
+        let body = || String::from_utf8_lossy(&self.body).to_string();
+
+        match self.status {
+            400..=499 => Err(HttpError::ClientStatus {
+                status: self.status,
+                body: body(),
+            }),
+            500..=599 => Err(HttpError::ServerStatus {
+                status: self.status,
+                body: body(),
+            }),
+            _ => Ok(self),
+        }
+    }
+}
+
+impl HttpRequest {
+    fn new(url: &str, headers: &[(&str, &str)]) -> Self {
+        Self {
+            url: url.to_string(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+}
+
 impl HttpClient for UreqHttpClient {
-    /// Make a GET HTTP call using a [`ureq`] client.
+    /// Make a GET HTTP call with given extra request headers using a
+    /// [`ureq`] client.
     ///
     /// The server base url (i.e. https://localhost:8080) should be provided
     /// as part of [`UreqHttpClient::server_url] field.
     ///
-    /// See [`HttpClient::get`] for more infos.
-    fn get(&self, url: &str) -> Result<String, ProgramExit> {
-        let full_url = format!("{}{url}", self.server_url);
-        let agent: Agent = Agent::config_builder()
+    /// See [`HttpClient::fetch`] for more infos.
+    fn fetch(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, HttpError> {
+        let full_url = DefaultUtils::join_url(&self.server_url, url);
+        let started_at = Instant::now();
+        let response = self.send(&full_url, headers);
+        let elapsed = started_at.elapsed().as_millis().to_string();
+
+        Logger::verbose(&match &response {
+            Ok(response) => log_messages::HTTP_RESPONSE
+                .replace("{url}", &full_url)
+                .replace("{status}", &response.status.to_string())
+                .replace("{elapsed}", &elapsed),
+            Err(error) => log_messages::HTTP_FAILURE
+                .replace("{url}", &full_url)
+                .replace("{elapsed}", &elapsed)
+                .replace("{error}", &error.to_string()),
+        });
+
+        response
+    }
+}
+
+impl UreqHttpClient {
+    /// Sends a GET HTTP call to given full url with given extra request
+    /// headers.
+    fn send(&self, full_url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, HttpError> {
+        let config = Agent::config_builder()
+            .http_status_as_error(false)
             .timeout_global(Some(
-                self.global_timeout.unwrap_or(Duration::from_secs(
-                    template_manager::TIMEOUT
-                        .parse()
-                        .expect(error_messages::FAILED_U64_CONVERSION),
-                )),
+                self.global_timeout
+                    .unwrap_or(Duration::from_secs(template_manager::TIMEOUT_INT)),
             ))
-            .build()
-            .into();
-
-        let result = agent.get(full_url).call();
-
-        match result {
-            Ok(mut response) => match response.body_mut().read_to_string() {
-                Ok(body) => Ok(body.trim().to_string()),
-                Err(error) => Err(ProgramExit {
-                    message: error.to_string(),
-                    exit_status: exit_status::HTTP_CLIENT_ERROR,
-                    styled_message: None,
-                    kind: ExitKind::Error,
-                }),
-            },
-            Err(error) => Err(ProgramExit {
-                message: error_messages::API_CALL_FAILURE.replace("{error}", &error.to_string()),
-                exit_status: exit_status::GENERIC,
-                styled_message: None,
-                kind: ExitKind::Error,
-            }),
+            .timeout_connect(self.connect_timeout)
+            .timeout_recv_response(self.read_timeout)
+            .timeout_recv_body(self.read_timeout)
+            .build();
+        let agent = Agent::with_parts(config, DefaultConnector::new(), LookupResolver::default());
+
+        let request = headers
+            .iter()
+            .fold(agent.get(full_url), |request, (name, value)| {
+                request.header(*name, *value)
+            });
+        let mut response = request.call().map_err(HttpError::from_call_error)?;
+
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            headers: response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    value
+                        .to_str()
+                        .ok()
+                        .map(|value| (name.to_string(), value.to_string()))
+                })
+                .collect(),
+            url: response.get_uri().to_string(),
+            body: response
+                .body_mut()
+                .read_to_vec()
+                .map_err(HttpError::from_body_error)?,
+        })
+    }
+}
+
+impl HttpClient for CachedHttpClient {
+    /// Make a conditional GET HTTP call with given extra request headers
+    /// using the linked client.
+    ///
+    /// The validators of the cached response for given `url`, if any, are
+    /// sent as `If-None-Match` and `If-Modified-Since` headers. A
+    /// `304 Not Modified` answer is served from the cache, while any other
+    /// successful answer carrying validators replaces the cached response.
+    ///
+    /// Failing to store a response in the cache does not fail the call, a
+    /// warning being logged instead.
+    fn fetch(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, HttpError> {
+        let cache_key = DefaultUtils::join_url(&self.server_url, url);
+        let cached_response = self.cache.load(&cache_key);
+        let mut request_headers = headers.to_vec();
+
+        if let Some(cached_response) = &cached_response {
+            if let Some(etag) = &cached_response.etag {
+                request_headers.push(("If-None-Match", etag));
+            }
+            if let Some(last_modified) = &cached_response.last_modified {
+                request_headers.push(("If-Modified-Since", last_modified));
+            }
+        }
+
+        let response = self.http_client.fetch(url, &request_headers)?;
+
+        match cached_response {
+            Some(cached_response) if response.status == 304 => {
+                Logger::debug(&log_messages::CACHE_HIT.replace("{url}", url));
+
+                Ok(HttpResponse {
+                    status: 200,
+                    body: cached_response.body,
+                    ..response
+                })
+            }
+            _ => {
+                let etag = response.header("etag").map(str::to_string);
+                let last_modified = response.header("last-modified").map(str::to_string);
+
+                if response.status == 200 && (etag.is_some() || last_modified.is_some()) {
+                    let stored = self.cache.store(
+                        &cache_key,
+                        &CachedResponse {
+                            body: response.body.clone(),
+                            etag,
+                            last_modified,
+                        },
+                    );
+
+                    if let Err(error) = stored {
+                        Logger::warn(
+                            &log_messages::CACHE_STORE_FAILURE
+                                .replace("{url}", &cache_key)
+                                .replace("{error}", &error.to_string()),
+                        );
+                    }
+                }
+
+                Ok(response)
+            }
+        }
+    }
+}
+
+impl MockHttpClient {
+    pub fn new(response: Result<HttpResponse, HttpError>) -> Self {
+        Self {
+            response,
+            requests: RefCell::new(Vec::new()),
         }
     }
 }
@@ -53,26 +368,52 @@ impl HttpClient for UreqHttpClient {
 impl HttpClient for MockHttpClient {
     /// Returns the result linked to this instance.
     ///
-    /// The given `_url` will not be used, simply a clone of linked
-    /// result will be returned.
-    fn get(&self, _url: &str) -> Result<String, ProgramExit> {
-        self.response.clone()
+    /// The given `url` and `headers` are only recorded, simply a clone of
+    /// linked result will be returned, with its url set to the given one.
+    fn fetch(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, HttpError> {
+        self.requests
+            .borrow_mut()
+            .push(HttpRequest::new(url, headers));
+
+        self.response.clone().map(|response| HttpResponse {
+            url: url.to_string(),
+            ..response
+        })
+    }
+}
+
+impl MockEndpointHttpClient {
+    pub fn new(response: HashMap<String, Result<HttpResponse, HttpError>>) -> Self {
+        Self {
+            response,
+            requests: RefCell::new(Vec::new()),
+        }
     }
 }
 
 impl HttpClient for MockEndpointHttpClient {
     /// Returns the result linked to the given url.
     ///
-    /// The given `url` will only be used to get proper result from linked
-    /// hashmap.
-    fn get(&self, url: &str) -> Result<String, ProgramExit> {
-        println!("{:?}", self.response[url]);
+    /// The given `url` and `headers` are recorded, and `url` is used to get
+    /// proper result from linked hashmap. An unmapped url results in a
+    /// `404` response.
+    fn fetch(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, HttpError> {
+        self.requests
+            .borrow_mut()
+            .push(HttpRequest::new(url, headers));
 
         match self.response.get(url) {
-            Some(value) => value.clone(),
-            None => Err(ProgramExit::error(
-                &error_messages::INVALID_MAPPED_URI.replace("{uri}", url),
-            )),
+            Some(value) => value.clone().map(|response| HttpResponse {
+                url: url.to_string(),
+                ..response
+            }),
+            None => Ok(HttpResponse {
+                status: 404,
+                body: error_messages::INVALID_MAPPED_URI
+                    .replace("{uri}", url)
+                    .into_bytes(),
+                ..HttpResponse::ok("")
+            }),
         }
     }
 }
@@ -1,9 +1,11 @@
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, io, net::TcpListener, thread, time::Duration};
 
-use mockito::Server;
+use mockito::{Matcher, Server};
+use rstest::rstest;
 
 use super::*;
 use crate::{
+    cache::{CachedResponse, MemoryCache},
     constant,
     core::{ExitKind, ProgramExit},
 };
@@ -32,7 +34,7 @@ mod ureq_client {
                 let http_client = UreqHttpClient::default();
 
                 let actual = http_client.get(&format!("{server_url}{mock_uri}"));
-                let expected: Result<String, ProgramExit> = Ok(String::from(mock_body));
+                let expected: Result<String, Error> = Ok(String::from(mock_body));
 
                 mock.assert();
                 assert_eq!(actual, expected);
@@ -52,16 +54,51 @@ mod ureq_client {
                 let server_url = mock_server.url();
                 let http_client = UreqHttpClient {
                     server_url,
-                    global_timeout: None,
+                    ..Default::default()
                 };
 
                 let actual = http_client.get(mock_uri);
-                let expected: Result<String, ProgramExit> = Ok(String::from(mock_body));
+                let expected: Result<String, Error> = Ok(String::from(mock_body));
 
                 mock.assert();
                 assert_eq!(actual, expected);
             }
 
+            #[test]
+            fn it_fetches_response_status_headers_and_final_url() {
+                let mut mock_server = Server::new();
+                let mock_uri = "/api/rust";
+                let redirect_mock = mock_server
+                    .mock("GET", "/api/old")
+                    .with_status(301)
+                    .with_header("location", mock_uri)
+                    .create();
+                let mock = mock_server
+                    .mock("GET", mock_uri)
+                    .match_header("accept", "text/plain")
+                    .with_status(404)
+                    .with_header("x-request-id", "42")
+                    .with_body(vec![0, 159])
+                    .create();
+
+                let server_url = mock_server.url();
+                let http_client = UreqHttpClient {
+                    server_url: server_url.clone(),
+                    ..Default::default()
+                };
+
+                let actual = http_client
+                    .fetch("/api/old", &[("Accept", "text/plain")])
+                    .unwrap();
+
+                redirect_mock.assert();
+                mock.assert();
+                assert_eq!(actual.status, 404);
+                assert_eq!(actual.header("X-Request-Id"), Some("42"));
+                assert_eq!(actual.body, vec![0, 159]);
+                assert_eq!(actual.url, format!("{server_url}{mock_uri}"));
+            }
+
             #[test]
             fn it_fetches_data_as_string_with_given_timeout() {
                 let mut mock_server = Server::new();
@@ -77,10 +114,12 @@ mod ureq_client {
                 let http_client = UreqHttpClient {
                     server_url,
                     global_timeout: Some(Duration::from_secs(5)),
+                    connect_timeout: Some(Duration::from_secs(1)),
+                    read_timeout: Some(Duration::from_secs(2)),
                 };
 
                 let actual = http_client.get(mock_uri);
-                let expected: Result<String, ProgramExit> = Ok(String::from(mock_body));
+                let expected: Result<String, Error> = Ok(String::from(mock_body));
 
                 mock.assert();
                 assert_eq!(actual, expected);
@@ -104,11 +143,75 @@ mod ureq_client {
                 let server_url = mock_server.url();
                 let http_client = UreqHttpClient::default();
 
-                let actual = http_client.get(&format!("{server_url}{mock_uri}"));
+                let actual = http_client
+                    .get(&format!("{server_url}{mock_uri}"))
+                    .map_err(ProgramExit::from);
                 let expected: Result<String, ProgramExit> = Err(ProgramExit {
-                    message: constant::error_messages::API_CALL_FAILURE
-                        .replace("{error}", constant::error_messages::HTTP_400),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: constant::error_messages::API_CALL_FAILURE.replace(
+                        "{error}",
+                        &constant::error_messages::SERVER_RESPONSE
+                            .replace("{error}", constant::error_messages::HTTP_400)
+                            .replace("{body}", mock_body),
+                    ),
+                    exit_status: constant::exit_status::HTTP_CLIENT_STATUS,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                });
+
+                mock.assert();
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fails_with_client_status_error_when_404_response() {
+                let mut mock_server = Server::new();
+                let mock_uri = "/api/rust";
+                let mock = mock_server.mock("GET", mock_uri).with_status(404).create();
+
+                let server_url = mock_server.url();
+                let http_client = UreqHttpClient {
+                    server_url,
+                    ..Default::default()
+                };
+
+                let actual = http_client
+                    .fetch(mock_uri, &[])
+                    .and_then(HttpResponse::error_for_status);
+                let expected: Result<HttpResponse, HttpError> = Err(HttpError::ClientStatus {
+                    status: 404,
+                    body: String::new(),
+                });
+
+                mock.assert();
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fails_with_server_status_error_when_500_response() {
+                let mut mock_server = Server::new();
+                let mock_body = "internal error";
+                let mock_uri = "/api/rust";
+                let mock = mock_server
+                    .mock("GET", mock_uri)
+                    .with_status(500)
+                    .with_body(mock_body)
+                    .create();
+
+                let server_url = mock_server.url();
+                let http_client = UreqHttpClient {
+                    server_url,
+                    ..Default::default()
+                };
+
+                let actual = http_client.get(mock_uri).map_err(ProgramExit::from);
+                let expected: Result<String, ProgramExit> = Err(ProgramExit {
+                    message: constant::error_messages::API_SERVER_FAILURE.replace(
+                        "{error}",
+                        &constant::error_messages::SERVER_RESPONSE
+                            .replace("{error}", constant::error_messages::HTTP_500)
+                            .replace("{body}", mock_body),
+                    ),
+                    exit_status: constant::exit_status::HTTP_SERVER_STATUS,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
@@ -117,6 +220,87 @@ mod ureq_client {
                 assert_eq!(actual, expected);
             }
 
+            #[test]
+            fn it_fails_with_connection_refused_error_when_no_server_listening() {
+                let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+                let server_url = format!("http://{}", listener.local_addr().unwrap());
+                drop(listener);
+
+                let http_client = UreqHttpClient {
+                    server_url,
+                    ..Default::default()
+                };
+
+                let actual = http_client
+                    .get("/api/rust")
+                    .map_err(|error| error.exit_status());
+                let expected: Result<String, i32> = Err(constant::exit_status::CONNECTION_REFUSED);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fails_with_dns_error_when_unresolvable_host() {
+                let http_client = UreqHttpClient {
+                    server_url: String::from("http://unresolvable-host.invalid"),
+                    ..Default::default()
+                };
+
+                let actual = http_client
+                    .get("/api/rust")
+                    .map_err(|error| error.exit_status());
+                let expected: Result<String, i32> = Err(constant::exit_status::DNS_FAILURE);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fails_with_tls_error_when_server_does_not_speak_tls() {
+                let mock_server = Server::new();
+                let server_url = mock_server.url().replace("http://", "https://");
+
+                let http_client = UreqHttpClient {
+                    server_url,
+                    ..Default::default()
+                };
+
+                let actual = http_client
+                    .get("/api/rust")
+                    .map_err(|error| error.exit_status());
+                let expected: Result<String, i32> = Err(constant::exit_status::TLS_FAILURE);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fails_with_timeout_error_when_read_timeout_reached() {
+                let mut mock_server = Server::new();
+                let mock_uri = "/api/rust";
+                let mock = mock_server
+                    .mock("GET", mock_uri)
+                    .with_status(200)
+                    .with_chunked_body(|w| {
+                        thread::sleep(Duration::from_millis(500));
+                        w.write_all(b"rust")
+                    })
+                    .create();
+
+                let server_url = mock_server.url();
+                let http_client = UreqHttpClient {
+                    server_url,
+                    read_timeout: Some(Duration::from_millis(100)),
+                    ..Default::default()
+                };
+
+                let actual = http_client
+                    .get(mock_uri)
+                    .map_err(|error| error.exit_status());
+                let expected: Result<String, i32> = Err(constant::exit_status::TIMEOUT);
+
+                mock.assert();
+                assert_eq!(actual, expected);
+            }
+
             #[test]
             fn it_fails_with_body_parsing_error_when_invalid_body() {
                 let mut mock_server = Server::new();
@@ -131,10 +315,12 @@ mod ureq_client {
                 let server_url = mock_server.url();
                 let http_client = UreqHttpClient::default();
 
-                let actual = http_client.get(&format!("{server_url}{mock_uri}"));
+                let actual = http_client
+                    .get(&format!("{server_url}{mock_uri}"))
+                    .map_err(ProgramExit::from);
                 let expected: Result<String, ProgramExit> = Err(ProgramExit {
                     message: String::from(constant::error_messages::INVALID_ENCODING),
-                    exit_status: constant::exit_status::HTTP_CLIENT_ERROR,
+                    exit_status: constant::exit_status::BODY_PARSING_ISSUE,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
@@ -146,9 +332,58 @@ mod ureq_client {
     }
 }
 
-mod mock_client {
+mod http_error {
     use super::*;
 
+    mod from_call_error {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case(ureq::Error::HostNotFound, constant::exit_status::DNS_FAILURE)]
+            #[case(
+                ureq::Error::Io(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    rustls::Error::DecryptError
+                )),
+                constant::exit_status::TLS_FAILURE
+            )]
+            #[case(
+                ureq::Error::Io(io::Error::new(io::ErrorKind::InvalidData, "not tls")),
+                constant::exit_status::HTTP_CLIENT_ERROR
+            )]
+            #[case(
+                ureq::Error::Io(io::Error::from(io::ErrorKind::ConnectionRefused)),
+                constant::exit_status::CONNECTION_REFUSED
+            )]
+            fn it_classifies_error_from_its_type(
+                #[case] error: ureq::Error,
+                #[case] expected: i32,
+            ) {
+                let actual = HttpError::from_call_error(error).exit_status();
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod cached_client {
+    use super::*;
+
+    fn cached_client(server_url: String, cache: MemoryCache) -> CachedHttpClient {
+        CachedHttpClient {
+            http_client: Box::new(UreqHttpClient {
+                server_url: server_url.clone(),
+                ..Default::default()
+            }),
+            server_url,
+            cache: Box::new(cache),
+        }
+    }
+
     mod get {
         use super::*;
 
@@ -156,34 +391,235 @@ mod mock_client {
             use super::*;
 
             #[test]
-            fn it_returns_ok_mocked_response() {
-                let result_content = "success response";
-                let http_client = MockHttpClient {
-                    response: Ok(String::from(result_content)),
-                };
+            fn it_caches_response_with_validators() {
+                let mut mock_server = Server::new();
+                let mock_uri = "/api/list";
+                let mock = mock_server
+                    .mock("GET", mock_uri)
+                    .with_status(200)
+                    .with_header("etag", "\"abc\"")
+                    .with_header("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                    .with_body("rust\n")
+                    .create();
 
-                let actual = http_client.get("/api/rust");
-                let expected: Result<String, ProgramExit> = Ok(String::from(result_content));
+                let http_client = cached_client(mock_server.url(), MemoryCache::default());
+
+                let actual = http_client.get(mock_uri);
+                let expected: Result<String, Error> = Ok(String::from("rust"));
+
+                let actual_cached = http_client
+                    .cache
+                    .load(&format!("{}{mock_uri}", mock_server.url()));
+                let expected_cached = Some(CachedResponse {
+                    body: b"rust\n".to_vec(),
+                    etag: Some(String::from("\"abc\"")),
+                    last_modified: Some(String::from("Wed, 21 Oct 2015 07:28:00 GMT")),
+                });
+
+                mock.assert();
+                assert_eq!(actual, expected);
+                assert_eq!(actual_cached, expected_cached);
+            }
+
+            #[test]
+            fn it_serves_cached_response_when_304_response() {
+                let mut mock_server = Server::new();
+                let mock_uri = "/api/list";
+                let mock = mock_server
+                    .mock("GET", mock_uri)
+                    .match_header("if-none-match", "\"abc\"")
+                    .match_header("if-modified-since", "Wed, 21 Oct 2015 07:28:00 GMT")
+                    .with_status(304)
+                    .create();
+
+                let cache = MemoryCache::default();
+                cache
+                    .store(
+                        &format!("{}{mock_uri}", mock_server.url()),
+                        &CachedResponse {
+                            body: b"rust".to_vec(),
+                            etag: Some(String::from("\"abc\"")),
+                            last_modified: Some(String::from("Wed, 21 Oct 2015 07:28:00 GMT")),
+                        },
+                    )
+                    .unwrap();
+                let http_client = cached_client(mock_server.url(), cache);
+
+                let actual = http_client.get(mock_uri);
+                let expected: Result<String, Error> = Ok(String::from("rust"));
+
+                mock.assert();
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_does_not_cache_response_without_validators() {
+                let mut mock_server = Server::new();
+                let mock_uri = "/api/list";
+                let mock = mock_server
+                    .mock("GET", mock_uri)
+                    .with_status(200)
+                    .with_body("rust")
+                    .create();
+
+                let http_client = cached_client(mock_server.url(), MemoryCache::default());
+
+                let actual = http_client.get(mock_uri);
+                let expected: Result<String, Error> = Ok(String::from("rust"));
+
+                mock.assert();
+                assert_eq!(actual, expected);
+                assert_eq!(
+                    http_client
+                        .cache
+                        .load(&format!("{}{mock_uri}", mock_server.url())),
+                    None
+                );
+            }
+
+            #[test]
+            fn it_does_not_revalidate_response_cached_for_another_server() {
+                let mut mock_server = Server::new();
+                let mock_uri = "/api/list";
+                let mock = mock_server
+                    .mock("GET", mock_uri)
+                    .match_header("if-none-match", Matcher::Missing)
+                    .with_status(200)
+                    .with_body("python")
+                    .create();
+
+                let cache = MemoryCache::default();
+                cache
+                    .store(
+                        &format!("http://localhost:1{mock_uri}"),
+                        &CachedResponse {
+                            body: b"rust".to_vec(),
+                            etag: Some(String::from("\"abc\"")),
+                            last_modified: None,
+                        },
+                    )
+                    .unwrap();
+                let http_client = cached_client(mock_server.url(), cache);
+
+                let actual = http_client.get(mock_uri);
+                let expected: Result<String, Error> = Ok(String::from("python"));
+
+                mock.assert();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod mock_client {
+    use super::*;
+
+    mod fetch {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_returns_mocked_response_with_requested_url() {
+                let http_client = MockHttpClient::new(Ok(HttpResponse {
+                    status: 503,
+                    headers: HashMap::from([("retry-after".to_string(), "120".to_string())]),
+                    ..HttpResponse::ok("maintenance")
+                }));
+
+                let actual = http_client.fetch("/api/rust", &[]);
+                let expected: Result<HttpResponse, HttpError> = Ok(HttpResponse {
+                    status: 503,
+                    headers: HashMap::from([("retry-after".to_string(), "120".to_string())]),
+                    body: b"maintenance".to_vec(),
+                    url: String::from("/api/rust"),
+                });
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_records_received_requests() {
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok("rust")));
+
+                let _ = http_client.fetch("/api/rust", &[("If-None-Match", "\"abc\"")]);
+
+                let actual = http_client.requests.borrow().clone();
+                let expected = vec![HttpRequest {
+                    url: String::from("/api/rust"),
+                    headers: HashMap::from([("If-None-Match".to_string(), "\"abc\"".to_string())]),
+                }];
 
                 assert_eq!(actual, expected);
             }
 
             #[test]
             fn it_returns_error_mocked_response() {
-                let result_content = "error response";
-                let http_client = MockHttpClient {
-                    response: Err(ProgramExit {
-                        message: String::from(result_content),
-                        exit_status: constant::exit_status::GENERIC,
-                        styled_message: None,
-                        kind: ExitKind::Error,
-                    }),
-                };
+                let http_client = MockHttpClient::new(Err(HttpError::Timeout(String::from(
+                    constant::error_messages::TIMEOUT,
+                ))));
+
+                let actual = http_client.fetch("/api/rust", &[]);
+                let expected: Result<HttpResponse, HttpError> = Err(HttpError::Timeout(
+                    String::from(constant::error_messages::TIMEOUT),
+                ));
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod get {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_returns_trimmed_body_of_mocked_response() {
+                let http_client = MockHttpClient::new(Ok(HttpResponse::ok("rust\n")));
 
                 let actual = http_client.get("/api/rust");
+                let expected: Result<String, Error> = Ok(String::from("rust"));
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            fn it_fails_when_mocked_error_status() {
+                let http_client = MockHttpClient::new(Ok(HttpResponse {
+                    status: 404,
+                    ..HttpResponse::ok("")
+                }));
+
+                let actual = http_client.get("/api/rust").map_err(ProgramExit::from);
+                let expected: Result<String, ProgramExit> = Err(ProgramExit {
+                    message: constant::error_messages::API_CALL_FAILURE
+                        .replace("{error}", constant::error_messages::HTTP_404),
+                    exit_status: constant::exit_status::HTTP_CLIENT_STATUS,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                });
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fails_when_mocked_body_is_not_utf8() {
+                let http_client = MockHttpClient::new(Ok(HttpResponse {
+                    body: vec![0, 159, 146, 150],
+                    ..HttpResponse::ok("")
+                }));
+
+                let actual = http_client.get("/api/rust").map_err(ProgramExit::from);
                 let expected: Result<String, ProgramExit> = Err(ProgramExit {
-                    message: String::from(result_content),
-                    exit_status: constant::exit_status::GENERIC,
+                    message: String::from(constant::error_messages::INVALID_ENCODING),
+                    exit_status: constant::exit_status::BODY_PARSING_ISSUE,
                     styled_message: None,
                     kind: ExitKind::Error,
                 });
@@ -197,71 +633,65 @@ mod mock_client {
 mod mock_endpoint_http_client {
     use super::*;
 
-    mod get {
+    mod fetch {
         use super::*;
 
         mod success {
             use super::*;
 
             #[test]
-            fn it_returns_ok_mocked_response_for_given_url() {
-                let rust_result_content = "success rust response";
-                let python_result_content = "success python response";
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (
-                            "/api/rust".to_string(),
-                            Ok(String::from(rust_result_content)),
-                        ),
-                        (
-                            "/api/python".to_string(),
-                            Ok(String::from(python_result_content)),
-                        ),
-                    ]),
-                };
-
-                let actual = http_client.get("/api/rust");
-                let expected: Result<String, ProgramExit> = Ok(String::from(rust_result_content));
+            fn it_returns_mocked_response_for_given_url() {
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (
+                        "/api/rust".to_string(),
+                        Ok(HttpResponse::ok("success rust response")),
+                    ),
+                    (
+                        "/api/python".to_string(),
+                        Err(HttpError::Transport(String::from("error python response"))),
+                    ),
+                ]));
+
+                let actual = http_client.fetch("/api/rust", &[]);
+                let expected: Result<HttpResponse, HttpError> = Ok(HttpResponse {
+                    url: String::from("/api/rust"),
+                    ..HttpResponse::ok("success rust response")
+                });
 
                 assert_eq!(actual, expected);
             }
 
             #[test]
             fn it_returns_error_mocked_response_for_given_url() {
-                let rust_result_content = "success rust response";
-                let python_result_content = "success python response";
-                let http_client = MockEndpointHttpClient {
-                    response: HashMap::from([
-                        (
-                            "/api/rust".to_string(),
-                            Err(ProgramExit {
-                                message: String::from(rust_result_content),
-                                exit_status: constant::exit_status::GENERIC,
-                                styled_message: None,
-                                kind: ExitKind::Error,
-                            }),
-                        ),
-                        (
-                            "/api/python".to_string(),
-                            Err(ProgramExit {
-                                message: String::from(python_result_content),
-                                exit_status: constant::exit_status::GENERIC,
-                                styled_message: None,
-                                kind: ExitKind::Error,
-                            }),
-                        ),
-                    ]),
-                };
+                let http_client = MockEndpointHttpClient::new(HashMap::from([
+                    (
+                        "/api/rust".to_string(),
+                        Ok(HttpResponse::ok("success rust response")),
+                    ),
+                    (
+                        "/api/python".to_string(),
+                        Err(HttpError::Transport(String::from("error python response"))),
+                    ),
+                ]));
+
+                let actual = http_client.fetch("/api/python", &[]);
+                let expected: Result<HttpResponse, HttpError> =
+                    Err(HttpError::Transport(String::from("error python response")));
 
-                let actual = http_client.get("/api/python");
-                let expected: Result<String, ProgramExit> = Err(ProgramExit {
-                    message: String::from(python_result_content),
-                    exit_status: constant::exit_status::GENERIC,
-                    styled_message: None,
-                    kind: ExitKind::Error,
-                });
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_returns_404_response_for_unmapped_url() {
+                let http_client = MockEndpointHttpClient::new(HashMap::new());
+
+                let actual = http_client
+                    .fetch("/api/rust", &[])
+                    .map(|response| response.status);
+                let expected: Result<u16, HttpError> = Ok(404);
 
                 assert_eq!(actual, expected);
+                assert_eq!(http_client.requests.borrow().len(), 1);
             }
         }
     }
@@ -0,0 +1,43 @@
+//! Define components to lay out generated templates.
+use clap::ValueEnum;
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Enum of policies for the newline ending generated templates.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum TrailingNewline {
+    /// Always end output with a single newline.
+    Always,
+
+    /// Never end output with a newline.
+    Never,
+}
+
+/// Layout of generated templates.
+///
+/// It tells how sections (i.e. templates grouped by source) and local
+/// templates are titled, and how the whole output is wrapped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Layout {
+    /// The format of section headers, supporting `{source}` and `{date}`
+    /// placeholders (defaults to
+    /// [`crate::constant::template_manager::SECTION_HEADER`]).
+    pub section_header: String,
+
+    /// The format of local template titles, supporting `{name}`,
+    /// `{source}` and `{date}` placeholders (defaults to
+    /// [`crate::constant::template_manager::TEMPLATE_TITLE`]).
+    pub template_title: String,
+
+    /// Whether to print section headers and local template titles.
+    pub headers: bool,
+
+    /// Whether to start output with a generated-by banner.
+    pub banner: bool,
+
+    /// The policy for the newline ending output.
+    pub trailing_newline: TrailingNewline,
+}
@@ -0,0 +1,174 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+
+use crate::{
+    constant::{help_texts, template_manager},
+    core::StringKind,
+    helper::{DefaultUtils, Utils},
+    layout::{Layout, TrailingNewline},
+    parser::Args,
+};
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            section_header: template_manager::SECTION_HEADER.to_string(),
+            template_title: template_manager::TEMPLATE_TITLE.to_string(),
+            headers: true,
+            banner: false,
+            trailing_newline: TrailingNewline::Always,
+        }
+    }
+}
+
+impl From<&Args> for Layout {
+    fn from(args: &Args) -> Self {
+        Self {
+            section_header: args.section_header.clone(),
+            template_title: args.template_title.clone(),
+            headers: !args.no_headers,
+            banner: args.banner,
+            trailing_newline: args.trailing_newline,
+        }
+    }
+}
+
+impl Layout {
+    /// Formats the header of the section holding templates served by
+    /// given source.
+    ///
+    /// # Returns
+    ///
+    /// The formatted header, or `None` if headers are disabled.
+    pub fn section_header(&self, source: StringKind) -> Option<String> {
+        self.headers.then(|| {
+            self.section_header
+                .replace("{source}", &source.name().to_uppercase())
+                .replace("{date}", &Self::today())
+        })
+    }
+
+    /// Formats the title of given local template.
+    ///
+    /// # Returns
+    ///
+    /// The formatted title, or `None` if headers are disabled.
+    pub fn template_title(&self, template_name: &str) -> Option<String> {
+        self.headers.then(|| {
+            self.template_title
+                .replace(
+                    "{name}",
+                    &DefaultUtils::capitalize(template_name.rsplit('/').next().unwrap_or_default()),
+                )
+                .replace("{source}", &StringKind::Local.name().to_uppercase())
+                .replace("{date}", &Self::today())
+        })
+    }
+
+    /// Wraps given generated templates with the banner and the trailing
+    /// newline, as they are meant to be printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `templates` - The generated templates.
+    /// * `command_line` - The command line having generated the templates,
+    ///   to be written in the banner.
+    ///
+    /// # Returns
+    ///
+    /// The rendered templates.
+    pub fn render(&self, templates: &str, command_line: &str) -> String {
+        let mut rendered = String::new();
+
+        if self.banner {
+            rendered.push_str(
+                &help_texts::BANNER
+                    .replace("{name}", env!("CARGO_PKG_NAME"))
+                    .replace("{version}", env!("CARGO_PKG_VERSION"))
+                    .replace("{command}", command_line),
+            );
+            rendered.push_str("\n\n");
+        }
+
+        rendered.push_str(templates.trim_end());
+        if self.trailing_newline == TrailingNewline::Always {
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+
+    /// Removes the generated-by banner starting given content, if any.
+    ///
+    /// The banner depends on the command line, so it is left out when
+    /// comparing a file with freshly generated templates.
+    pub fn strip_banner(content: &str) -> &str {
+        let banner_prefix = help_texts::BANNER
+            .split("{name}")
+            .next()
+            .unwrap_or_default();
+        if !content.starts_with(banner_prefix) {
+            return content;
+        }
+
+        let mut stripped = content;
+        for _ in 0..help_texts::BANNER.lines().count() {
+            stripped = stripped.split_once('\n').map_or("", |(_, rest)| rest);
+        }
+
+        stripped.strip_prefix('\n').unwrap_or(stripped)
+    }
+
+    /// Replaces the dates of section headers and local template titles
+    /// found in given content with the `{date}` placeholder.
+    ///
+    /// Dates change from one day to the next, so they are left out when
+    /// comparing a file or a lockfile with freshly generated templates.
+    /// Only lines matching this layout's formats are affected, and content
+    /// is returned as is if none of them has a `{date}` placeholder.
+    pub fn undate(&self, content: &str) -> String {
+        let header_patterns: Vec<Regex> = [&self.section_header, &self.template_title]
+            .into_iter()
+            .filter(|format| format.contains("{date}"))
+            .filter_map(|format| Self::format_pattern(format))
+            .collect();
+        if header_patterns.is_empty() {
+            return content.to_string();
+        }
+
+        let date_pattern = Regex::new(r"\d{4}-\d{2}-\d{2}").expect("date pattern is valid");
+        content
+            .split_inclusive('\n')
+            .map(|line| {
+                let header = line.trim_end_matches(['\n', '\r']);
+                if header_patterns
+                    .iter()
+                    .any(|pattern| pattern.is_match(header))
+                {
+                    date_pattern.replace_all(line, "{date}").into_owned()
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect()
+    }
+
+    fn format_pattern(format: &str) -> Option<Regex> {
+        let pattern = regex::escape(format)
+            .replace(r"\{date\}", r"\d{4}-\d{2}-\d{2}")
+            .replace(r"\{source\}", ".*")
+            .replace(r"\{name\}", ".*");
+
+        Regex::new(&format!("^{pattern}$")).ok()
+    }
+
+    fn today() -> String {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        DefaultUtils::format_date(seconds)
+    }
+}
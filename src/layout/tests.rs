@@ -0,0 +1,159 @@
+use crate::{
+    core::StringKind,
+    layout::{Layout, TrailingNewline},
+};
+
+mod layout {
+    use super::*;
+
+    mod section_header {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_formats_header_with_uppercase_source() {
+                let layout = Layout::default();
+
+                let actual = layout.section_header(StringKind::Local);
+                let expected = Some(String::from("## LOCAL"));
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_returns_none_when_headers_disabled() {
+                let layout = Layout {
+                    headers: false,
+                    ..Layout::default()
+                };
+
+                let actual = layout.section_header(StringKind::Remote);
+                let expected = None;
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod template_title {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_formats_title_with_capitalized_name() {
+                let layout = Layout {
+                    template_title: String::from("# {name} ({source})"),
+                    ..Layout::default()
+                };
+
+                let actual = layout.template_title("rust");
+                let expected = Some(String::from("# Rust (LOCAL)"));
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod undate {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_replaces_dates_of_headers_and_titles() {
+                let layout = Layout {
+                    section_header: String::from("## {source} ({date})"),
+                    template_title: String::from("### *{name} {date} ###"),
+                    ..Layout::default()
+                };
+
+                let actual = layout.undate(
+                    "## LOCAL (2025-04-20)\n\n### *Rust 2025-04-20 ###\nbuild-2025-04-20/\n",
+                );
+                let expected = "## LOCAL ({date})\n\n### *Rust {date} ###\nbuild-2025-04-20/\n";
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_returns_content_as_is_without_date_placeholder() {
+                let layout = Layout::default();
+
+                let actual = layout.undate("## LOCAL\n2025-04-20\n");
+                let expected = "## LOCAL\n2025-04-20\n";
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod render {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_ends_templates_with_single_newline_by_default() {
+                let layout = Layout::default();
+
+                let actual = layout.render("target/\n\n", "");
+                let expected = "target/\n";
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_starts_templates_with_banner_and_strips_newline() {
+                let layout = Layout {
+                    banner: true,
+                    trailing_newline: TrailingNewline::Never,
+                    ..Layout::default()
+                };
+
+                let actual = layout.render("target/", "gitignore-template-generator rust");
+                let expected = format!(
+                    "# Generated by gitignore-template-generator {}\n# Command: gitignore-template-generator rust\n\ntarget/",
+                    env!("CARGO_PKG_VERSION")
+                );
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod strip_banner {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_removes_banner_rendered_by_layout() {
+                let layout = Layout {
+                    banner: true,
+                    ..Layout::default()
+                };
+
+                let rendered = layout.render("target/", "gitignore-template-generator rust");
+                let actual = Layout::strip_banner(&rendered);
+                let expected = "target/\n";
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_keeps_content_without_banner() {
+                let actual = Layout::strip_banner("# Rust\ntarget/\n");
+                let expected = "# Rust\ntarget/\n";
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
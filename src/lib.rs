@@ -1,12 +1,24 @@
 #![doc = include_str!("../DOCUMENTATION.md")]
 
+pub mod api;
+pub mod cache;
 pub mod constant;
 pub mod core;
+pub mod dialect;
+pub mod diff;
+pub mod error;
 pub mod fs;
 pub mod helper;
 pub mod http_client;
+pub mod layout;
+pub mod lint;
+pub mod lockfile;
+pub mod logger;
+pub mod matcher;
 pub mod parser;
 pub mod printer;
 pub mod runner;
+pub mod search;
 pub mod test_helper;
 pub mod validator;
+pub mod verify;
@@ -0,0 +1,56 @@
+//! Define components to lint gitignore files and templates.
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Enum for kinds of issues found while linting gitignore content.
+#[derive(Clone, PartialEq, Debug)]
+pub enum LintIssueKind {
+    /// The content uses CRLF line endings.
+    CrlfLineEnding,
+
+    /// The rule ends with whitespaces that git ignores, as they are not
+    /// escaped with a backslash.
+    UnescapedTrailingSpace,
+
+    /// The rule uses `**` elsewhere than as a whole path segment, where it
+    /// behaves like a regular `*`.
+    InvalidDoubleAsterisk,
+
+    /// The rule pattern is invalid (e.g. an unclosed or reversed character
+    /// class), so that it never matches any path.
+    InvalidPattern,
+
+    /// The rule repeats the rule found at `first_line`.
+    Duplicate { first_line: usize },
+
+    /// The rule designates paths within a directory already excluded by the
+    /// rule found at `excluded_at`, so that it never changes anything.
+    UnreachableRule { excluded_at: usize },
+
+    /// The negation designates paths within a directory excluded by the rule
+    /// found at `excluded_at`, so that it can never match (git does not
+    /// re-include files whose parent directory is excluded).
+    UnreachableNegation { excluded_at: usize },
+}
+
+/// DTO struct representing an issue found while linting gitignore content.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LintIssue {
+    /// The line number of the issue, starting at 1.
+    pub line: usize,
+
+    /// The kind of the issue.
+    pub kind: LintIssueKind,
+}
+
+/// DTO struct representing the lint result of a gitignore file or template.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LintReport {
+    /// The linted source (e.g. a file path).
+    pub source: String,
+
+    /// The issues found, in line order.
+    pub issues: Vec<LintIssue>,
+}
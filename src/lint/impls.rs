@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use crate::{
+    constant::help_texts,
+    lint::{LintIssue, LintIssueKind, LintReport},
+    matcher::{GitignoreRule, parent_directories},
+};
+
+/// Finds the rule excluding the first excluded parent directory of given
+/// pattern, if any.
+///
+/// As in git, the last rule matching a directory decides whether it is
+/// excluded or re-included. Only the parent directories preceding any glob
+/// are known, and patterns without any inner slash match at any depth,
+/// therefore having no known parent directory.
+fn find_excluding_rule<'a>(rules: &'a [GitignoreRule], pattern: &str) -> Option<&'a GitignoreRule> {
+    parent_directories(pattern.trim_matches('/'))
+        .iter()
+        .take_while(|directory_path| !directory_path.contains(['*', '?', '[', '\\']))
+        .find_map(|directory_path| {
+            rules
+                .iter()
+                .rev()
+                .find(|rule| rule.matches(directory_path, true))
+                .filter(|rule| !rule.negated)
+        })
+}
+
+impl LintIssueKind {
+    /// Describes this issue kind.
+    pub fn message(&self) -> String {
+        match self {
+            Self::CrlfLineEnding => help_texts::LINT_CRLF_LINE_ENDING.to_string(),
+            Self::UnescapedTrailingSpace => help_texts::LINT_UNESCAPED_TRAILING_SPACE.to_string(),
+            Self::InvalidDoubleAsterisk => help_texts::LINT_INVALID_DOUBLE_ASTERISK.to_string(),
+            Self::InvalidPattern => help_texts::LINT_INVALID_PATTERN.to_string(),
+            Self::Duplicate { first_line } => {
+                help_texts::LINT_DUPLICATE.replace("{line}", &first_line.to_string())
+            }
+            Self::UnreachableRule { excluded_at } => {
+                help_texts::LINT_UNREACHABLE_RULE.replace("{line}", &excluded_at.to_string())
+            }
+            Self::UnreachableNegation { excluded_at } => {
+                help_texts::LINT_UNREACHABLE_NEGATION.replace("{line}", &excluded_at.to_string())
+            }
+        }
+    }
+}
+
+impl LintReport {
+    /// Lints given gitignore content.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The linted source, to be written in the report.
+    /// * `content` - The gitignore content to be linted.
+    ///
+    /// # Returns
+    ///
+    /// The lint result, listing the issues found in line order.
+    pub fn lint(source: &str, content: &str) -> Self {
+        let mut issues = Vec::new();
+        let mut rules: Vec<GitignoreRule> = Vec::new();
+        let mut first_lines: HashMap<&str, usize> = HashMap::new();
+        let mut has_crlf_issue = false;
+
+        for (index, line) in content.split('\n').enumerate() {
+            let line_number = index + 1;
+            let mut report = |kind| {
+                issues.push(LintIssue {
+                    line: line_number,
+                    kind,
+                })
+            };
+
+            if line.ends_with('\r') && !has_crlf_issue {
+                has_crlf_issue = true;
+                report(LintIssueKind::CrlfLineEnding);
+            }
+
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let trimmed_line = line.trim_end_matches([' ', '\t']);
+            let rule = if trimmed_line.len() == line.len() || trimmed_line.ends_with('\\') {
+                line
+            } else {
+                report(LintIssueKind::UnescapedTrailingSpace);
+                trimmed_line
+            };
+
+            let (negated, pattern) = match rule.strip_prefix('!') {
+                Some(pattern) => (true, pattern),
+                None => (false, rule),
+            };
+
+            if pattern
+                .split('/')
+                .any(|segment| segment.contains("**") && segment != "**")
+            {
+                report(LintIssueKind::InvalidDoubleAsterisk);
+            }
+
+            if GitignoreRule::parse(line_number, rule, None).is_some_and(|rule| !rule.is_valid()) {
+                report(LintIssueKind::InvalidPattern);
+            }
+
+            if let Some(first_line) = first_lines.get(rule) {
+                report(LintIssueKind::Duplicate {
+                    first_line: *first_line,
+                });
+                continue;
+            }
+
+            if let Some(excluding_rule) = find_excluding_rule(&rules, pattern) {
+                let excluded_at = excluding_rule.line;
+                report(if negated {
+                    LintIssueKind::UnreachableNegation { excluded_at }
+                } else {
+                    LintIssueKind::UnreachableRule { excluded_at }
+                });
+            }
+
+            first_lines.insert(rule, line_number);
+            rules.extend(GitignoreRule::parse(line_number, rule, None));
+        }
+
+        Self {
+            source: source.to_string(),
+            issues,
+        }
+    }
+
+    /// Tells whether no issue was found.
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Formats this lint result, with one line per issue.
+    pub fn to_report(&self) -> String {
+        if self.is_clean() {
+            return help_texts::LINT_CLEAN.replace("{source}", &self.source);
+        }
+
+        self.issues
+            .iter()
+            .map(|issue| {
+                help_texts::LINT_ISSUE
+                    .replace("{source}", &self.source)
+                    .replace("{line}", &issue.line.to_string())
+                    .replace("{message}", &issue.kind.message())
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
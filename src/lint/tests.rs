@@ -0,0 +1,107 @@
+use rstest::rstest;
+
+use crate::lint::{LintIssue, LintIssueKind, LintReport};
+
+mod lint_report {
+    use super::*;
+
+    mod lint {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_finds_no_issues_in_valid_content() {
+                let content = "# Rust\ntarget/\n**/*.rs.bk\n\n*\n!*/\n!src/**\n\\#notes\n";
+
+                let actual = LintReport::lint(".gitignore", content);
+
+                assert!(actual.is_clean());
+            }
+
+            #[rstest]
+            #[case("foo\r\nbar\r\n", 1, LintIssueKind::CrlfLineEnding)]
+            #[case("foo\n*.log \n", 2, LintIssueKind::UnescapedTrailingSpace)]
+            #[case("foo**/bar\n", 1, LintIssueKind::InvalidDoubleAsterisk)]
+            #[case("foo\ndebug[0-9.log\n", 2, LintIssueKind::InvalidPattern)]
+            #[case("*.log\ntarget/\n*.log\n", 3, LintIssueKind::Duplicate { first_line: 1 })]
+            #[case("target/\ntarget/debug\n", 2, LintIssueKind::UnreachableRule { excluded_at: 1 })]
+            #[case(
+                "/target/\n/target/debug/\n",
+                2,
+                LintIssueKind::UnreachableRule { excluded_at: 1 }
+            )]
+            #[case(
+                "build\n!build/keep.txt\n",
+                2,
+                LintIssueKind::UnreachableNegation { excluded_at: 1 }
+            )]
+            fn it_finds_issue(
+                #[case] content: &str,
+                #[case] line: usize,
+                #[case] kind: LintIssueKind,
+            ) {
+                let actual = LintReport::lint(".gitignore", content).issues;
+                let expected = vec![LintIssue { line, kind }];
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_allows_negation_once_parent_directory_is_re_included() {
+                let content = "logs/\n!logs/\n!logs/keep.log\n";
+
+                let actual = LintReport::lint(".gitignore", content);
+
+                assert!(actual.is_clean());
+            }
+
+            #[test]
+            fn it_ignores_parent_directories_past_a_glob() {
+                let content = "cache/*/\n!cache/*/keep.txt\n";
+
+                let actual = LintReport::lint(".gitignore", content);
+
+                assert!(actual.is_clean());
+            }
+
+            #[test]
+            fn it_keeps_escaped_trailing_space() {
+                let content = "foo\\ \n";
+
+                let actual = LintReport::lint(".gitignore", content);
+
+                assert!(actual.is_clean());
+            }
+        }
+    }
+
+    mod to_report {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_reports_each_issue_with_its_line() {
+                let report = LintReport::lint(".gitignore", "foo \nfoo\n");
+
+                let actual = report.to_report();
+                let expected = ".gitignore:1: trailing whitespace is ignored unless escaped with a backslash\n.gitignore:2: duplicate of the rule at line 1";
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_reports_clean_source() {
+                let report = LintReport::lint(".gitignore", "foo\n");
+
+                let actual = report.to_report();
+                let expected = ".gitignore: no issues found";
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
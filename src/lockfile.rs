@@ -0,0 +1,36 @@
+//! Define components to lock generated templates.
+use crate::core::StringKind;
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// DTO struct representing a template recorded in a lockfile.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LockedTemplate {
+    /// The template name, as given in cli args.
+    pub name: String,
+
+    /// The source that served the template.
+    pub source: StringKind,
+
+    /// The location the template got served from (i.e. the template file
+    /// path for local templates, or the url it got fetched from for remote
+    /// ones).
+    pub location: String,
+
+    /// The lowercase hex SHA-256 hash of the template content.
+    pub hash: String,
+}
+
+/// DTO struct representing a lockfile.
+///
+/// A lockfile records, for each generated template, which source served it
+/// and a hash of its content, so that later generations can detect
+/// upstream changes. It is stored as a JSON document.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Lockfile {
+    /// The locked templates, in generation order.
+    pub templates: Vec<LockedTemplate>,
+}
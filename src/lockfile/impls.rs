@@ -0,0 +1,198 @@
+use std::fs;
+
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    constant::error_messages,
+    core::StringKind,
+    error::Error,
+    lockfile::{LockedTemplate, Lockfile},
+};
+
+fn parse_source(source: &str) -> Option<StringKind> {
+    match source {
+        "local" => Some(StringKind::Local),
+        "remote" => Some(StringKind::Remote),
+        "mixed" => Some(StringKind::Mixed),
+        _ => None,
+    }
+}
+
+fn lockfile_error(path: &str, error: &str) -> Error {
+    Error::InvalidInput(
+        error_messages::LOCKFILE_PARSING
+            .replace("{path}", path)
+            .replace("{error}", error),
+    )
+}
+
+impl LockedTemplate {
+    /// Creates a locked template, hashing given content.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The template name.
+    /// * `source` - The source that served the template.
+    /// * `location` - The location the template got served from.
+    /// * `content` - The template content to be hashed.
+    ///
+    /// # Returns
+    ///
+    /// A locked template holding the SHA-256 hash of given content.
+    pub fn new(name: &str, source: StringKind, location: &str, content: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            source,
+            location: location.to_string(),
+            hash: Sha256::digest(content.as_bytes())
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect(),
+        }
+    }
+}
+
+impl Lockfile {
+    pub fn new(templates: Vec<LockedTemplate>) -> Self {
+        Self { templates }
+    }
+
+    /// Serializes this lockfile as a pretty-printed JSON document.
+    pub fn to_json(&self) -> String {
+        let templates: Vec<Value> = self
+            .templates
+            .iter()
+            .map(|template| {
+                json!({
+                    "name": template.name,
+                    "source": template.source.name(),
+                    "location": template.location,
+                    "hash": template.hash,
+                })
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&json!({ "templates": templates })).unwrap_or_default()
+    }
+
+    /// Deserializes a lockfile from given JSON document.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the lockfile, only used in error messages.
+    /// * `content` - The JSON document to be parsed.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the parsed lockfile, or an [`Error`] if the
+    /// document is not a valid lockfile.
+    pub fn from_json(path: &str, content: &str) -> Result<Self, Error> {
+        let value: Value = serde_json::from_str(content)
+            .map_err(|error| lockfile_error(path, &error.to_string()))?;
+        let templates = value
+            .get("templates")
+            .and_then(Value::as_array)
+            .ok_or_else(|| lockfile_error(path, error_messages::LOCKFILE_NO_TEMPLATES))?;
+
+        let templates = templates
+            .iter()
+            .enumerate()
+            .map(|(index, template)| {
+                let field = |name: &str| {
+                    template
+                        .get(name)
+                        .and_then(Value::as_str)
+                        .map(String::from)
+                        .ok_or_else(|| {
+                            lockfile_error(
+                                path,
+                                &error_messages::LOCKFILE_MISSING_FIELD
+                                    .replace("{field}", name)
+                                    .replace("{index}", &index.to_string()),
+                            )
+                        })
+                };
+                let source = field("source")?;
+
+                Ok(LockedTemplate {
+                    name: field("name")?,
+                    source: parse_source(&source).ok_or_else(|| {
+                        lockfile_error(
+                            path,
+                            &error_messages::LOCKFILE_INVALID_SOURCE.replace("{source}", &source),
+                        )
+                    })?,
+                    location: field("location")?,
+                    hash: field("hash")?,
+                })
+            })
+            .collect::<Result<Vec<LockedTemplate>, Error>>()?;
+
+        Ok(Self { templates })
+    }
+
+    /// Loads the lockfile stored at given path.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the loaded lockfile, or an [`Error`] if the
+    /// file could not be read or is not a valid lockfile.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        let content = fs::read_to_string(path).map_err(|error| {
+            Error::io(
+                &error_messages::FILE_READ_FAILURE
+                    .replace("{path}", path)
+                    .replace("{error}", &error.to_string()),
+                error,
+            )
+        })?;
+
+        Self::from_json(path, &content)
+    }
+
+    /// Stores this lockfile at given path, replacing any previous one.
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or an [`Error`] if the file could not be
+    /// written.
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        fs::write(path, format!("{}\n", self.to_json())).map_err(|error| {
+            Error::io(
+                &error_messages::FILE_WRITE_FAILURE
+                    .replace("{path}", path)
+                    .replace("{error}", &error.to_string()),
+                error,
+            )
+        })
+    }
+
+    /// Lists the templates of given lockfile not matching this one.
+    ///
+    /// A template is considered changed when it is not recorded in this
+    /// lockfile with the same source and content hash. Locations are not
+    /// compared, as local template paths depend on the machine.
+    ///
+    /// # Arguments
+    ///
+    /// * `actual` - The lockfile computed from freshly generated templates.
+    ///
+    /// # Returns
+    ///
+    /// The names of the changed templates, in `actual` order.
+    pub fn changed_templates(&self, actual: &Lockfile) -> Vec<String> {
+        actual
+            .templates
+            .iter()
+            .filter(|template| {
+                !self.templates.iter().any(|locked_template| {
+                    locked_template.name == template.name
+                        && locked_template.source == template.source
+                        && locked_template.hash == template.hash
+                })
+            })
+            .map(|template| template.name.clone())
+            .collect()
+    }
+}
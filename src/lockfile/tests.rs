@@ -0,0 +1,131 @@
+use crate::{
+    constant::{error_messages, exit_status},
+    core::{ExitKind, ProgramExit, StringKind},
+    lockfile::{LockedTemplate, Lockfile},
+};
+
+fn lockfile() -> Lockfile {
+    Lockfile::new(vec![
+        LockedTemplate::new("rust", StringKind::Local, "/tmp/templates", "target/"),
+        LockedTemplate::new(
+            "python",
+            StringKind::Remote,
+            "https://www.toptal.com/developers/gitignore/api",
+            "__pycache__/",
+        ),
+    ])
+}
+
+mod locked_template {
+    use super::*;
+
+    mod new {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_hashes_content_with_sha256() {
+                let actual = LockedTemplate::new("rust", StringKind::Local, "/tmp", "abc").hash;
+                let expected = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod lockfile {
+    use super::*;
+
+    mod from_json {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_parses_serialized_lockfile() {
+                let lockfile = lockfile();
+
+                let actual = Lockfile::from_json("foo.lock", &lockfile.to_json());
+                let expected = Ok(lockfile);
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            fn it_fails_when_templates_array_is_missing() {
+                let actual = Lockfile::from_json("foo.lock", "{}").map_err(ProgramExit::from);
+                let expected = Err(ProgramExit {
+                    message: error_messages::LOCKFILE_PARSING
+                        .replace("{path}", "foo.lock")
+                        .replace("{error}", error_messages::LOCKFILE_NO_TEMPLATES),
+                    exit_status: exit_status::GENERIC,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                });
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_fails_when_source_is_unknown() {
+                let content = r#"{"templates": [{"name": "rust", "source": "foo", "location": "", "hash": ""}]}"#;
+
+                let actual = Lockfile::from_json("foo.lock", content).map_err(ProgramExit::from);
+                let expected = Err(ProgramExit {
+                    message: error_messages::LOCKFILE_PARSING
+                        .replace("{path}", "foo.lock")
+                        .replace(
+                            "{error}",
+                            &error_messages::LOCKFILE_INVALID_SOURCE.replace("{source}", "foo"),
+                        ),
+                    exit_status: exit_status::GENERIC,
+                    styled_message: None,
+                    kind: ExitKind::Error,
+                });
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod changed_templates {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_returns_nothing_when_same_templates() {
+                let actual = lockfile().changed_templates(&lockfile());
+                let expected: Vec<String> = Vec::new();
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_returns_templates_with_different_or_missing_entry() {
+                let mut actual_lockfile = lockfile();
+                actual_lockfile.templates[1].hash = String::from("foo");
+                actual_lockfile.templates.push(LockedTemplate::new(
+                    "node",
+                    StringKind::Local,
+                    "/tmp/templates",
+                    "node_modules/",
+                ));
+
+                let actual = lockfile().changed_templates(&actual_lockfile);
+                let expected = vec![String::from("python"), String::from("node")];
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
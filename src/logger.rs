@@ -0,0 +1,32 @@
+//! Define components to log what the program is doing.
+//!
+//! Logs are written to stderr, as allowed by the global [`Verbosity`] set
+//! once cli args got parsed (see [`Logger::set_verbosity`]).
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Enum of verbosity levels, from the least to the most verbose.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only report failures through the exit status, without printing
+    /// error messages.
+    Quiet,
+
+    /// Print error messages, without logging anything else.
+    #[default]
+    Normal,
+
+    /// Also log the main steps of the run (e.g. template directory, HTTP
+    /// calls, template routing).
+    Verbose,
+
+    /// Also log debugging details (e.g. resolved cli args, overridden
+    /// templates, cache hits).
+    Debug,
+}
+
+/// Logger of what the program is doing, writing to stderr.
+pub struct Logger;
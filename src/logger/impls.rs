@@ -0,0 +1,100 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{
+    constant::log_messages,
+    logger::{Logger, Verbosity},
+};
+
+/// Global verbosity, stored as the position of its [`Verbosity`] variant.
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+impl Verbosity {
+    /// Gets the verbosity matching given cli flags.
+    ///
+    /// # Arguments
+    ///
+    /// * `verbose` - The number of times the verbose option was given.
+    /// * `quiet` - Whether the quiet option was given.
+    pub fn from_flags(verbose: u8, quiet: bool) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Self::Quiet,
+            (false, 0) => Self::Normal,
+            (false, 1) => Self::Verbose,
+            (false, _) => Self::Debug,
+        }
+    }
+
+    /// Gets the lowercase name of this verbosity (e.g. `debug`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Quiet => "quiet",
+            Self::Normal => "normal",
+            Self::Verbose => "verbose",
+            Self::Debug => "debug",
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Quiet,
+            1 => Self::Normal,
+            2 => Self::Verbose,
+            _ => Self::Debug,
+        }
+    }
+}
+
+impl Logger {
+    /// Sets the global verbosity, deciding which logs get written.
+    pub fn set_verbosity(verbosity: Verbosity) {
+        VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+    }
+
+    /// Gets the global verbosity.
+    pub fn verbosity() -> Verbosity {
+        Verbosity::from_u8(VERBOSITY.load(Ordering::Relaxed))
+    }
+
+    /// Checks whether logs of given verbosity get written.
+    pub fn is_enabled(verbosity: Verbosity) -> bool {
+        Self::verbosity() >= verbosity
+    }
+
+    /// Logs given message if the global verbosity is at least
+    /// [`Verbosity::Verbose`].
+    pub fn verbose(message: &str) {
+        Self::log(Verbosity::Verbose, message);
+    }
+
+    /// Logs given message if the global verbosity is
+    /// [`Verbosity::Debug`].
+    pub fn debug(message: &str) {
+        Self::log(Verbosity::Debug, message);
+    }
+
+    /// Logs given message as a warning if the global verbosity is at least
+    /// [`Verbosity::Normal`], i.e. unless on quiet runs.
+    pub fn warn(message: &str) {
+        if Self::is_enabled(Verbosity::Normal) {
+            eprintln!(
+                "{}",
+                log_messages::LOG_LINE
+                    .replace("{level}", log_messages::WARNING_LEVEL)
+                    .replace("{message}", message)
+            );
+        }
+    }
+
+    /// Formats given message as a log line of given verbosity.
+    pub fn format(verbosity: Verbosity, message: &str) -> String {
+        log_messages::LOG_LINE
+            .replace("{level}", verbosity.name())
+            .replace("{message}", message)
+    }
+
+    fn log(verbosity: Verbosity, message: &str) {
+        if Self::is_enabled(verbosity) {
+            eprintln!("{}", Self::format(verbosity, message));
+        }
+    }
+}
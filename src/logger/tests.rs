@@ -0,0 +1,76 @@
+use rstest::rstest;
+use serial_test::serial;
+
+use crate::logger::{Logger, Verbosity};
+
+mod verbosity {
+    use super::*;
+
+    mod from_flags {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case(0, false, Verbosity::Normal)]
+            #[case(1, false, Verbosity::Verbose)]
+            #[case(2, false, Verbosity::Debug)]
+            #[case(5, false, Verbosity::Debug)]
+            #[case(0, true, Verbosity::Quiet)]
+            fn it_maps_cli_flags_to_verbosity(
+                #[case] verbose: u8,
+                #[case] quiet: bool,
+                #[case] expected: Verbosity,
+            ) {
+                let actual = Verbosity::from_flags(verbose, quiet);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod logger {
+    use super::*;
+
+    mod format {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_prefixes_message_with_verbosity_name() {
+                let actual = Logger::format(Verbosity::Debug, "Resolved cli args");
+                let expected = "[debug] Resolved cli args";
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod is_enabled {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            #[serial]
+            fn it_enables_logs_up_to_global_verbosity() {
+                Logger::set_verbosity(Verbosity::Verbose);
+
+                let actual = [
+                    Logger::is_enabled(Verbosity::Normal),
+                    Logger::is_enabled(Verbosity::Verbose),
+                    Logger::is_enabled(Verbosity::Debug),
+                ];
+                Logger::set_verbosity(Verbosity::Normal);
+                let expected = [true, true, false];
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
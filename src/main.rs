@@ -9,7 +9,7 @@ use gitignore_template_generator::{
 fn main() {
     start(|runner, parser| match runner.exec(&parser) {
         Ok(output) => handle_success(&output),
-        Err(error) => handle_failure(&error),
+        Err(error) => handle_failure(&ProgramExit::from(error)),
     });
 }
 
@@ -0,0 +1,75 @@
+//! Define components to match paths against gitignore rules.
+use regex::Regex;
+
+pub(crate) use crate::matcher::impls::parent_directories;
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// DTO struct representing a rule of gitignore content.
+///
+/// Rules are built with [`GitignoreRule::parse`], and matched against
+/// paths the way git does.
+#[derive(Clone, Debug)]
+pub struct GitignoreRule {
+    /// The line number of the rule, starting at 1.
+    pub line: usize,
+
+    /// The rule as written, without unescaped trailing whitespaces.
+    pub rule: String,
+
+    /// Whether the rule is a negation (i.e. starts with `!`) re-including
+    /// the paths it matches.
+    pub negated: bool,
+
+    /// The name of the template section the rule belongs to, if any.
+    pub section: Option<String>,
+
+    directory_only: bool,
+    anchored: bool,
+    regex: Option<Regex>,
+}
+
+/// DTO struct representing parsed gitignore content, able to tell whether
+/// paths are ignored.
+///
+/// Template sections are identified by the `### Name ###` headers emitted
+/// by the template managers.
+#[derive(Clone, Debug, Default)]
+pub struct GitignoreMatcher {
+    /// The rules of the content, in line order.
+    pub rules: Vec<GitignoreRule>,
+}
+
+/// Enum for the status of a path against gitignore rules.
+#[derive(Clone, PartialEq, Debug)]
+pub enum PathStatus {
+    /// No rule matches the path, which is not ignored.
+    Unmatched,
+
+    /// The path is ignored by `rule`.
+    Ignored { rule: GitignoreRule },
+
+    /// The path is re-included by the negation `rule`, and thus not ignored.
+    ReIncluded { rule: GitignoreRule },
+
+    /// The parent `directory` of the path is ignored by `rule`, so that the
+    /// path is ignored too (git does not re-include files whose parent
+    /// directory is excluded).
+    IgnoredParent {
+        directory: String,
+        rule: GitignoreRule,
+    },
+}
+
+/// DTO struct representing why a path is ignored or not.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PathExplanation {
+    /// The explained path, relative to the gitignore file location.
+    pub path: String,
+
+    /// The status of the path, along with the deciding rule if any.
+    pub status: PathStatus,
+}
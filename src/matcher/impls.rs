@@ -0,0 +1,404 @@
+use std::{
+    fs::{self, DirEntry},
+    io,
+    path::Path,
+};
+
+use regex::Regex;
+
+use crate::{
+    constant::{help_texts, path},
+    matcher::{GitignoreMatcher, GitignoreRule, PathExplanation, PathStatus},
+};
+
+/// Names of the POSIX character classes supported within bracket
+/// expressions (e.g. `[[:digit:]]`).
+const POSIX_CLASSES: [&str; 12] = [
+    "alnum", "alpha", "blank", "cntrl", "digit", "graph", "lower", "print", "punct", "space",
+    "upper", "xdigit",
+];
+
+/// Escapes given character to be used literally within a regular
+/// expression class.
+fn escape_class_char(c: char) -> String {
+    if c.is_ascii_punctuation() {
+        format!("\\{c}")
+    } else {
+        c.to_string()
+    }
+}
+
+/// Converts the bracket expression starting given glob pattern characters
+/// into a regular expression class.
+///
+/// As in git, `!` or `^` negates the class, a `]` right after the opening
+/// bracket is taken literally, `-` designates a range unless first or last,
+/// and `[:name:]` designates a POSIX character class.
+///
+/// # Returns
+///
+/// The regular expression class along with the number of pattern characters
+/// it spans, or `None` if the bracket expression is never closed or names
+/// an unknown POSIX character class.
+fn class_to_regex(chars: &[char]) -> Option<(String, usize)> {
+    let mut class = String::from("[");
+    let mut index = 1;
+
+    if matches!(chars.get(index), Some('!' | '^')) {
+        class.push_str("^/");
+        index += 1;
+    }
+    let first_index = index;
+
+    loop {
+        match *chars.get(index)? {
+            ']' if index > first_index => {
+                class.push(']');
+                return Some((class, index + 1));
+            }
+            '[' if chars.get(index + 1) == Some(&':') => {
+                match chars[index + 2..]
+                    .windows(2)
+                    .position(|window| window == [':', ']'])
+                {
+                    Some(length) => {
+                        let name: String = chars[index + 2..index + 2 + length].iter().collect();
+                        if !POSIX_CLASSES.contains(&name.as_str()) {
+                            return None;
+                        }
+                        class.extend(&chars[index..index + length + 4]);
+                        index += length + 3;
+                    }
+                    None => class.push_str(&escape_class_char('[')),
+                }
+            }
+            '\\' => {
+                index += 1;
+                class.push_str(&escape_class_char(*chars.get(index)?));
+            }
+            '-' if index > first_index && chars.get(index + 1).is_some_and(|c| *c != ']') => {
+                class.push('-')
+            }
+            c => class.push_str(&escape_class_char(c)),
+        }
+        index += 1;
+    }
+}
+
+/// Converts given gitignore glob pattern into an anchored regular
+/// expression, or `None` if the pattern is invalid (e.g. an unclosed or
+/// reversed character class), in which case it never matches, as in git.
+fn glob_to_regex(pattern: &str) -> Option<Regex> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut regex = String::from("^");
+    let mut index = 0;
+
+    while index < chars.len() {
+        let rest: String = chars[index..].iter().take(3).collect();
+
+        if rest == "**/" {
+            regex.push_str("(?:.*/)?");
+            index += 3;
+        } else if rest == "/**" && index + 3 == chars.len() {
+            regex.push_str("/.*");
+            index += 3;
+        } else if rest.starts_with("**") {
+            regex.push_str(".*");
+            index += 2;
+        } else {
+            match chars[index] {
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                '[' => {
+                    let (class, length) = class_to_regex(&chars[index..])?;
+                    regex.push_str(&class);
+                    index += length - 1;
+                }
+                '\\' if index + 1 < chars.len() => {
+                    index += 1;
+                    regex.push_str(&regex::escape(&chars[index].to_string()));
+                }
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+            index += 1;
+        }
+    }
+    regex.push('$');
+
+    Regex::new(&regex).ok()
+}
+
+/// Lists the parent directories of given path, shallowest first.
+pub(crate) fn parent_directories(path: &str) -> Vec<String> {
+    let mut segments: Vec<&str> = path.split('/').collect();
+    segments.pop();
+
+    segments
+        .iter()
+        .scan(String::new(), |directory_path, segment| {
+            if !directory_path.is_empty() {
+                directory_path.push('/');
+            }
+            directory_path.push_str(segment);
+            Some(directory_path.clone())
+        })
+        .collect()
+}
+
+impl GitignoreRule {
+    /// Parses given line of gitignore content into a rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line number of the rule, starting at 1.
+    /// * `content` - The line content.
+    /// * `section` - The name of the template section the line belongs to,
+    ///   if any.
+    ///
+    /// # Returns
+    ///
+    /// The parsed rule, or `None` if the line is empty or a comment.
+    pub fn parse(line: usize, content: &str, section: Option<&str>) -> Option<Self> {
+        let content = content.trim_end_matches('\r');
+        if content.trim().is_empty() || content.starts_with('#') {
+            return None;
+        }
+
+        let trimmed_content = content.trim_end_matches([' ', '\t']);
+        let rule = if trimmed_content.ends_with('\\') && trimmed_content.len() < content.len() {
+            &content[..=trimmed_content.len()]
+        } else {
+            trimmed_content
+        };
+
+        let (negated, pattern) = match rule.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, rule),
+        };
+        let directory_only = pattern.ends_with('/');
+        let pattern = pattern.trim_end_matches('/');
+        let anchored = pattern.contains('/');
+
+        Some(Self {
+            line,
+            rule: rule.to_string(),
+            negated,
+            section: section.map(String::from),
+            directory_only,
+            anchored,
+            regex: glob_to_regex(pattern.trim_start_matches('/')),
+        })
+    }
+
+    /// Whether the pattern of this rule is valid, an invalid one never
+    /// matching any path.
+    pub fn is_valid(&self) -> bool {
+        self.regex.is_some()
+    }
+
+    /// Tells whether this rule matches given path, relative to the gitignore
+    /// file location.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The matched path, without leading nor trailing slash.
+    /// * `is_directory` - Whether the path designates a directory.
+    pub fn matches(&self, path: &str, is_directory: bool) -> bool {
+        if self.directory_only && !is_directory {
+            return false;
+        }
+
+        let path = if self.anchored {
+            path
+        } else {
+            path.rsplit('/').next().unwrap_or_default()
+        };
+
+        self.regex
+            .as_ref()
+            .is_some_and(|regex| regex.is_match(path))
+    }
+
+    /// Formats the location of this rule, along with its template section
+    /// if any.
+    fn to_location(&self) -> String {
+        let location = help_texts::EXPLAIN_RULE
+            .replace("{rule}", &self.rule)
+            .replace("{line}", &self.line.to_string());
+
+        match &self.section {
+            Some(section) => {
+                location + &help_texts::EXPLAIN_RULE_SECTION.replace("{section}", section)
+            }
+            None => location,
+        }
+    }
+}
+
+impl PartialEq for GitignoreRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.line == other.line && self.rule == other.rule && self.section == other.section
+    }
+}
+
+impl GitignoreMatcher {
+    /// Parses given gitignore content into its rules.
+    pub fn parse(content: &str) -> Self {
+        let mut section = None;
+        let mut rules = Vec::new();
+
+        for (index, line) in content.split('\n').enumerate() {
+            if let Some(title) = line
+                .trim()
+                .strip_prefix("### ")
+                .and_then(|line| line.strip_suffix(" ###"))
+            {
+                section = Some(title.trim_start_matches('*').trim().to_string());
+            }
+
+            rules.extend(GitignoreRule::parse(index + 1, line, section.as_deref()));
+        }
+
+        Self { rules }
+    }
+
+    /// Explains whether given path is ignored, and which rule decided it.
+    ///
+    /// As in git, the last rule matching a path decides whether it is
+    /// ignored, unless one of its parent directories is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The explained path, relative to the gitignore file
+    ///   location. Directories are designated by a trailing slash.
+    ///
+    /// # Returns
+    ///
+    /// The explanation of the path status.
+    pub fn explain(&self, path: &str) -> PathExplanation {
+        let is_directory = path.ends_with('/');
+        let normalized_path = path.trim_start_matches("./").trim_matches('/');
+
+        let excluded_parent =
+            parent_directories(normalized_path)
+                .into_iter()
+                .find_map(|directory| {
+                    self.find_deciding_rule(&directory, true)
+                        .filter(|rule| !rule.negated)
+                        .map(|rule| (directory, rule))
+                });
+
+        let status = match excluded_parent {
+            Some((directory, rule)) => PathStatus::IgnoredParent {
+                directory,
+                rule: rule.clone(),
+            },
+            None => match self.find_deciding_rule(normalized_path, is_directory) {
+                Some(rule) if rule.negated => PathStatus::ReIncluded { rule: rule.clone() },
+                Some(rule) => PathStatus::Ignored { rule: rule.clone() },
+                None => PathStatus::Unmatched,
+            },
+        };
+
+        PathExplanation {
+            path: path.to_string(),
+            status,
+        }
+    }
+
+    /// Lists the entries of given directory that would be ignored.
+    ///
+    /// Directories are walked through recursively, except ignored ones
+    /// (only listed themselves) and the git repository one.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory_path` - The walked directory, where the gitignore file
+    ///   would be located.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the explanations of the ignored entries, sorted
+    /// by path, or an I/O error if any directory could not be read.
+    pub fn ignored_entries(&self, directory_path: &Path) -> io::Result<Vec<PathExplanation>> {
+        let mut ignored_entries = Vec::new();
+        self.collect_ignored_entries(directory_path, "", &mut ignored_entries)?;
+
+        Ok(ignored_entries)
+    }
+
+    fn collect_ignored_entries(
+        &self,
+        directory_path: &Path,
+        prefix: &str,
+        ignored_entries: &mut Vec<PathExplanation>,
+    ) -> io::Result<()> {
+        let mut entries = fs::read_dir(directory_path)?.collect::<io::Result<Vec<DirEntry>>>()?;
+        entries.sort_by_key(DirEntry::file_name);
+
+        for entry in entries {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if file_name == path::GIT_DIRECTORY {
+                continue;
+            }
+
+            let is_directory = entry.file_type()?.is_dir();
+            let entry_path = if is_directory {
+                format!("{prefix}{file_name}/")
+            } else {
+                format!("{prefix}{file_name}")
+            };
+
+            let explanation = self.explain(&entry_path);
+            if explanation.is_ignored() {
+                ignored_entries.push(explanation);
+            } else if is_directory {
+                self.collect_ignored_entries(&entry.path(), &entry_path, ignored_entries)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find_deciding_rule(&self, path: &str, is_directory: bool) -> Option<&GitignoreRule> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(path, is_directory))
+    }
+}
+
+impl PathExplanation {
+    /// Whether the explained path is ignored.
+    pub fn is_ignored(&self) -> bool {
+        matches!(
+            self.status,
+            PathStatus::Ignored { .. } | PathStatus::IgnoredParent { .. }
+        )
+    }
+
+    /// Whether the explained path names a file usually tracked by version
+    /// control (see [`path::TRACKED_LOOKING_FILES`]).
+    pub fn is_tracked_looking(&self) -> bool {
+        let file_name = self.path.trim_end_matches('/').rsplit('/').next();
+
+        file_name.is_some_and(|file_name| path::TRACKED_LOOKING_FILES.contains(&file_name))
+    }
+
+    /// Formats this explanation as a report entry.
+    pub fn to_report(&self) -> String {
+        match &self.status {
+            PathStatus::Unmatched => help_texts::EXPLAIN_UNMATCHED.to_string(),
+            PathStatus::Ignored { rule } => {
+                help_texts::EXPLAIN_IGNORED.replace("{rule}", &rule.to_location())
+            }
+            PathStatus::ReIncluded { rule } => {
+                help_texts::EXPLAIN_RE_INCLUDED.replace("{rule}", &rule.to_location())
+            }
+            PathStatus::IgnoredParent { directory, rule } => help_texts::EXPLAIN_IGNORED_PARENT
+                .replace("{directory}", directory)
+                .replace("{rule}", &rule.to_location()),
+        }
+        .replace("{path}", &self.path)
+    }
+}
@@ -0,0 +1,280 @@
+use rstest::rstest;
+
+use crate::{
+    matcher::{GitignoreMatcher, GitignoreRule, PathStatus},
+    test_helper::{DefaultTestUtils, TestUtils},
+};
+
+fn rule(line: usize, rule: &str, section: Option<&str>) -> GitignoreRule {
+    GitignoreRule::parse(line, rule, section).unwrap()
+}
+
+mod gitignore_rule {
+    use super::*;
+
+    mod parse {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case("")]
+            #[case("   ")]
+            #[case("# comment")]
+            fn it_skips_empty_lines_and_comments(#[case] content: &str) {
+                let actual = GitignoreRule::parse(1, content, None);
+
+                assert!(actual.is_none());
+            }
+
+            #[rstest]
+            #[case("*.log  ", "*.log")]
+            #[case("foo\\ ", "foo\\ ")]
+            #[case("!keep.log\r", "!keep.log")]
+            fn it_trims_unescaped_trailing_whitespaces(
+                #[case] content: &str,
+                #[case] expected: &str,
+            ) {
+                let actual = rule(1, content, None).rule;
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod matches {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case("*.log", "logs/debug.log", false)]
+            #[case("target/", "foo/target", true)]
+            #[case("/target", "target", false)]
+            #[case("doc/*.txt", "doc/notes.txt", false)]
+            #[case("**/build", "src/build", true)]
+            #[case("logs/**", "logs/2024/debug.log", false)]
+            #[case("a/**/b", "a/x/y/b", false)]
+            #[case("a/**/b", "a/b", false)]
+            #[case("debug[0-9].log", "debug1.log", false)]
+            #[case("debug[!0-9].log", "debuga.log", false)]
+            #[case("\\#notes", "#notes", false)]
+            #[case("[]a]b", "]b", false)]
+            #[case("file[.+]txt", "file+txt", false)]
+            #[case("file[a-c-]", "file-", false)]
+            #[case("file[[:digit:]]", "file7", false)]
+            #[case("file[\\]]", "file]", false)]
+            fn it_matches_path(#[case] pattern: &str, #[case] path: &str, #[case] is_dir: bool) {
+                let actual = rule(1, pattern, None).matches(path, is_dir);
+
+                assert!(actual);
+            }
+
+            #[rstest]
+            #[case("target/", "target", false)]
+            #[case("/target", "foo/target", true)]
+            #[case("doc/*.txt", "doc/server/api.txt", false)]
+            #[case("*.log", "debug.log.bak", false)]
+            #[case("debug[0-9].log", "debuga.log", false)]
+            #[case("file[.+]txt", "fileatxt", false)]
+            #[case("/a[!b]c", "a/c", false)]
+            #[case("debug[0-9.log", "debug[0-9.log", false)]
+            #[case("debug[9-0].log", "debug5.log", false)]
+            fn it_does_not_match_path(
+                #[case] pattern: &str,
+                #[case] path: &str,
+                #[case] is_dir: bool,
+            ) {
+                let actual = rule(1, pattern, None).matches(path, is_dir);
+
+                assert!(!actual);
+            }
+
+            #[rstest]
+            #[case("debug[0-9].log", true)]
+            #[case("debug[0-9.log", false)]
+            #[case("debug[9-0].log", false)]
+            #[case("file[[:foo:]]", false)]
+            fn it_tells_whether_pattern_is_valid(#[case] pattern: &str, #[case] expected: bool) {
+                let actual = rule(1, pattern, None).is_valid();
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod gitignore_matcher {
+    use super::*;
+
+    mod parse {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_binds_rules_to_their_template_section() {
+                let content =
+                    "## LOCAL\n### *Rust ###\ntarget/\n\n## REMOTE\n### Python ###\n*.pyc\n";
+
+                let actual = GitignoreMatcher::parse(content).rules;
+                let expected = vec![
+                    rule(3, "target/", Some("Rust")),
+                    rule(7, "*.pyc", Some("Python")),
+                ];
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod explain {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_explains_unmatched_path() {
+                let matcher = GitignoreMatcher::parse("*.log\n");
+
+                let actual = matcher.explain("src/main.rs");
+
+                assert_eq!(actual.status, PathStatus::Unmatched);
+                assert!(!actual.is_ignored());
+            }
+
+            #[test]
+            fn it_explains_path_ignored_by_last_matching_rule() {
+                let matcher = GitignoreMatcher::parse("*.log\n!keep.log\nkeep.*\n");
+
+                let actual = matcher.explain("logs/keep.log");
+                let expected = PathStatus::Ignored {
+                    rule: rule(3, "keep.*", None),
+                };
+
+                assert_eq!(actual.status, expected);
+                assert!(actual.is_ignored());
+            }
+
+            #[test]
+            fn it_explains_path_re_included_by_negation() {
+                let matcher = GitignoreMatcher::parse("### Logs ###\n*.log\n!keep.log\n");
+
+                let actual = matcher.explain("keep.log");
+                let expected = PathStatus::ReIncluded {
+                    rule: rule(3, "!keep.log", Some("Logs")),
+                };
+
+                assert_eq!(actual.status, expected);
+                assert!(!actual.is_ignored());
+            }
+
+            #[test]
+            fn it_explains_path_within_ignored_directory() {
+                let matcher = GitignoreMatcher::parse("build/\n!build/keep.txt\n");
+
+                let actual = matcher.explain("build/keep.txt");
+                let expected = PathStatus::IgnoredParent {
+                    directory: String::from("build"),
+                    rule: rule(1, "build/", None),
+                };
+
+                assert_eq!(actual.status, expected);
+                assert!(actual.is_ignored());
+            }
+
+            #[test]
+            fn it_explains_path_within_re_included_directory() {
+                let matcher = GitignoreMatcher::parse("/*\n!/src/\n");
+
+                let actual = matcher.explain("src/main.rs");
+
+                assert_eq!(actual.status, PathStatus::Unmatched);
+            }
+
+            #[rstest]
+            #[case("target", PathStatus::Unmatched)]
+            #[case("target/", PathStatus::Ignored { rule: rule(1, "target/", None) })]
+            fn it_only_matches_directories_with_directory_rules(
+                #[case] path: &str,
+                #[case] expected: PathStatus,
+            ) {
+                let matcher = GitignoreMatcher::parse("target/\n");
+
+                let actual = matcher.explain(path).status;
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod ignored_entries {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_lists_ignored_entries_without_walking_ignored_directories() {
+                let matcher = GitignoreMatcher::parse("build/\n*.log\n*.tmp\npackage.json\n");
+                let working_tree = DefaultTestUtils::get_resource_file_path("working_tree");
+
+                let actual: Vec<(String, bool)> = matcher
+                    .ignored_entries(std::path::Path::new(&working_tree))
+                    .unwrap()
+                    .iter()
+                    .map(|explanation| (explanation.path.clone(), explanation.is_tracked_looking()))
+                    .collect();
+                let expected = vec![
+                    (String::from("build/"), false),
+                    (String::from("debug.log"), false),
+                    (String::from("docs/draft.tmp"), false),
+                    (String::from("package.json"), true),
+                ];
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod path_explanation {
+    use super::*;
+
+    mod to_report {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case("src/main.rs", "src/main.rs: not ignored, no rule matches")]
+            #[case(
+                "debug.log",
+                "debug.log: ignored by '*.log' at line 2 of template Logs"
+            )]
+            #[case(
+                "keep.log",
+                "keep.log: not ignored, re-included by '!keep.log' at line 3 of template Logs"
+            )]
+            #[case(
+                "build/out.txt",
+                "build/out.txt: ignored since its parent directory build is ignored by 'build/' at line 4 of template Logs"
+            )]
+            fn it_reports_path_status_with_deciding_rule(
+                #[case] path: &str,
+                #[case] expected: &str,
+            ) {
+                let matcher = GitignoreMatcher::parse("### Logs ###\n*.log\n!keep.log\nbuild/\n");
+
+                let actual = matcher.explain(path).to_report();
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
@@ -6,17 +6,119 @@ mod impls;
 #[cfg(test)]
 mod tests;
 
-use std::ffi::OsString;
+use std::{ffi::OsString, time::Duration};
 
 pub use crate::parser::impls::ClapArgsParser;
-use crate::{core::ProgramExit, helper::TimeoutUnit};
+use crate::{
+    core::{SourcePrecedence, TemplateOrder},
+    dialect::ApiDialectKind,
+    error::Error,
+    layout::TrailingNewline,
+    printer::ColorChoice,
+};
 
 pub enum Action {
+    ManageTemplate,
+    Lint,
+    Explain,
     List,
+    Search,
+    Show,
+    Diff,
+    Verify,
+    Preview,
     RobustGenerate,
     Generate,
 }
 
+/// Enum for subcommands managing local templates.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplateCommand {
+    /// Adds a new local template with content read from given source.
+    Add {
+        name: String,
+        source: TemplateSource,
+    },
+
+    /// Imports remote templates as local ones, overwriting existing local
+    /// templates if forced.
+    Import { names: Vec<String>, force: bool },
+
+    /// Edits an existing local template in `$EDITOR`.
+    Edit { name: String },
+
+    /// Removes an existing local template.
+    Remove { name: String },
+
+    /// Renames an existing local template.
+    Rename { name: String, new_name: String },
+}
+
+/// DTO struct representing the targets of the lint subcommand.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct LintTargets {
+    /// The gitignore files to be linted.
+    pub paths: Vec<String>,
+
+    /// The local templates to be linted. All of them are linted if neither
+    /// paths nor template names are given.
+    pub template_names: Vec<String>,
+}
+
+/// DTO struct representing the targets of the explain subcommand.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ExplainTargets {
+    /// The paths to be explained, relative to the gitignore file location.
+    pub paths: Vec<String>,
+
+    /// The gitignore file to match paths against, falling back to
+    /// [`crate::constant::path::GITIGNORE_FILE`] if neither file nor
+    /// template names are given.
+    pub file_path: Option<String>,
+
+    /// The templates to be generated and matched paths against, instead of
+    /// a gitignore file.
+    pub template_names: Vec<String>,
+}
+
+/// DTO struct representing the targets of the search subcommand.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct SearchTargets {
+    /// The query to search available templates with.
+    pub query: String,
+
+    /// Whether to handle the query as a regular expression.
+    pub regex: bool,
+
+    /// Whether to also search inside the bodies of local templates.
+    pub content: bool,
+}
+
+/// DTO struct representing the targets of the verify subcommand.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct VerifyTargets {
+    /// The templates expected in the gitignore file, each one generated on
+    /// its own to serve as reference.
+    pub template_names: Vec<String>,
+
+    /// The gitignore file to be verified, falling back to
+    /// [`crate::constant::path::GITIGNORE_FILE`] if not given.
+    pub file_path: Option<String>,
+}
+
+/// Enum for sources of the content of added local templates.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplateSource {
+    /// Content written in `$EDITOR`.
+    Editor,
+
+    /// Content read from given file.
+    File(String),
+
+    /// Content read from stdin.
+    Stdin,
+}
+
 /// Struct to gather cli args parsing result.
 ///
 /// Used by [`crate::parser::ArgsParser`] implementations to store
@@ -57,6 +159,15 @@ pub struct Args {
     ///   args.
     pub lister_uri: String,
 
+    /// The dialect spoken by the gitignore template manager service.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::API_DIALECT`] that takes one of
+    ///   `toptal`, `json` or `per-template`, and falling back to
+    ///   [`crate::constant::template_manager::API_DIALECT`] if not provided
+    ///   in cli args.
+    pub api_dialect: ApiDialectKind,
+
     /// The boolean indicator of whether to display help infos or not.
     ///
     /// * Optional value represented by the cli option
@@ -98,21 +209,241 @@ pub struct Args {
     ///   `false` if not provided in cli args.
     pub check_template_names: bool,
 
-    /// The service call timeout.
+    /// The service call total deadline.
     ///
     /// * Optional value represented by the cli option
     ///   [`crate::constant::cli_options::TIMEOUT`], and falling back to
     ///   [`crate::constant::template_manager::TIMEOUT`] if not provided in
     ///   cli args.
-    pub timeout: u64,
+    /// * Takes a duration made of an unsigned integer and an optional unit
+    ///   among `ms`, `s` or `m` (e.g. `1500ms`, `2s`), defaulting to seconds
+    ///   if no unit is given.
+    pub timeout: Duration,
 
-    /// The timeout unit.
+    /// The service call connection timeout.
     ///
     /// * Optional value represented by the cli option
-    ///   [`crate::constant::cli_options::TIMEOUT_UNIT`], and falling back to
-    ///   [`crate::constant::template_manager::TIMEOUT_UNIT`] if not provided in
-    ///   cli args.
-    pub timeout_unit: TimeoutUnit,
+    ///   [`crate::constant::cli_options::CONNECT_TIMEOUT`], and only bounded
+    ///   by [`Args::timeout`] if not provided in cli args.
+    /// * Takes a duration with the same syntax as [`Args::timeout`].
+    pub connect_timeout: Option<Duration>,
+
+    /// The service call response read timeout.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::READ_TIMEOUT`], and only bounded
+    ///   by [`Args::timeout`] if not provided in cli args.
+    /// * Takes a duration with the same syntax as [`Args::timeout`].
+    pub read_timeout: Option<Duration>,
+
+    /// The boolean indicator of whether to cache service responses or not.
+    ///
+    /// Cached responses are revalidated on later calls using conditional
+    /// requests, so that unchanged responses are not downloaded again.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::CACHE`], and falling back to
+    ///   `false` if not provided in cli args.
+    pub cache_responses: bool,
+
+    /// The targets of the search subcommand, if any.
+    ///
+    /// * Optional value represented by the subcommand
+    ///   [`crate::constant::cli_options::SEARCH_COMMAND`], and falling back
+    ///   to `None` if not provided in cli args.
+    pub search_targets: Option<SearchTargets>,
+
+    /// The name of the template to be shown along with its sources.
+    ///
+    /// * Optional value represented by the subcommand
+    ///   [`crate::constant::cli_options::SHOW_COMMAND`], and falling back to
+    ///   `None` if not provided in cli args.
+    pub show_template_name: Option<String>,
+
+    /// The path of the gitignore file to compare with the generated
+    /// templates.
+    ///
+    /// When given, the generated templates are not printed but compared
+    /// with the content of this file, and a unified diff is printed if
+    /// they differ.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::DIFF`], and falling back to
+    ///   `None` if not provided in cli args.
+    pub diff_path: Option<String>,
+
+    /// The path of the lockfile recording the generated templates.
+    ///
+    /// When given, the source, location and content hash of each generated
+    /// template are written in this file.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::LOCKFILE`], and falling back to
+    ///   `None` if not provided in cli args.
+    pub lockfile_path: Option<String>,
+
+    /// The boolean indicator of whether to check generated templates
+    /// against the lockfile or not.
+    ///
+    /// When set, the lockfile given by [`Args::lockfile_path`] is not
+    /// written, but the generation fails if any generated template differs
+    /// from its locked version.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::LOCKED`], and falling back to
+    ///   `false` if not provided in cli args.
+    /// * Requires [`Args::lockfile_path`] to be given.
+    pub locked: bool,
+
+    /// The format of the header starting each section of generated templates
+    /// (i.e. the templates served by a same source).
+    ///
+    /// Supports `{source}` (i.e. `LOCAL` or `REMOTE`) and `{date}` (i.e. the
+    /// current UTC date) placeholders.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::SECTION_HEADER`], and falling back to
+    ///   [`crate::constant::template_manager::SECTION_HEADER`] if not provided
+    ///   in cli args.
+    pub section_header: String,
+
+    /// The format of the title starting each generated local template.
+    ///
+    /// Supports `{name}` (i.e. the capitalized template name), `{source}`
+    /// (i.e. `LOCAL`) and `{date}` placeholders.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::TEMPLATE_TITLE`], and falling back to
+    ///   [`crate::constant::template_manager::TEMPLATE_TITLE`] if not provided
+    ///   in cli args.
+    pub template_title: String,
+
+    /// The boolean indicator of whether to omit section headers and local
+    /// template titles or not.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::NO_HEADERS`], and falling back to
+    ///   `false` if not provided in cli args.
+    pub no_headers: bool,
+
+    /// The boolean indicator of whether to start generated templates with a
+    /// banner telling the script version and command line or not.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::BANNER`], and falling back to
+    ///   `false` if not provided in cli args.
+    pub banner: bool,
+
+    /// The policy for the newline ending generated templates.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::TRAILING_NEWLINE`], and falling back
+    ///   to [`TrailingNewline::Always`] if not provided in cli args.
+    pub trailing_newline: TrailingNewline,
+
+    /// The policy for templates served by both local and remote sources.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::PREFER`], and falling back to
+    ///   [`SourcePrecedence::Both`] if not provided in cli args.
+    pub prefer: SourcePrecedence,
+
+    /// The policy for the order of generated templates.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::ORDER`], and falling back to
+    ///   [`TemplateOrder::Source`] if not provided in cli args.
+    pub order: TemplateOrder,
+
+    /// The boolean indicator of whether template names must match exactly,
+    /// case included, or not.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::STRICT`], and falling back to
+    ///   `false` if not provided in cli args.
+    pub strict: bool,
+
+    /// The file extensions of local templates.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::TEMPLATE_EXTENSION`], and falling back
+    ///   to `txt` if not provided in cli args.
+    pub template_extensions: Vec<String>,
+
+    /// The gitignore patterns of local templates to be excluded (e.g.
+    /// `drafts/` or `*-old`).
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::EXCLUDE`], and falling back to an
+    ///   empty list if not provided in cli args.
+    pub excluded_templates: Vec<String>,
+
+    /// The subcommand managing local templates, if any.
+    ///
+    /// * Optional value represented by the subcommand
+    ///   [`crate::constant::cli_options::TEMPLATE_COMMAND`], and falling
+    ///   back to `None` if not provided in cli args.
+    pub template_command: Option<TemplateCommand>,
+
+    /// The targets of the lint subcommand, if any.
+    ///
+    /// * Optional value represented by the subcommand
+    ///   [`crate::constant::cli_options::LINT_COMMAND`], and falling back to
+    ///   `None` if not provided in cli args.
+    pub lint_targets: Option<LintTargets>,
+
+    /// The targets of the explain subcommand, if any.
+    ///
+    /// * Optional value represented by the subcommand
+    ///   [`crate::constant::cli_options::EXPLAIN_COMMAND`], and falling back
+    ///   to `None` if not provided in cli args.
+    pub explain_targets: Option<ExplainTargets>,
+
+    /// The targets of the verify subcommand, if any.
+    ///
+    /// * Optional value represented by the subcommand
+    ///   [`crate::constant::cli_options::VERIFY_COMMAND`], and falling back
+    ///   to `None` if not provided in cli args.
+    pub verify_targets: Option<VerifyTargets>,
+
+    /// The boolean indicator of whether to list the files of the current
+    /// directory the generated templates would ignore, instead of printing them.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::PREVIEW`], and falling back to
+    ///   `false` if not provided in cli args.
+    pub preview: bool,
+
+    /// The number of times the verbose option was given, raising the
+    /// verbosity of logs written to stderr (see [`crate::logger::Verbosity`]).
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::VERBOSE`], and falling back to `0`
+    ///   if not provided in cli args.
+    pub verbose: u8,
+
+    /// The boolean indicator of whether to only report failures through
+    /// the exit status, without printing error messages.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::QUIET`], and falling back to `false`
+    ///   if not provided in cli args.
+    pub quiet: bool,
+
+    /// The policy for styling output with colors.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::COLOR`], and falling back to
+    ///   [`ColorChoice::Auto`] if not provided in cli args.
+    pub color: ColorChoice,
+
+    /// The boolean indicator of whether to print output directly, instead
+    /// of through the pager when it exceeds the terminal height.
+    ///
+    /// * Optional value represented by the cli option
+    ///   [`crate::constant::cli_options::NO_PAGER`], and falling back to
+    ///   `false` if not provided in cli args.
+    pub no_pager: bool,
 }
 
 /// Cli args parser trait to parse CLI args and return them in an [`Args`].
@@ -153,7 +484,7 @@ pub trait ArgsParser {
     /// # Returns
     ///
     /// A result containing an owned instance of [`Args`] if successful parsing,
-    /// or a [`ProgramExit`] if any error or early exit occurred (e.g. version/
+    /// or an [`Error`] if any error or early exit occurred (e.g. version/
     /// author/help infos printing, invalid cli args...)
-    fn try_parse(&self, args: impl IntoIterator<Item = OsString>) -> Result<Args, ProgramExit>;
+    fn try_parse(&self, args: impl IntoIterator<Item = OsString>) -> Result<Args, Error>;
 }
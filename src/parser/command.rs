@@ -1,48 +1,163 @@
 //! Define components to build cli args
 
-use clap::{Arg, ArgMatches};
+use clap::{Arg, ArgGroup, ArgMatches, Command};
 
+mod api_dialect;
 mod author;
+mod banner;
+mod cache;
 mod check;
+mod color;
+mod connect_timeout;
+mod diff;
+mod exclude;
+mod explain;
 mod generator_uri;
 mod help;
+mod lint;
 mod list;
 mod lister_uri;
+mod locked;
+mod lockfile;
+mod no_headers;
+mod no_pager;
+mod order;
+mod prefer;
+mod preview;
+mod quiet;
+mod read_timeout;
+mod search;
+mod section_header;
 mod server_url;
+mod show;
+mod strict;
+mod template;
+mod template_extension;
 mod template_names;
+mod template_title;
 mod timeout;
-mod timeout_unit;
+mod trailing_newline;
+mod verbose;
+mod verify;
 mod version;
 
+pub use api_dialect::ApiDialectClapArg;
 pub use author::AuthorClapArg;
+pub use banner::BannerClapArg;
+pub use cache::CacheClapArg;
 pub use check::CheckClapArg;
+pub use color::ColorClapArg;
+pub use connect_timeout::ConnectTimeoutClapArg;
+pub use diff::DiffClapArg;
+pub use exclude::ExcludeClapArg;
+pub use explain::ExplainClapCommand;
 pub use generator_uri::GeneratorUriClapArg;
 pub use help::HelpClapArg;
+pub use lint::LintClapCommand;
 pub use list::ListClapArg;
 pub use lister_uri::ListerUriClapArg;
+pub use locked::LockedClapArg;
+pub use lockfile::LockfileClapArg;
+pub use no_headers::NoHeadersClapArg;
+pub use no_pager::NoPagerClapArg;
+pub use order::OrderClapArg;
+pub use prefer::PreferClapArg;
+pub use preview::PreviewClapArg;
+pub use quiet::QuietClapArg;
+pub use read_timeout::ReadTimeoutClapArg;
+pub use search::SearchClapCommand;
+pub use section_header::SectionHeaderClapArg;
 pub use server_url::ServerUrlClapArg;
+pub use show::ShowClapCommand;
+pub use strict::StrictClapArg;
+pub use template::TemplateClapCommand;
+pub use template_extension::TemplateExtensionClapArg;
 pub use template_names::TemplateNamesClapArg;
+pub use template_title::TemplateTitleClapArg;
 pub use timeout::TimeoutClapArg;
-pub use timeout_unit::TimeoutUnitClapArg;
+pub use trailing_newline::TrailingNewlineClapArg;
+pub use verbose::VerboseClapArg;
+pub use verify::VerifyClapCommand;
 pub use version::VersionClapArg;
 
+/// Id of the group of options standing for an action other than generating
+/// templates.
+pub const ACTION_GROUP: &str = "ACTION";
+
 pub trait ClapArg<T> {
     fn build() -> Arg;
     fn from_arg_matches(arg_matches: &ArgMatches) -> T;
 }
 
-pub fn build_clap_args() -> [Arg; 11] {
+pub trait ClapCommand<T> {
+    fn build() -> Command;
+    fn from_arg_matches(arg_matches: &ArgMatches) -> T;
+}
+
+/// Builds the root cli args, leaving out the ones only relevant to local
+/// templates unless the `local_templating` feature is enabled.
+pub fn build_clap_args() -> Vec<Arg> {
+    let local_templating = cfg!(feature = "local_templating");
+
+    [
+        Some(CheckClapArg::build()),
+        Some(GeneratorUriClapArg::build()),
+        Some(ListClapArg::build()),
+        Some(ListerUriClapArg::build()),
+        Some(ApiDialectClapArg::build()),
+        Some(ServerUrlClapArg::build()),
+        Some(TemplateNamesClapArg::build()),
+        Some(TimeoutClapArg::build()),
+        Some(ConnectTimeoutClapArg::build()),
+        Some(ReadTimeoutClapArg::build()),
+        Some(CacheClapArg::build()),
+        Some(DiffClapArg::build()),
+        Some(LockfileClapArg::build()),
+        Some(LockedClapArg::build()),
+        Some(SectionHeaderClapArg::build()),
+        local_templating.then(TemplateTitleClapArg::build),
+        Some(NoHeadersClapArg::build()),
+        Some(BannerClapArg::build()),
+        Some(TrailingNewlineClapArg::build()),
+        local_templating.then(PreferClapArg::build),
+        Some(OrderClapArg::build()),
+        Some(StrictClapArg::build()),
+        local_templating.then(TemplateExtensionClapArg::build),
+        local_templating.then(ExcludeClapArg::build),
+        Some(PreviewClapArg::build()),
+        Some(VerboseClapArg::build()),
+        Some(QuietClapArg::build()),
+        Some(ColorClapArg::build()),
+        Some(NoPagerClapArg::build()),
+        Some(HelpClapArg::build()),
+        Some(VersionClapArg::build()),
+        Some(AuthorClapArg::build()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Builds the subcommands, leaving out the ones only relevant to local
+/// templates unless the `local_templating` feature is enabled.
+pub fn build_clap_commands() -> Vec<Command> {
+    let local_templating = cfg!(feature = "local_templating");
+
     [
-        CheckClapArg::build(),
-        GeneratorUriClapArg::build(),
-        ListClapArg::build(),
-        ListerUriClapArg::build(),
-        ServerUrlClapArg::build(),
-        TemplateNamesClapArg::build(),
-        TimeoutClapArg::build(),
-        TimeoutUnitClapArg::build(),
-        HelpClapArg::build(),
-        VersionClapArg::build(),
-        AuthorClapArg::build(),
+        local_templating.then(TemplateClapCommand::build),
+        Some(LintClapCommand::build()),
+        Some(ExplainClapCommand::build()),
+        Some(SearchClapCommand::build()),
+        Some(ShowClapCommand::build()),
+        Some(VerifyClapCommand::build()),
     ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Builds the root arg groups, i.e. the group of options standing for an
+/// action other than generating templates, only one of which can be given.
+pub fn build_clap_groups() -> Vec<ArgGroup> {
+    vec![ArgGroup::new(ACTION_GROUP).args(["LIST", "DIFF", "PREVIEW"])]
 }
@@ -0,0 +1,24 @@
+use clap::{Arg, ArgMatches, value_parser};
+
+use super::ClapArg;
+use crate::{constant, dialect::ApiDialectKind};
+
+pub struct ApiDialectClapArg;
+
+impl ClapArg<ApiDialectKind> for ApiDialectClapArg {
+    fn build() -> Arg {
+        Arg::new("api_dialect")
+            .id("API_DIALECT")
+            .long(constant::cli_options::API_DIALECT.long)
+            .value_name("DIALECT")
+            .help(constant::help_messages::API_DIALECT)
+            .value_parser(value_parser!(ApiDialectKind))
+            .default_value(constant::template_manager::API_DIALECT)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> ApiDialectKind {
+        *arg_matches
+            .get_one::<ApiDialectKind>("API_DIALECT")
+            .unwrap()
+    }
+}
@@ -3,20 +3,25 @@ use clap::{Arg, ArgAction, ArgMatches, Command};
 use super::ClapArg;
 use crate::{
     constant::{self, error_messages},
-    core::{ExitKind, ProgramExit},
+    core::ExitKind,
+    error::Error,
     helper::{DefaultUtils, Utils},
 };
 
 pub struct AuthorClapArg;
 
 impl AuthorClapArg {
-    pub fn as_program_exit(cli_parser: &Command) -> ProgramExit {
+    pub fn as_early_exit(cli_parser: &Command) -> Error {
         let message = match cli_parser.get_author() {
             Some(author) => author,
             None => error_messages::AUTHOR_INFOS_NOT_AVAILABLE,
         };
 
-        ProgramExit::success(message, &ExitKind::AuthorInfos)
+        Error::EarlyExit {
+            message: message.to_string(),
+            styled_message: None,
+            kind: ExitKind::AuthorInfos,
+        }
     }
 }
 
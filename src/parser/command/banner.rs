@@ -0,0 +1,20 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct BannerClapArg;
+
+impl ClapArg<bool> for BannerClapArg {
+    fn build() -> Arg {
+        Arg::new("banner")
+            .id("BANNER")
+            .long(constant::cli_options::BANNER.long)
+            .help(constant::help_messages::BANNER)
+            .action(ArgAction::SetTrue)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> bool {
+        arg_matches.get_flag("BANNER")
+    }
+}
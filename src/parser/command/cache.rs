@@ -0,0 +1,20 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct CacheClapArg;
+
+impl ClapArg<bool> for CacheClapArg {
+    fn build() -> Arg {
+        Arg::new("cache")
+            .id("CACHE")
+            .long(constant::cli_options::CACHE.long)
+            .help(constant::help_messages::CACHE)
+            .action(ArgAction::SetTrue)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> bool {
+        arg_matches.get_flag("CACHE")
+    }
+}
@@ -0,0 +1,23 @@
+use clap::{Arg, ArgMatches, value_parser};
+
+use super::ClapArg;
+use crate::{constant, printer::ColorChoice};
+
+pub struct ColorClapArg;
+
+impl ClapArg<ColorChoice> for ColorClapArg {
+    fn build() -> Arg {
+        Arg::new("color")
+            .id("COLOR")
+            .long(constant::cli_options::COLOR.long)
+            .value_name("WHEN")
+            .help(constant::help_messages::COLOR)
+            .value_parser(value_parser!(ColorChoice))
+            .default_value(constant::template_manager::COLOR)
+            .global(true)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> ColorChoice {
+        *arg_matches.get_one::<ColorChoice>("COLOR").unwrap()
+    }
+}
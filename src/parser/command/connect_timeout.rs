@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use clap::{Arg, ArgMatches};
+
+use super::ClapArg;
+use crate::{
+    constant,
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
+};
+
+pub struct ConnectTimeoutClapArg;
+
+impl ClapArg<Option<Duration>> for ConnectTimeoutClapArg {
+    fn build() -> Arg {
+        Arg::new("connect_timeout")
+            .id("CONNECT_TIMEOUT")
+            .long(constant::cli_options::CONNECT_TIMEOUT.long)
+            .value_name("DURATION")
+            .help(constant::help_messages::CONNECT_TIMEOUT)
+            .value_parser(DefaultCliArgsValidator::is_valid_duration)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<Duration> {
+        arg_matches.get_one::<Duration>("CONNECT_TIMEOUT").copied()
+    }
+}
@@ -0,0 +1,20 @@
+use clap::{Arg, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct DiffClapArg;
+
+impl ClapArg<Option<String>> for DiffClapArg {
+    fn build() -> Arg {
+        Arg::new("diff")
+            .id("DIFF")
+            .long(constant::cli_options::DIFF.long)
+            .value_name("PATH")
+            .help(constant::help_messages::DIFF)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<String> {
+        arg_matches.get_one::<String>("DIFF").cloned()
+    }
+}
@@ -0,0 +1,28 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::{
+    constant,
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
+};
+
+pub struct ExcludeClapArg;
+
+impl ClapArg<Vec<String>> for ExcludeClapArg {
+    fn build() -> Arg {
+        Arg::new("exclude")
+            .id("EXCLUDE")
+            .long(constant::cli_options::EXCLUDE.long)
+            .value_name("PATTERN")
+            .help(constant::help_messages::EXCLUDE)
+            .value_parser(DefaultCliArgsValidator::is_valid_pattern)
+            .action(ArgAction::Append)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Vec<String> {
+        arg_matches
+            .get_many::<String>("EXCLUDE")
+            .map(|vals| vals.cloned().collect())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,67 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::ClapCommand;
+use crate::{
+    constant::{cli_options, help_messages},
+    helper::{DefaultUtils, Utils},
+    parser::ExplainTargets,
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
+};
+
+pub struct ExplainClapCommand;
+
+impl ClapCommand<Option<ExplainTargets>> for ExplainClapCommand {
+    fn build() -> Command {
+        Command::new(cli_options::EXPLAIN_COMMAND)
+            .about(help_messages::EXPLAIN_COMMAND)
+            .arg(
+                Arg::new("paths")
+                    .id("PATHS")
+                    .help(help_messages::EXPLAINED_PATHS)
+                    .required(true)
+                    .num_args(1..),
+            )
+            .arg(
+                Arg::new("file")
+                    .id("FILE")
+                    .long(cli_options::EXPLAINED_FILE.long)
+                    .value_name("PATH")
+                    .help(help_messages::EXPLAINED_FILE)
+                    .conflicts_with("TEMPLATE"),
+            )
+            .arg(
+                Arg::new("template")
+                    .id("TEMPLATE")
+                    .long(cli_options::EXPLAINED_TEMPLATE.long)
+                    .value_name("NAME")
+                    .help(help_messages::EXPLAINED_TEMPLATE)
+                    .value_parser(DefaultCliArgsValidator::is_valid_template_name)
+                    .action(ArgAction::Append),
+            )
+            .arg(
+                Arg::new("help")
+                    .id("HELP")
+                    .short(DefaultUtils::to_char(cli_options::HELP.short))
+                    .long(cli_options::HELP.long)
+                    .help(help_messages::HELP)
+                    .action(ArgAction::Help)
+                    .display_order(usize::MAX),
+            )
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<ExplainTargets> {
+        let arg_matches = arg_matches.subcommand_matches(cli_options::EXPLAIN_COMMAND)?;
+        let get_values = |id: &str| {
+            arg_matches
+                .get_many::<String>(id)
+                .map(|vals| vals.cloned().collect())
+                .unwrap_or_default()
+        };
+
+        Some(ExplainTargets {
+            paths: get_values("PATHS"),
+            file_path: arg_matches.get_one::<String>("FILE").cloned(),
+            template_names: get_values("TEMPLATE"),
+        })
+    }
+}
@@ -3,21 +3,22 @@ use clap::{Arg, ArgAction, ArgMatches, Command};
 use super::ClapArg;
 use crate::{
     constant,
-    core::{ExitKind, ProgramExit},
+    core::ExitKind,
+    error::Error,
     helper::{DefaultUtils, Utils},
 };
 
 pub struct HelpClapArg;
 
 impl HelpClapArg {
-    pub fn as_program_exit(cli_parser: &Command) -> ProgramExit {
+    pub fn as_early_exit(cli_parser: &Command) -> Error {
         let rendered_help = cli_parser.clone().render_help();
 
-        ProgramExit::styled_success(
-            rendered_help.to_string().trim_end(),
-            rendered_help.ansi().to_string().trim_end(),
-            &ExitKind::HelpInfos,
-        )
+        Error::EarlyExit {
+            message: rendered_help.to_string().trim_end().to_string(),
+            styled_message: Some(rendered_help.ansi().to_string().trim_end().to_string()),
+            kind: ExitKind::HelpInfos,
+        }
     }
 }
 
@@ -0,0 +1,57 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::ClapCommand;
+use crate::{
+    constant::{cli_options, help_messages},
+    helper::{DefaultUtils, Utils},
+    parser::LintTargets,
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
+};
+
+pub struct LintClapCommand;
+
+impl ClapCommand<Option<LintTargets>> for LintClapCommand {
+    fn build() -> Command {
+        Command::new(cli_options::LINT_COMMAND)
+            .about(help_messages::LINT_COMMAND)
+            .arg(
+                Arg::new("paths")
+                    .id("PATHS")
+                    .help(help_messages::LINTED_PATHS)
+                    .num_args(1..),
+            )
+            .arg(
+                Arg::new("template")
+                    .id("TEMPLATE")
+                    .long(cli_options::LINTED_TEMPLATE.long)
+                    .value_name("NAME")
+                    .help(help_messages::LINTED_TEMPLATE)
+                    .value_parser(DefaultCliArgsValidator::is_valid_template_name)
+                    .action(ArgAction::Append),
+            )
+            .arg(
+                Arg::new("help")
+                    .id("HELP")
+                    .short(DefaultUtils::to_char(cli_options::HELP.short))
+                    .long(cli_options::HELP.long)
+                    .help(help_messages::HELP)
+                    .action(ArgAction::Help)
+                    .display_order(usize::MAX),
+            )
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<LintTargets> {
+        let arg_matches = arg_matches.subcommand_matches(cli_options::LINT_COMMAND)?;
+        let get_values = |id: &str| {
+            arg_matches
+                .get_many::<String>(id)
+                .map(|vals| vals.cloned().collect())
+                .unwrap_or_default()
+        };
+
+        Some(LintTargets {
+            paths: get_values("PATHS"),
+            template_names: get_values("TEMPLATE"),
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct LockedClapArg;
+
+impl ClapArg<bool> for LockedClapArg {
+    fn build() -> Arg {
+        Arg::new("locked")
+            .id("LOCKED")
+            .long(constant::cli_options::LOCKED.long)
+            .help(constant::help_messages::LOCKED)
+            .action(ArgAction::SetTrue)
+            .requires("LOCKFILE")
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> bool {
+        arg_matches.get_flag("LOCKED")
+    }
+}
@@ -0,0 +1,21 @@
+use clap::{Arg, ArgMatches};
+
+use super::{ACTION_GROUP, ClapArg};
+use crate::constant;
+
+pub struct LockfileClapArg;
+
+impl ClapArg<Option<String>> for LockfileClapArg {
+    fn build() -> Arg {
+        Arg::new("lockfile")
+            .id("LOCKFILE")
+            .long(constant::cli_options::LOCKFILE.long)
+            .value_name("PATH")
+            .help(constant::help_messages::LOCKFILE)
+            .conflicts_with(ACTION_GROUP)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<String> {
+        arg_matches.get_one::<String>("LOCKFILE").cloned()
+    }
+}
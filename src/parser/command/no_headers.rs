@@ -0,0 +1,20 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct NoHeadersClapArg;
+
+impl ClapArg<bool> for NoHeadersClapArg {
+    fn build() -> Arg {
+        Arg::new("no_headers")
+            .id("NO_HEADERS")
+            .long(constant::cli_options::NO_HEADERS.long)
+            .help(constant::help_messages::NO_HEADERS)
+            .action(ArgAction::SetTrue)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> bool {
+        arg_matches.get_flag("NO_HEADERS")
+    }
+}
@@ -0,0 +1,21 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct NoPagerClapArg;
+
+impl ClapArg<bool> for NoPagerClapArg {
+    fn build() -> Arg {
+        Arg::new("no_pager")
+            .id("NO_PAGER")
+            .long(constant::cli_options::NO_PAGER.long)
+            .help(constant::help_messages::NO_PAGER)
+            .action(ArgAction::SetTrue)
+            .global(true)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> bool {
+        arg_matches.get_flag("NO_PAGER")
+    }
+}
@@ -0,0 +1,22 @@
+use clap::{Arg, ArgMatches, value_parser};
+
+use super::ClapArg;
+use crate::{constant, core::TemplateOrder};
+
+pub struct OrderClapArg;
+
+impl ClapArg<TemplateOrder> for OrderClapArg {
+    fn build() -> Arg {
+        Arg::new("order")
+            .id("ORDER")
+            .long(constant::cli_options::ORDER.long)
+            .value_name("ORDER")
+            .help(constant::help_messages::ORDER)
+            .value_parser(value_parser!(TemplateOrder))
+            .default_value(constant::template_manager::ORDER)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> TemplateOrder {
+        *arg_matches.get_one::<TemplateOrder>("ORDER").unwrap()
+    }
+}
@@ -0,0 +1,22 @@
+use clap::{Arg, ArgMatches, value_parser};
+
+use super::ClapArg;
+use crate::{constant, core::SourcePrecedence};
+
+pub struct PreferClapArg;
+
+impl ClapArg<SourcePrecedence> for PreferClapArg {
+    fn build() -> Arg {
+        Arg::new("prefer")
+            .id("PREFER")
+            .long(constant::cli_options::PREFER.long)
+            .value_name("SOURCE")
+            .help(constant::help_messages::PREFER)
+            .value_parser(value_parser!(SourcePrecedence))
+            .default_value(constant::template_manager::PREFER)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> SourcePrecedence {
+        *arg_matches.get_one::<SourcePrecedence>("PREFER").unwrap()
+    }
+}
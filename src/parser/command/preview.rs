@@ -0,0 +1,20 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct PreviewClapArg;
+
+impl ClapArg<bool> for PreviewClapArg {
+    fn build() -> Arg {
+        Arg::new("preview")
+            .id("PREVIEW")
+            .long(constant::cli_options::PREVIEW.long)
+            .help(constant::help_messages::PREVIEW)
+            .action(ArgAction::SetTrue)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> bool {
+        arg_matches.get_flag("PREVIEW")
+    }
+}
@@ -0,0 +1,26 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::{
+    constant,
+    helper::{DefaultUtils, Utils},
+};
+
+pub struct QuietClapArg;
+
+impl ClapArg<bool> for QuietClapArg {
+    fn build() -> Arg {
+        Arg::new("quiet")
+            .id("QUIET")
+            .short(DefaultUtils::to_char(constant::cli_options::QUIET.short))
+            .long(constant::cli_options::QUIET.long)
+            .help(constant::help_messages::QUIET)
+            .action(ArgAction::SetTrue)
+            .conflicts_with("VERBOSE")
+            .global(true)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> bool {
+        arg_matches.get_flag("QUIET")
+    }
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use clap::{Arg, ArgMatches};
+
+use super::ClapArg;
+use crate::{
+    constant,
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
+};
+
+pub struct ReadTimeoutClapArg;
+
+impl ClapArg<Option<Duration>> for ReadTimeoutClapArg {
+    fn build() -> Arg {
+        Arg::new("read_timeout")
+            .id("READ_TIMEOUT")
+            .long(constant::cli_options::READ_TIMEOUT.long)
+            .value_name("DURATION")
+            .help(constant::help_messages::READ_TIMEOUT)
+            .value_parser(DefaultCliArgsValidator::is_valid_duration)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<Duration> {
+        arg_matches.get_one::<Duration>("READ_TIMEOUT").copied()
+    }
+}
@@ -0,0 +1,64 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::ClapCommand;
+use crate::{
+    constant::{cli_options, help_messages},
+    helper::{DefaultUtils, Utils},
+    parser::SearchTargets,
+};
+
+pub struct SearchClapCommand;
+
+impl ClapCommand<Option<SearchTargets>> for SearchClapCommand {
+    /// Builds the search subcommand, leaving out the content option unless
+    /// the `local_templating` feature is enabled, as only local template
+    /// bodies get searched.
+    fn build() -> Command {
+        let local_templating = cfg!(feature = "local_templating");
+
+        Command::new(cli_options::SEARCH_COMMAND)
+            .about(help_messages::SEARCH_COMMAND)
+            .arg(
+                Arg::new("query")
+                    .id("QUERY")
+                    .help(help_messages::SEARCH_QUERY)
+                    .required(true),
+            )
+            .arg(
+                Arg::new("regex")
+                    .id("REGEX")
+                    .long(cli_options::REGEX.long)
+                    .help(help_messages::REGEX)
+                    .action(ArgAction::SetTrue),
+            )
+            .args(local_templating.then(|| {
+                Arg::new("content")
+                    .id("CONTENT")
+                    .long(cli_options::CONTENT.long)
+                    .help(help_messages::CONTENT)
+                    .action(ArgAction::SetTrue)
+            }))
+            .arg(
+                Arg::new("help")
+                    .id("HELP")
+                    .short(DefaultUtils::to_char(cli_options::HELP.short))
+                    .long(cli_options::HELP.long)
+                    .help(help_messages::HELP)
+                    .action(ArgAction::Help)
+                    .display_order(usize::MAX),
+            )
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<SearchTargets> {
+        let arg_matches = arg_matches.subcommand_matches(cli_options::SEARCH_COMMAND)?;
+
+        Some(SearchTargets {
+            query: arg_matches
+                .get_one::<String>("QUERY")
+                .cloned()
+                .unwrap_or_default(),
+            regex: arg_matches.get_flag("REGEX"),
+            content: cfg!(feature = "local_templating") && arg_matches.get_flag("CONTENT"),
+        })
+    }
+}
@@ -0,0 +1,24 @@
+use clap::{Arg, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct SectionHeaderClapArg;
+
+impl ClapArg<String> for SectionHeaderClapArg {
+    fn build() -> Arg {
+        Arg::new("section_header")
+            .id("SECTION_HEADER")
+            .long(constant::cli_options::SECTION_HEADER.long)
+            .value_name("FORMAT")
+            .help(constant::help_messages::SECTION_HEADER)
+            .default_value(constant::template_manager::SECTION_HEADER)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> String {
+        arg_matches
+            .get_one::<String>("SECTION_HEADER")
+            .unwrap()
+            .to_string()
+    }
+}
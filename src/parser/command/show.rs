@@ -0,0 +1,40 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::ClapCommand;
+use crate::{
+    constant::{cli_options, help_messages},
+    helper::{DefaultUtils, Utils},
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
+};
+
+pub struct ShowClapCommand;
+
+impl ClapCommand<Option<String>> for ShowClapCommand {
+    fn build() -> Command {
+        Command::new(cli_options::SHOW_COMMAND)
+            .about(help_messages::SHOW_COMMAND)
+            .arg(
+                Arg::new("name")
+                    .id("NAME")
+                    .help(help_messages::SHOWN_TEMPLATE_NAME)
+                    .required(true)
+                    .value_parser(DefaultCliArgsValidator::is_valid_template_name),
+            )
+            .arg(
+                Arg::new("help")
+                    .id("HELP")
+                    .short(DefaultUtils::to_char(cli_options::HELP.short))
+                    .long(cli_options::HELP.long)
+                    .help(help_messages::HELP)
+                    .action(ArgAction::Help)
+                    .display_order(usize::MAX),
+            )
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<String> {
+        arg_matches
+            .subcommand_matches(cli_options::SHOW_COMMAND)?
+            .get_one::<String>("NAME")
+            .cloned()
+    }
+}
@@ -0,0 +1,20 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct StrictClapArg;
+
+impl ClapArg<bool> for StrictClapArg {
+    fn build() -> Arg {
+        Arg::new("strict")
+            .id("STRICT")
+            .long(constant::cli_options::STRICT.long)
+            .help(constant::help_messages::STRICT)
+            .action(ArgAction::SetTrue)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> bool {
+        arg_matches.get_flag("STRICT")
+    }
+}
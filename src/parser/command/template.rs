@@ -0,0 +1,139 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::ClapCommand;
+use crate::{
+    constant::{cli_options, help_messages},
+    helper::{DefaultUtils, Utils},
+    parser::{TemplateCommand, TemplateSource},
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
+};
+
+pub struct TemplateClapCommand;
+
+impl TemplateClapCommand {
+    fn build_name_arg() -> Arg {
+        Arg::new("name")
+            .id("NAME")
+            .help(help_messages::MANAGED_TEMPLATE_NAME)
+            .required(true)
+            .value_parser(DefaultCliArgsValidator::is_valid_template_name)
+    }
+
+    fn build_help_arg() -> Arg {
+        Arg::new("help")
+            .id("HELP")
+            .short(DefaultUtils::to_char(cli_options::HELP.short))
+            .long(cli_options::HELP.long)
+            .help(help_messages::HELP)
+            .action(ArgAction::Help)
+            .display_order(usize::MAX)
+    }
+
+    fn build_subcommand(name: &'static str, about: &'static str) -> Command {
+        Command::new(name).about(about).arg(Self::build_help_arg())
+    }
+
+    fn get_name(arg_matches: &ArgMatches, id: &str) -> String {
+        arg_matches.get_one::<String>(id).unwrap().to_string()
+    }
+}
+
+impl ClapCommand<Option<TemplateCommand>> for TemplateClapCommand {
+    fn build() -> Command {
+        Self::build_subcommand(
+            cli_options::TEMPLATE_COMMAND,
+            help_messages::TEMPLATE_COMMAND,
+        )
+        .subcommand_required(true)
+        .subcommand(
+            Self::build_subcommand(cli_options::ADD_COMMAND, help_messages::ADD_COMMAND)
+                .arg(Self::build_name_arg())
+                .arg(
+                    Arg::new("from_file")
+                        .id("FROM_FILE")
+                        .long(cli_options::FROM_FILE.long)
+                        .value_name("PATH")
+                        .help(help_messages::FROM_FILE)
+                        .conflicts_with("FROM_STDIN"),
+                )
+                .arg(
+                    Arg::new("from_stdin")
+                        .id("FROM_STDIN")
+                        .long(cli_options::FROM_STDIN.long)
+                        .help(help_messages::FROM_STDIN)
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Self::build_subcommand(cli_options::EDIT_COMMAND, help_messages::EDIT_COMMAND)
+                .arg(Self::build_name_arg()),
+        )
+        .subcommand(
+            Self::build_subcommand(cli_options::IMPORT_COMMAND, help_messages::IMPORT_COMMAND)
+                .arg(
+                    Arg::new("names")
+                        .id("NAMES")
+                        .help(help_messages::IMPORTED_TEMPLATE_NAMES)
+                        .required(true)
+                        .value_parser(DefaultCliArgsValidator::is_valid_template_name)
+                        .num_args(1..),
+                )
+                .arg(
+                    Arg::new("force")
+                        .id("FORCE")
+                        .long(cli_options::FORCE.long)
+                        .help(help_messages::FORCE)
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Self::build_subcommand(cli_options::REMOVE_COMMAND, help_messages::REMOVE_COMMAND)
+                .arg(Self::build_name_arg()),
+        )
+        .subcommand(
+            Self::build_subcommand(cli_options::RENAME_COMMAND, help_messages::RENAME_COMMAND)
+                .arg(Self::build_name_arg())
+                .arg(
+                    Arg::new("new_name")
+                        .id("NEW_NAME")
+                        .help(help_messages::NEW_TEMPLATE_NAME)
+                        .required(true)
+                        .value_parser(DefaultCliArgsValidator::is_valid_template_name),
+                ),
+        )
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<TemplateCommand> {
+        let (command_name, arg_matches) = arg_matches
+            .subcommand_matches(cli_options::TEMPLATE_COMMAND)?
+            .subcommand()?;
+        if command_name == cli_options::IMPORT_COMMAND {
+            return Some(TemplateCommand::Import {
+                names: arg_matches
+                    .get_many::<String>("NAMES")
+                    .map(|vals| vals.cloned().collect())
+                    .unwrap_or_default(),
+                force: arg_matches.get_flag("FORCE"),
+            });
+        }
+        let name = Self::get_name(arg_matches, "NAME");
+
+        match command_name {
+            cli_options::ADD_COMMAND => Some(TemplateCommand::Add {
+                name,
+                source: match arg_matches.get_one::<String>("FROM_FILE") {
+                    Some(path) => TemplateSource::File(path.to_string()),
+                    None if arg_matches.get_flag("FROM_STDIN") => TemplateSource::Stdin,
+                    None => TemplateSource::Editor,
+                },
+            }),
+            cli_options::EDIT_COMMAND => Some(TemplateCommand::Edit { name }),
+            cli_options::REMOVE_COMMAND => Some(TemplateCommand::Remove { name }),
+            cli_options::RENAME_COMMAND => Some(TemplateCommand::Rename {
+                name,
+                new_name: Self::get_name(arg_matches, "NEW_NAME"),
+            }),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct TemplateExtensionClapArg;
+
+impl ClapArg<Vec<String>> for TemplateExtensionClapArg {
+    fn build() -> Arg {
+        Arg::new("template_extension")
+            .id("TEMPLATE_EXTENSION")
+            .long(constant::cli_options::TEMPLATE_EXTENSION.long)
+            .value_name("EXTENSION")
+            .help(constant::help_messages::TEMPLATE_EXTENSION)
+            .action(ArgAction::Append)
+            .default_value(constant::template_manager::TEMPLATE_EXTENSION)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Vec<String> {
+        arg_matches
+            .get_many::<String>("TEMPLATE_EXTENSION")
+            .map(|vals| vals.cloned().collect())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,24 @@
+use clap::{Arg, ArgMatches};
+
+use super::ClapArg;
+use crate::constant;
+
+pub struct TemplateTitleClapArg;
+
+impl ClapArg<String> for TemplateTitleClapArg {
+    fn build() -> Arg {
+        Arg::new("template_title")
+            .id("TEMPLATE_TITLE")
+            .long(constant::cli_options::TEMPLATE_TITLE.long)
+            .value_name("FORMAT")
+            .help(constant::help_messages::TEMPLATE_TITLE)
+            .default_value(constant::template_manager::TEMPLATE_TITLE)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> String {
+        arg_matches
+            .get_one::<String>("TEMPLATE_TITLE")
+            .unwrap()
+            .to_string()
+    }
+}
@@ -1,42 +1,31 @@
-use clap::{
-    Arg, ArgMatches,
-    builder::{ArgPredicate, OsStr},
-};
+use std::time::Duration;
+
+use clap::{Arg, ArgMatches};
 
 use super::ClapArg;
 use crate::{
     constant,
     helper::{DefaultUtils, Utils},
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
 };
 
 pub struct TimeoutClapArg;
 
-impl ClapArg<u64> for TimeoutClapArg {
+impl ClapArg<Duration> for TimeoutClapArg {
     fn build() -> Arg {
         Arg::new("timeout")
             .id("TIMEOUT")
             .short(DefaultUtils::to_char(constant::cli_options::TIMEOUT.short))
             .long(constant::cli_options::TIMEOUT.long)
-            .help(format!(
-                "{} [default: {}s/{}ms]",
-                constant::help_messages::TIMEOUT,
-                constant::template_manager::TIMEOUT,
-                constant::template_manager::TIMEOUT_MILLISECOND
-            ))
-            .value_parser(clap::value_parser!(u64))
-            .default_value_if(
-                "TIMEOUT_UNIT",
-                ArgPredicate::Equals(OsStr::from(
-                    constant::template_manager::TIMEOUT_MILLISECOND_UNIT,
-                )),
-                constant::template_manager::TIMEOUT_MILLISECOND,
-            )
+            .help(constant::help_messages::TIMEOUT)
+            .value_parser(DefaultCliArgsValidator::is_valid_duration)
+            .default_value(constant::template_manager::TIMEOUT)
     }
 
-    fn from_arg_matches(arg_matches: &ArgMatches) -> u64 {
-        match arg_matches.get_one::<u64>("TIMEOUT") {
-            Some(timeout) => *timeout,
-            None => constant::template_manager::TIMEOUT_INT,
-        }
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Duration {
+        arg_matches
+            .get_one::<Duration>("TIMEOUT")
+            .unwrap()
+            .to_owned()
     }
 }
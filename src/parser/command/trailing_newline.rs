@@ -0,0 +1,24 @@
+use clap::{Arg, ArgMatches, value_parser};
+
+use super::ClapArg;
+use crate::{constant, layout::TrailingNewline};
+
+pub struct TrailingNewlineClapArg;
+
+impl ClapArg<TrailingNewline> for TrailingNewlineClapArg {
+    fn build() -> Arg {
+        Arg::new("trailing_newline")
+            .id("TRAILING_NEWLINE")
+            .long(constant::cli_options::TRAILING_NEWLINE.long)
+            .value_name("POLICY")
+            .help(constant::help_messages::TRAILING_NEWLINE)
+            .value_parser(value_parser!(TrailingNewline))
+            .default_value(constant::template_manager::TRAILING_NEWLINE)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> TrailingNewline {
+        *arg_matches
+            .get_one::<TrailingNewline>("TRAILING_NEWLINE")
+            .unwrap()
+    }
+}
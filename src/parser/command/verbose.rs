@@ -0,0 +1,25 @@
+use clap::{Arg, ArgAction, ArgMatches};
+
+use super::ClapArg;
+use crate::{
+    constant,
+    helper::{DefaultUtils, Utils},
+};
+
+pub struct VerboseClapArg;
+
+impl ClapArg<u8> for VerboseClapArg {
+    fn build() -> Arg {
+        Arg::new("verbose")
+            .id("VERBOSE")
+            .short(DefaultUtils::to_char(constant::cli_options::VERBOSE.short))
+            .long(constant::cli_options::VERBOSE.long)
+            .help(constant::help_messages::VERBOSE)
+            .action(ArgAction::Count)
+            .global(true)
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> u8 {
+        arg_matches.get_count("VERBOSE")
+    }
+}
@@ -0,0 +1,54 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::ClapCommand;
+use crate::{
+    constant::{cli_options, help_messages},
+    helper::{DefaultUtils, Utils},
+    parser::VerifyTargets,
+    validator::{CliArgsValidator, DefaultCliArgsValidator},
+};
+
+pub struct VerifyClapCommand;
+
+impl ClapCommand<Option<VerifyTargets>> for VerifyClapCommand {
+    fn build() -> Command {
+        Command::new(cli_options::VERIFY_COMMAND)
+            .about(help_messages::VERIFY_COMMAND)
+            .arg(
+                Arg::new("template_names")
+                    .id("TEMPLATE_NAMES")
+                    .help(help_messages::VERIFIED_TEMPLATE_NAMES)
+                    .required(true)
+                    .value_parser(DefaultCliArgsValidator::is_valid_template_name)
+                    .num_args(1..),
+            )
+            .arg(
+                Arg::new("file")
+                    .id("FILE")
+                    .long(cli_options::VERIFIED_FILE.long)
+                    .value_name("PATH")
+                    .help(help_messages::VERIFIED_FILE),
+            )
+            .arg(
+                Arg::new("help")
+                    .id("HELP")
+                    .short(DefaultUtils::to_char(cli_options::HELP.short))
+                    .long(cli_options::HELP.long)
+                    .help(help_messages::HELP)
+                    .action(ArgAction::Help)
+                    .display_order(usize::MAX),
+            )
+    }
+
+    fn from_arg_matches(arg_matches: &ArgMatches) -> Option<VerifyTargets> {
+        let arg_matches = arg_matches.subcommand_matches(cli_options::VERIFY_COMMAND)?;
+
+        Some(VerifyTargets {
+            template_names: arg_matches
+                .get_many::<String>("TEMPLATE_NAMES")
+                .map(|vals| vals.cloned().collect())
+                .unwrap_or_default(),
+            file_path: arg_matches.get_one::<String>("FILE").cloned(),
+        })
+    }
+}
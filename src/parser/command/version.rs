@@ -3,20 +3,25 @@ use clap::{Arg, ArgAction, ArgMatches, Command};
 use super::ClapArg;
 use crate::{
     constant::{self, error_messages},
-    core::{ExitKind, ProgramExit},
+    core::ExitKind,
+    error::Error,
     helper::{DefaultUtils, Utils},
 };
 
 pub struct VersionClapArg;
 
 impl VersionClapArg {
-    pub fn as_program_exit(cli_parser: &Command) -> ProgramExit {
+    pub fn as_early_exit(cli_parser: &Command) -> Error {
         let message = match cli_parser.get_version() {
             Some(version) => format!("{} {version}", env!("CARGO_PKG_NAME")),
             None => error_messages::VERSION_INFOS_NOT_AVAILABLE.to_string(),
         };
 
-        ProgramExit::success(&message, &ExitKind::VersionInfos)
+        Error::EarlyExit {
+            message,
+            styled_message: None,
+            kind: ExitKind::VersionInfos,
+        }
     }
 }
 
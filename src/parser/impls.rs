@@ -1,23 +1,42 @@
-use std::{ffi::OsString, process::exit};
+use std::{ffi::OsString, process::exit, time::Duration};
 
-use clap::{ArgMatches, Command};
+use clap::{
+    ArgMatches, Command, ValueEnum, error::ErrorKind as ClapErrorKind, parser::ValueSource,
+};
 
-use super::{Args, ArgsParser, command::build_clap_args};
+use super::{
+    Args, ArgsParser, ExplainTargets, LintTargets, SearchTargets, TemplateCommand, VerifyTargets,
+    command::{ACTION_GROUP, build_clap_args, build_clap_commands, build_clap_groups},
+};
+#[cfg(feature = "local_templating")]
+use crate::parser::command::{
+    ExcludeClapArg, PreferClapArg, TemplateClapCommand, TemplateExtensionClapArg,
+    TemplateTitleClapArg,
+};
 use crate::{
-    constant::{parser_infos, template_manager},
-    core::{ExitKind, ProgramExit},
-    helper::TimeoutUnit,
+    constant::{cli_options, error_messages, parser_infos, template_manager},
+    core::{SourcePrecedence, TemplateOrder},
+    dialect::ApiDialectKind,
+    error::Error,
+    layout::TrailingNewline,
+    logger::Verbosity,
     parser::{
         Action,
         command::{
-            AuthorClapArg, CheckClapArg, ClapArg, GeneratorUriClapArg, HelpClapArg, ListClapArg,
-            ListerUriClapArg, ServerUrlClapArg, TemplateNamesClapArg, TimeoutClapArg,
-            TimeoutUnitClapArg, VersionClapArg,
+            ApiDialectClapArg, AuthorClapArg, BannerClapArg, CacheClapArg, CheckClapArg, ClapArg,
+            ClapCommand, ColorClapArg, ConnectTimeoutClapArg, DiffClapArg, ExplainClapCommand,
+            GeneratorUriClapArg, HelpClapArg, LintClapCommand, ListClapArg, ListerUriClapArg,
+            LockedClapArg, LockfileClapArg, NoHeadersClapArg, NoPagerClapArg, OrderClapArg,
+            PreviewClapArg, QuietClapArg, ReadTimeoutClapArg, SearchClapCommand,
+            SectionHeaderClapArg, ServerUrlClapArg, ShowClapCommand, StrictClapArg,
+            TemplateNamesClapArg, TimeoutClapArg, TrailingNewlineClapArg, VerboseClapArg,
+            VerifyClapCommand, VersionClapArg,
         },
     },
+    printer::{ColorChoice, DefaultDataPrinter, OutputStream},
 };
 
-type ToProgramExitCallback = fn(&Command) -> ProgramExit;
+type ToEarlyExitCallback = fn(&Command) -> Error;
 
 /// Default implementation of args parser that parses CLI args using
 /// [`clap`].
@@ -38,43 +57,118 @@ impl Args {
             server_url: template_manager::BASE_URL.to_string(),
             generator_uri: template_manager::GENERATOR_URI.to_string(),
             lister_uri: template_manager::LISTER_URI.to_string(),
+            api_dialect: ApiDialectKind::Toptal,
             show_help: false,
             show_version: false,
             show_author: false,
             show_list: false,
             check_template_names: false,
-            timeout: template_manager::TIMEOUT_INT,
-            timeout_unit: template_manager::TIMEOUT_UNIT_ENUM,
+            timeout: Duration::from_secs(template_manager::TIMEOUT_INT),
+            connect_timeout: None,
+            read_timeout: None,
+            cache_responses: false,
+            search_targets: None,
+            show_template_name: None,
+            diff_path: None,
+            lockfile_path: None,
+            locked: false,
+            section_header: template_manager::SECTION_HEADER.to_string(),
+            template_title: template_manager::TEMPLATE_TITLE.to_string(),
+            no_headers: false,
+            banner: false,
+            trailing_newline: TrailingNewline::Always,
+            prefer: SourcePrecedence::Both,
+            order: TemplateOrder::Source,
+            strict: false,
+            template_extensions: vec![template_manager::TEMPLATE_EXTENSION.to_string()],
+            excluded_templates: Vec::new(),
+            template_command: None,
+            lint_targets: None,
+            explain_targets: None,
+            verify_targets: None,
+            preview: false,
+            verbose: 0,
+            quiet: false,
+            color: ColorChoice::Auto,
+            no_pager: false,
         }
     }
 
+    /// Builds args from given clap matches.
+    ///
+    /// Args only relevant to local templates keep their default value
+    /// unless the `local_templating` feature is enabled, as their cli
+    /// options are not built otherwise.
     pub fn from_arg_matches(arg_matches: &ArgMatches) -> Self {
-        Self {
+        let args = Self {
             template_names: TemplateNamesClapArg::from_arg_matches(arg_matches),
             server_url: ServerUrlClapArg::from_arg_matches(arg_matches),
             generator_uri: GeneratorUriClapArg::from_arg_matches(arg_matches),
             lister_uri: ListerUriClapArg::from_arg_matches(arg_matches),
+            api_dialect: ApiDialectClapArg::from_arg_matches(arg_matches),
             timeout: TimeoutClapArg::from_arg_matches(arg_matches),
-            timeout_unit: TimeoutUnitClapArg::from_arg_matches(arg_matches),
+            connect_timeout: ConnectTimeoutClapArg::from_arg_matches(arg_matches),
+            read_timeout: ReadTimeoutClapArg::from_arg_matches(arg_matches),
+            cache_responses: CacheClapArg::from_arg_matches(arg_matches),
+            search_targets: SearchClapCommand::from_arg_matches(arg_matches),
+            show_template_name: ShowClapCommand::from_arg_matches(arg_matches),
+            diff_path: DiffClapArg::from_arg_matches(arg_matches),
+            lockfile_path: LockfileClapArg::from_arg_matches(arg_matches),
+            locked: LockedClapArg::from_arg_matches(arg_matches),
             check_template_names: CheckClapArg::from_arg_matches(arg_matches),
             show_help: HelpClapArg::from_arg_matches(arg_matches),
             show_version: VersionClapArg::from_arg_matches(arg_matches),
             show_author: AuthorClapArg::from_arg_matches(arg_matches),
             show_list: ListClapArg::from_arg_matches(arg_matches),
-        }
+            section_header: SectionHeaderClapArg::from_arg_matches(arg_matches),
+            no_headers: NoHeadersClapArg::from_arg_matches(arg_matches),
+            banner: BannerClapArg::from_arg_matches(arg_matches),
+            trailing_newline: TrailingNewlineClapArg::from_arg_matches(arg_matches),
+            order: OrderClapArg::from_arg_matches(arg_matches),
+            strict: StrictClapArg::from_arg_matches(arg_matches),
+            lint_targets: LintClapCommand::from_arg_matches(arg_matches),
+            explain_targets: ExplainClapCommand::from_arg_matches(arg_matches),
+            verify_targets: VerifyClapCommand::from_arg_matches(arg_matches),
+            preview: PreviewClapArg::from_arg_matches(arg_matches),
+            verbose: VerboseClapArg::from_arg_matches(arg_matches),
+            quiet: QuietClapArg::from_arg_matches(arg_matches),
+            color: ColorClapArg::from_arg_matches(arg_matches),
+            no_pager: NoPagerClapArg::from_arg_matches(arg_matches),
+            ..Self::new()
+        };
+
+        #[cfg(feature = "local_templating")]
+        let args = Self {
+            template_title: TemplateTitleClapArg::from_arg_matches(arg_matches),
+            prefer: PreferClapArg::from_arg_matches(arg_matches),
+            template_extensions: TemplateExtensionClapArg::from_arg_matches(arg_matches),
+            excluded_templates: ExcludeClapArg::from_arg_matches(arg_matches),
+            template_command: TemplateClapCommand::from_arg_matches(arg_matches),
+            ..args
+        };
+
+        args
     }
 
-    pub fn get_global_options(&self) -> [(bool, ToProgramExitCallback); 3] {
+    pub fn get_global_options(&self) -> [(bool, ToEarlyExitCallback); 3] {
         [
-            (self.show_help, HelpClapArg::as_program_exit),
-            (self.show_version, VersionClapArg::as_program_exit),
-            (self.show_author, AuthorClapArg::as_program_exit),
+            (self.show_help, HelpClapArg::as_early_exit),
+            (self.show_version, VersionClapArg::as_early_exit),
+            (self.show_author, AuthorClapArg::as_early_exit),
         ]
     }
 
-    pub fn get_action_options(&self) -> [(bool, Action); 2] {
+    pub fn get_action_options(&self) -> [(bool, Action); 10] {
         [
+            (self.template_command.is_some(), Action::ManageTemplate),
+            (self.lint_targets.is_some(), Action::Lint),
+            (self.explain_targets.is_some(), Action::Explain),
+            (self.verify_targets.is_some(), Action::Verify),
             (self.show_list, Action::List),
+            (self.search_targets.is_some(), Action::Search),
+            (self.show_template_name.is_some(), Action::Show),
+            (self.diff_path.is_some(), Action::Diff),
+            (self.preview, Action::Preview),
             (self.check_template_names, Action::RobustGenerate),
         ]
     }
@@ -86,7 +180,13 @@ impl Args {
             .unwrap_or(Action::Generate)
     }
 
-    pub fn as_program_exit(&self, cli_parser: &Command) -> Option<ProgramExit> {
+    /// Gets the verbosity of logs, as told by the verbose and quiet
+    /// options.
+    pub fn verbosity(&self) -> Verbosity {
+        Verbosity::from_flags(self.verbose, self.quiet)
+    }
+
+    pub fn as_early_exit(&self, cli_parser: &Command) -> Option<Error> {
         self.get_global_options()
             .into_iter()
             .find_map(|(flag, handler)| flag.then(|| handler(cli_parser)))
@@ -159,6 +259,23 @@ impl Args {
         self
     }
 
+    /// Sets new value for `api_dialect` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_dialect` - The new value to be assigned to `api_dialect`
+    ///   field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_api_dialect(mut self, api_dialect: ApiDialectKind) -> Self {
+        self.api_dialect = api_dialect;
+        self
+    }
+
     /// Sets new value for `show_list` field.
     ///
     /// It needs to be called on struct instance and effectively mutates it.
@@ -204,24 +321,455 @@ impl Args {
     /// # Returns
     ///
     /// The mutated borrowed instance.
-    pub fn with_timeout(mut self, timeout: u64) -> Self {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
     }
 
-    /// Sets new value for `timeout_unit` field.
+    /// Sets new value for `connect_timeout` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `connect_timeout` - The new value to be assigned to
+    ///   `connect_timeout` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets new value for `read_timeout` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `read_timeout` - The new value to be assigned to `read_timeout`
+    ///   field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Sets new value for `cache_responses` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_responses` - The new value to be assigned to
+    ///   `cache_responses` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_cache_responses(mut self, cache_responses: bool) -> Self {
+        self.cache_responses = cache_responses;
+        self
+    }
+
+    /// Sets new value for `show_template_name` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `show_template_name` - The new value to be assigned to
+    ///   `show_template_name` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_show_template_name(mut self, show_template_name: &str) -> Self {
+        self.show_template_name = Some(show_template_name.to_string());
+        self
+    }
+
+    /// Sets new value for `diff_path` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `diff_path` - The new value to be assigned to `diff_path` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_diff_path(mut self, diff_path: &str) -> Self {
+        self.diff_path = Some(diff_path.to_string());
+        self
+    }
+
+    /// Sets new value for `lockfile_path` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `lockfile_path` - The new value to be assigned to `lockfile_path`
+    ///   field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_lockfile_path(mut self, lockfile_path: &str) -> Self {
+        self.lockfile_path = Some(lockfile_path.to_string());
+        self
+    }
+
+    /// Sets new value for `locked` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `locked` - The new value to be assigned to `locked` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_locked(mut self, locked: bool) -> Self {
+        self.locked = locked;
+        self
+    }
+
+    /// Sets new value for `section_header` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `section_header` - The new value to be assigned to `section_header`
+    ///   field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_section_header(mut self, section_header: &str) -> Self {
+        self.section_header = section_header.to_string();
+        self
+    }
+
+    /// Sets new value for `template_title` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_title` - The new value to be assigned to `template_title`
+    ///   field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_template_title(mut self, template_title: &str) -> Self {
+        self.template_title = template_title.to_string();
+        self
+    }
+
+    /// Sets new value for `no_headers` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `no_headers` - The new value to be assigned to `no_headers` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_no_headers(mut self, no_headers: bool) -> Self {
+        self.no_headers = no_headers;
+        self
+    }
+
+    /// Sets new value for `banner` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `banner` - The new value to be assigned to `banner` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_banner(mut self, banner: bool) -> Self {
+        self.banner = banner;
+        self
+    }
+
+    /// Sets new value for `trailing_newline` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `trailing_newline` - The new value to be assigned to
+    ///   `trailing_newline` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_trailing_newline(mut self, trailing_newline: TrailingNewline) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Sets new value for `prefer` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefer` - The new value to be assigned to `prefer` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_prefer(mut self, prefer: SourcePrecedence) -> Self {
+        self.prefer = prefer;
+        self
+    }
+
+    /// Sets new value for `order` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The new value to be assigned to `order` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_order(mut self, order: TemplateOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets new value for `strict` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - The new value to be assigned to `strict` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets new value for `template_extensions` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_extensions` - The new value to be assigned to
+    ///   `template_extensions` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_template_extensions(mut self, template_extensions: &[String]) -> Self {
+        self.template_extensions = template_extensions.to_vec();
+        self
+    }
+
+    /// Sets new value for `excluded_templates` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `excluded_templates` - The new value to be assigned to
+    ///   `excluded_templates` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_excluded_templates(mut self, excluded_templates: &[String]) -> Self {
+        self.excluded_templates = excluded_templates.to_vec();
+        self
+    }
+
+    /// Sets new value for `template_command` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_command` - The new value to be assigned to
+    ///   `template_command` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_template_command(mut self, template_command: TemplateCommand) -> Self {
+        self.template_command = Some(template_command);
+        self
+    }
+
+    /// Sets new value for `lint_targets` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `lint_targets` - The new value to be assigned to `lint_targets`
+    ///   field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_lint_targets(mut self, lint_targets: LintTargets) -> Self {
+        self.lint_targets = Some(lint_targets);
+        self
+    }
+
+    /// Sets new value for `explain_targets` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `explain_targets` - The new value to be assigned to
+    ///   `explain_targets` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_explain_targets(mut self, explain_targets: ExplainTargets) -> Self {
+        self.explain_targets = Some(explain_targets);
+        self
+    }
+
+    /// Sets new value for `verify_targets` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `verify_targets` - The new value to be assigned to `verify_targets`
+    ///   field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_verify_targets(mut self, verify_targets: VerifyTargets) -> Self {
+        self.verify_targets = Some(verify_targets);
+        self
+    }
+
+    /// Sets new value for `search_targets` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `search_targets` - The new value to be assigned to `search_targets`
+    ///   field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_search_targets(mut self, search_targets: SearchTargets) -> Self {
+        self.search_targets = Some(search_targets);
+        self
+    }
+
+    /// Sets new value for `preview` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `preview` - The new value to be assigned to `preview` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_preview(mut self, preview: bool) -> Self {
+        self.preview = preview;
+        self
+    }
+
+    /// Sets new value for `verbose` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `verbose` - The new value to be assigned to `verbose` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_verbose(mut self, verbose: u8) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Sets new value for `quiet` field.
     ///
     /// It needs to be called on struct instance and effectively mutates it.
     ///
     /// # Arguments
     ///
-    /// * `timeout_unit` - The new value to be assigned to `timeout_unit` field.
+    /// * `quiet` - The new value to be assigned to `quiet` field.
     ///
     /// # Returns
     ///
     /// The mutated borrowed instance.
-    pub fn with_timeout_unit(mut self, timeout_unit: TimeoutUnit) -> Self {
-        self.timeout_unit = timeout_unit;
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Sets new value for `color` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The new value to be assigned to `color` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets new value for `no_pager` field.
+    ///
+    /// It needs to be called on struct instance and effectively mutates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `no_pager` - The new value to be assigned to `no_pager` field.
+    ///
+    /// # Returns
+    ///
+    /// The mutated borrowed instance.
+    pub fn with_no_pager(mut self, no_pager: bool) -> Self {
+        self.no_pager = no_pager;
         self
     }
 }
@@ -242,21 +790,95 @@ impl ClapArgsParser {
                 .help_template(include_str!("../../assets/help_template.txt"))
                 .disable_help_flag(true)
                 .disable_version_flag(true)
-                .args(build_clap_args()),
+                .args(build_clap_args())
+                .groups(build_clap_groups())
+                .subcommands(build_clap_commands())
+                .subcommand_negates_reqs(true)
+                .disable_help_subcommand(true),
         }
     }
 
-    fn print_message(error: &ProgramExit, message: &str) -> Option<String> {
-        match error.kind {
-            ExitKind::Error => eprintln!("{message}"),
-            _ => println!("{message}"),
+    fn print_message(stream: OutputStream, message: &str) {
+        match stream {
+            OutputStream::Stderr => eprintln!("{message}"),
+            OutputStream::Stdout => println!("{message}"),
         }
-        Some(message.to_string())
     }
 
-    fn process_arg_matches(&self, arg_matches: &ArgMatches) -> Result<Args, ProgramExit> {
+    /// Gets the color choice given in raw cli args, falling back to
+    /// [`ColorChoice::Auto`] if none or an invalid one was given.
+    ///
+    /// It allows styling messages of early exits, which occur before the
+    /// color option could be parsed along with the other cli args.
+    pub(crate) fn find_color_choice(args: &[OsString]) -> ColorChoice {
+        let long_option = format!("--{}", cli_options::COLOR.long);
+        let mut args = args
+            .iter()
+            .skip(1)
+            .map_while(|arg| arg.to_str())
+            .take_while(|arg| *arg != "--");
+        let mut color_choice = ColorChoice::Auto;
+
+        while let Some(arg) = args.next() {
+            let value = if arg == long_option {
+                args.next()
+            } else {
+                arg.strip_prefix(&long_option)
+                    .and_then(|arg| arg.strip_prefix('='))
+            };
+
+            if let Some(value) = value.and_then(|value| ColorChoice::from_str(value, true).ok()) {
+                color_choice = value;
+            }
+        }
+
+        color_choice
+    }
+
+    /// Finds any root option given along with a subcommand while only
+    /// applying to generated templates (i.e. action options and the
+    /// lockfile option).
+    ///
+    /// # Returns
+    ///
+    /// The conflict error, or `None` if there is no subcommand or no such
+    /// option.
+    fn find_subcommand_conflict(&self, arg_matches: &ArgMatches) -> Option<Error> {
+        let subcommand = arg_matches.subcommand_name()?;
+        // Args can only be displayed once built.
+        let mut cli_parser = self.cli_parser.clone();
+        cli_parser.build();
+
+        let conflicting_ids: Vec<&str> = cli_parser
+            .get_groups()
+            .filter(|group| group.get_id() == ACTION_GROUP)
+            .flat_map(|group| group.get_args())
+            .map(|id| id.as_str())
+            .chain(["LOCKFILE"])
+            .collect();
+        let conflicting_arg = cli_parser.get_arguments().find(|arg| {
+            let id = arg.get_id().as_str();
+            conflicting_ids.contains(&id)
+                && arg_matches.value_source(id) == Some(ValueSource::CommandLine)
+        })?;
+
+        Some(Error::from_clap_error(
+            &cli_parser.clone().error(
+                ClapErrorKind::ArgumentConflict,
+                error_messages::SUBCOMMAND_CONFLICT
+                    .replace("{argument}", &conflicting_arg.to_string())
+                    .replace("{subcommand}", subcommand),
+            ),
+        ))
+    }
+
+    fn process_arg_matches(&self, arg_matches: &ArgMatches) -> Result<Args, Error> {
+        if let Some(error) = self.find_subcommand_conflict(arg_matches) {
+            return Err(error);
+        }
+
         let args = Args::from_arg_matches(arg_matches);
-        match args.as_program_exit(&self.cli_parser) {
+        match args.as_early_exit(&self.cli_parser) {
             Some(value) => Err(value),
             None => Ok(args),
         }
@@ -266,33 +888,45 @@ impl ClapArgsParser {
 impl ArgsParser for ClapArgsParser {
     /// Parses given cli args and perform basic error handling.
     ///
-    /// * If the underlying [`ProgramExit`] contains a
-    ///   [`ProgramExit::styled_message`], it will be printed instead of
-    ///   [`ProgramExit::message`].
-    /// * Will exit using [`ProgramExit::exit_status`] if any
-    ///   [`ProgramExit`] received.
+    /// * If the underlying [`Error`] has a styled message (see
+    ///   [`Error::styled_message`]), it will be printed instead of its
+    ///   message when colors are enabled (see
+    ///   [`DefaultDataPrinter::is_colored`]).
+    /// * Will exit using [`Error::exit_status`] if any [`Error`] received.
     /// * Will print to stderr on error, to stdout on early exit (i.e. version,
     ///   author, help options)
     ///
     /// See [`ArgsParser::parse`] for more infos.
     fn parse(&self, args: impl IntoIterator<Item = OsString>) -> Args {
-        match self.try_parse(args) {
+        let args: Vec<OsString> = args.into_iter().collect();
+
+        match self.try_parse(args.clone()) {
             Ok(parsed_args) => parsed_args,
             Err(error) => {
-                error
-                    .styled_message
-                    .clone()
-                    .and_then(|styled_message| Self::print_message(&error, &styled_message))
-                    .or_else(|| Self::print_message(&error, &error.message));
-                exit(error.exit_status);
+                let stream = match error {
+                    Error::EarlyExit { .. } => OutputStream::Stdout,
+                    _ => OutputStream::Stderr,
+                };
+                DefaultDataPrinter::set_color_choice(Self::find_color_choice(&args));
+
+                let message = error
+                    .styled_message()
+                    .filter(|_| DefaultDataPrinter::is_colored(stream))
+                    .map(String::from)
+                    .unwrap_or_else(|| error.to_string());
+                Self::print_message(stream, &message);
+                exit(error.exit_status());
             }
         }
     }
 
-    fn try_parse(&self, args: impl IntoIterator<Item = OsString>) -> Result<Args, ProgramExit> {
+    fn try_parse(&self, args: impl IntoIterator<Item = OsString>) -> Result<Args, Error> {
         match self.cli_parser.clone().try_get_matches_from(args) {
             Ok(arg_matches) => self.process_arg_matches(&arg_matches),
-            Err(error) => Err(ProgramExit::from_clap_error(&error)),
+            // Subcommand errors get formatted as root ones, so that clap does
+            // not hint at the help option on top of the hint added to all
+            // errors.
+            Err(error) => Err(Error::from_clap_error(&error.with_cmd(&self.cli_parser))),
         }
     }
 }
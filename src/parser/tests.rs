@@ -1,12 +1,12 @@
-use std::ffi::OsString;
+use std::{ffi::OsString, time::Duration};
 
 use rstest::*;
 
 use super::*;
 use crate::{
     constant,
-    core::{ExitKind, ProgramExit},
-    helper::TimeoutUnit,
+    core::ExitKind,
+    error::Error,
     test_helper::{DefaultTestUtils, TestUtils},
 };
 
@@ -29,7 +29,8 @@ mod default_args_parser {
             #[case("rust -i /bar -V")]
             #[case("rust -c -V")]
             #[case("rust -t 5 -V")]
-            #[case("rust -u second -V")]
+            #[case("rust --connect-timeout 2s -V")]
+            #[case("rust --read-timeout 2s -V")]
             #[case("-aV")]
             #[case("rust -l -V")]
             fn it_parses_version_cli_option(#[case] cli_args: &str) {
@@ -40,9 +41,8 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::EarlyExit {
                     message: format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
-                    exit_status: 0,
                     styled_message: None,
                     kind: ExitKind::VersionInfos,
                 };
@@ -62,7 +62,8 @@ mod default_args_parser {
             #[case("rust -i /bar -h")]
             #[case("rust -c -h")]
             #[case("rust -t 5 -h")]
-            #[case("rust -u second -h")]
+            #[case("rust --connect-timeout 2s -h")]
+            #[case("rust --read-timeout 2s -h")]
             #[case("-aVh")]
             #[case("rust -l -h")]
             fn it_parses_help_cli_option(#[case] cli_args: &str) {
@@ -73,9 +74,8 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::EarlyExit {
                     message: DefaultTestUtils::get_help_message(),
-                    exit_status: 0,
                     styled_message: Some(DefaultTestUtils::get_ansi_help_message()),
                     kind: ExitKind::HelpInfos,
                 };
@@ -95,7 +95,8 @@ mod default_args_parser {
             #[case("rust -i /bar -a")]
             #[case("rust -c -a")]
             #[case("rust -t 5 -a")]
-            #[case("rust -u second -a")]
+            #[case("rust --connect-timeout 2s -a")]
+            #[case("rust --read-timeout 2s -a")]
             #[case("rust -l -a")]
             fn it_parses_author_cli_option_preemptively(#[case] cli_args: &str) {
                 let cli_args = DefaultTestUtils::parse_and_map_cli_args(
@@ -105,9 +106,8 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::EarlyExit {
                     message: env!("CARGO_PKG_AUTHORS").to_string(),
-                    exit_status: 0,
                     styled_message: None,
                     kind: ExitKind::AuthorInfos,
                 };
@@ -137,6 +137,29 @@ mod default_args_parser {
                 assert_eq!(actual_result, expected_result);
             }
 
+            #[rstest]
+            #[case("-- lint", "lint")]
+            #[case("-- explain rust", "explain rust")]
+            #[case("rust lint", "rust lint")]
+            fn it_parses_pos_args_named_after_subcommands(
+                #[case] cli_options: &str,
+                #[case] template_names: &str,
+            ) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_options,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list(template_names));
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
             #[rstest]
             #[case("rust -s https://test.com")]
             #[case("rust --server-url https://test.com")]
@@ -241,9 +264,457 @@ mod default_args_parser {
             }
 
             #[rstest]
-            #[case("rust python -t 5")]
-            #[case("rust python --timeout 5")]
-            fn it_parses_timeout_option(#[case] cli_args: &str) {
+            #[case("rust python -t 5", Duration::from_secs(5))]
+            #[case("rust python --timeout 5", Duration::from_secs(5))]
+            #[case("rust python --timeout 2s", Duration::from_secs(2))]
+            #[case("rust python --timeout 1500ms", Duration::from_millis(1500))]
+            #[case("rust python --timeout 1m", Duration::from_secs(60))]
+            fn it_parses_timeout_option(#[case] cli_args: &str, #[case] timeout: Duration) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_args,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust python"))
+                    .with_timeout(timeout);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[rstest]
+            #[case("rust python --connect-timeout 2", Duration::from_secs(2))]
+            #[case("rust python --connect-timeout 300ms", Duration::from_millis(300))]
+            fn it_parses_connect_timeout_option(#[case] cli_args: &str, #[case] timeout: Duration) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_args,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust python"))
+                    .with_connect_timeout(timeout);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[rstest]
+            #[case("rust --api-dialect toptal", ApiDialectKind::Toptal)]
+            #[case("rust --api-dialect json", ApiDialectKind::Json)]
+            #[case("rust --api-dialect per-template", ApiDialectKind::PerTemplate)]
+            fn it_parses_api_dialect_option(
+                #[case] cli_args: &str,
+                #[case] api_dialect: ApiDialectKind,
+            ) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_args,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust"))
+                    .with_api_dialect(api_dialect);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            fn it_parses_cache_option() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "rust python --cache",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust python"))
+                    .with_cache_responses(true);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            fn it_parses_diff_option() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "rust python --diff .gitignore",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust python"))
+                    .with_diff_path(".gitignore");
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            fn it_parses_preview_option() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "rust python --preview",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust python"))
+                    .with_preview(true);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[rstest]
+            #[case("rust -vv", 2, false)]
+            #[case("rust --verbose", 1, false)]
+            #[case("rust --quiet", 0, true)]
+            fn it_parses_verbosity_options(
+                #[case] input: &str,
+                #[case] verbose: u8,
+                #[case] quiet: bool,
+            ) {
+                let cli_args =
+                    DefaultTestUtils::parse_and_map_cli_args(input, DefaultTestUtils::to_os_string);
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust"))
+                    .with_verbose(verbose)
+                    .with_quiet(quiet);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[rstest]
+            #[case("rust", ColorChoice::Auto)]
+            #[case("rust --color always", ColorChoice::Always)]
+            #[case("rust --color=never", ColorChoice::Never)]
+            fn it_parses_color_option(#[case] input: &str, #[case] color: ColorChoice) {
+                let cli_args =
+                    DefaultTestUtils::parse_and_map_cli_args(input, DefaultTestUtils::to_os_string);
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust"))
+                    .with_color(color);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            fn it_parses_global_options_after_subcommand() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "lint -v --color never --no-pager",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_lint_targets(LintTargets {
+                        paths: vec![],
+                        template_names: vec![],
+                    })
+                    .with_verbose(1)
+                    .with_color(ColorChoice::Never)
+                    .with_no_pager(true);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            fn it_parses_no_pager_option() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "--list --no-pager",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new().with_show_list(true).with_no_pager(true);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            fn it_parses_template_matching_options() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "rust --order given --strict",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust"))
+                    .with_order(TemplateOrder::Given)
+                    .with_strict(true);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            #[cfg(feature = "local_templating")]
+            fn it_parses_local_templating_options() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "rust --prefer merge --template-title ## --template-ext gitignore --exclude tmp",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust"))
+                    .with_prefer(SourcePrecedence::Merge)
+                    .with_template_title("##")
+                    .with_template_extensions(&DefaultTestUtils::to_string_list("gitignore"))
+                    .with_excluded_templates(&DefaultTestUtils::to_string_list("tmp"));
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[rstest]
+            #[cfg(feature = "local_templating")]
+            #[case(
+                "template add tools/vim --from-file vim.txt",
+                TemplateCommand::Add {
+                    name: String::from("tools/vim"),
+                    source: TemplateSource::File(String::from("vim.txt")),
+                }
+            )]
+            #[case(
+                "template add vim",
+                TemplateCommand::Add {
+                    name: String::from("vim"),
+                    source: TemplateSource::Editor,
+                }
+            )]
+            #[case(
+                "template import rust python --force",
+                TemplateCommand::Import {
+                    names: DefaultTestUtils::to_string_list("rust python"),
+                    force: true,
+                }
+            )]
+            #[case(
+                "--template-ext gitignore template rename vim tools/vim",
+                TemplateCommand::Rename {
+                    name: String::from("vim"),
+                    new_name: String::from("tools/vim"),
+                }
+            )]
+            fn it_parses_template_subcommands(
+                #[case] cli_options: &str,
+                #[case] template_command: TemplateCommand,
+            ) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_options,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok().map(|args| &args.template_command);
+                let expected_result = Some(Some(template_command));
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result.as_ref());
+            }
+
+            #[rstest]
+            #[case("lint .gitignore", DefaultTestUtils::to_string_list(".gitignore"), vec![])]
+            #[case("lint --template rust --template tools/vim", vec![], DefaultTestUtils::to_string_list("rust tools/vim"))]
+            #[case("lint", vec![], vec![])]
+            fn it_parses_lint_subcommand(
+                #[case] cli_options: &str,
+                #[case] paths: Vec<String>,
+                #[case] template_names: Vec<String>,
+            ) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_options,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok().map(|args| &args.lint_targets);
+                let expected_result = Some(Some(LintTargets {
+                    paths,
+                    template_names,
+                }));
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result.as_ref());
+            }
+
+            #[rstest]
+            #[case("explain target/ main.rs", None, vec![])]
+            #[case("explain target/ main.rs --file foo/.gitignore", Some("foo/.gitignore"), vec![])]
+            #[case(
+                "explain target/ main.rs --template rust --template python",
+                None,
+                DefaultTestUtils::to_string_list("rust python")
+            )]
+            fn it_parses_explain_subcommand(
+                #[case] cli_options: &str,
+                #[case] file_path: Option<&str>,
+                #[case] template_names: Vec<String>,
+            ) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_options,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok().map(|args| &args.explain_targets);
+                let expected_result = Some(Some(ExplainTargets {
+                    paths: DefaultTestUtils::to_string_list("target/ main.rs"),
+                    file_path: file_path.map(String::from),
+                    template_names,
+                }));
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result.as_ref());
+            }
+
+            #[test]
+            fn it_parses_layout_options() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "rust --section-header # --no-headers --banner --trailing-newline never",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new()
+                    .with_template_names(DefaultTestUtils::to_string_list("rust"))
+                    .with_section_header("#")
+                    .with_no_headers(true)
+                    .with_banner(true)
+                    .with_trailing_newline(TrailingNewline::Never);
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            fn it_parses_show_subcommand() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "show rust",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new().with_show_template_name("rust");
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[test]
+            fn it_parses_search_subcommand() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "search py --regex",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new().with_search_targets(SearchTargets {
+                    query: String::from("py"),
+                    regex: true,
+                    content: false,
+                });
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[cfg(feature = "local_templating")]
+            #[test]
+            fn it_parses_search_subcommand_with_content_option() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "search py --content",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new().with_search_targets(SearchTargets {
+                    query: String::from("py"),
+                    regex: false,
+                    content: true,
+                });
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[rstest]
+            #[case("verify rust python", None)]
+            #[case("verify rust python --file foo/.gitignore", Some("foo/.gitignore"))]
+            fn it_parses_verify_subcommand(
+                #[case] cli_args: &str,
+                #[case] file_path: Option<&str>,
+            ) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_args,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_result = parsed_args.as_ref().ok();
+                let expected_result = Args::new().with_verify_targets(VerifyTargets {
+                    template_names: DefaultTestUtils::to_string_list("rust python"),
+                    file_path: file_path.map(String::from),
+                });
+                let expected_result = Some(&expected_result);
+
+                assert!(actual_result.is_some());
+                assert_eq!(actual_result, expected_result);
+            }
+
+            #[rstest]
+            #[case("rust python --lockfile foo.lock", false)]
+            #[case("rust python --lockfile foo.lock --locked", true)]
+            fn it_parses_lockfile_options(#[case] cli_args: &str, #[case] locked: bool) {
                 let cli_args = DefaultTestUtils::parse_and_map_cli_args(
                     cli_args,
                     DefaultTestUtils::to_os_string,
@@ -253,7 +724,8 @@ mod default_args_parser {
                 let actual_result = parsed_args.as_ref().ok();
                 let expected_result = Args::new()
                     .with_template_names(DefaultTestUtils::to_string_list("rust python"))
-                    .with_timeout(5);
+                    .with_lockfile_path("foo.lock")
+                    .with_locked(locked);
                 let expected_result = Some(&expected_result);
 
                 assert!(actual_result.is_some());
@@ -261,9 +733,9 @@ mod default_args_parser {
             }
 
             #[rstest]
-            #[case("rust python -u second", TimeoutUnit::SECOND)]
-            #[case("rust python --timeout-unit millisecond", TimeoutUnit::MILLISECOND)]
-            fn it_parses_timeout_unit_option(#[case] cli_args: &str, #[case] unit: TimeoutUnit) {
+            #[case("rust python --read-timeout 3", Duration::from_secs(3))]
+            #[case("rust python --read-timeout 800ms", Duration::from_millis(800))]
+            fn it_parses_read_timeout_option(#[case] cli_args: &str, #[case] timeout: Duration) {
                 let cli_args = DefaultTestUtils::parse_and_map_cli_args(
                     cli_args,
                     DefaultTestUtils::to_os_string,
@@ -273,12 +745,7 @@ mod default_args_parser {
                 let actual_result = parsed_args.as_ref().ok();
                 let expected_result = Args::new()
                     .with_template_names(DefaultTestUtils::to_string_list("rust python"))
-                    .with_timeout_unit(unit)
-                    .with_timeout(if unit == TimeoutUnit::MILLISECOND {
-                        constant::template_manager::TIMEOUT_MILLISECOND_INT
-                    } else {
-                        constant::template_manager::TIMEOUT_INT
-                    });
+                    .with_read_timeout(timeout);
                 let expected_result = Some(&expected_result);
 
                 assert!(actual_result.is_some());
@@ -289,6 +756,30 @@ mod default_args_parser {
         mod failure {
             use super::*;
 
+            #[test]
+            fn it_fails_parsing_when_locked_without_lockfile() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "rust --locked",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_error = parsed_args.as_ref().err();
+                let expected_error = Error::Cli {
+                    message: DefaultTestUtils::load_expectation_file(
+                        "locked_without_lockfile_error",
+                    ),
+                    exit_status: constant::exit_status::GENERIC,
+                    styled_message: DefaultTestUtils::load_expectation_file(
+                        "ansi_locked_without_lockfile_error",
+                    ),
+                };
+                let expected_error = Some(&expected_error);
+
+                assert!(actual_error.is_some());
+                assert_eq!(actual_error, expected_error);
+            }
+
             #[test]
             fn it_fails_parsing_when_no_pos_args_given() {
                 let cli_args =
@@ -296,14 +787,12 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file("no_pos_args_error"),
                     exit_status: constant::exit_status::GENERIC,
-
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_no_pos_args_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -320,14 +809,12 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file("comma_pos_args_error"),
                     exit_status: constant::exit_status::GENERIC,
-
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_comma_pos_args_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -341,14 +828,12 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file("whitespace_pos_args_error"),
                     exit_status: constant::exit_status::GENERIC,
-
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_whitespace_pos_args_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -365,16 +850,14 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file(
                         "comma_whitespace_pos_args_error",
                     ),
                     exit_status: constant::exit_status::GENERIC,
-
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_comma_whitespace_pos_args_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -391,16 +874,14 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file(
                         "server_url_no_pos_args_error",
                     ),
                     exit_status: constant::exit_status::GENERIC,
-
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_server_url_no_pos_args_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -417,16 +898,14 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file(
                         "generator_uri_no_pos_args_error",
                     ),
                     exit_status: constant::exit_status::GENERIC,
-
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_generator_uri_no_pos_args_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -443,16 +922,14 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file(
                         "lister_uri_no_pos_args_error",
                     ),
                     exit_status: constant::exit_status::GENERIC,
-
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_lister_uri_no_pos_args_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -469,16 +946,14 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file(
                         "check_option_no_pos_args_error",
                     ),
                     exit_status: constant::exit_status::GENERIC,
-
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_check_option_no_pos_args_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -493,13 +968,107 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file("unexpected_argument_error"),
                     exit_status: constant::exit_status::GENERIC,
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_unexpected_argument_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
+                };
+                let expected_error = Some(&expected_error);
+
+                assert!(actual_error.is_some());
+                assert_eq!(actual_error, expected_error);
+            }
+
+            #[rstest]
+            #[case("rust --prefer merge")]
+            #[case("rust --template-title ##")]
+            #[case("rust --template-ext gitignore")]
+            #[case("rust --exclude tmp")]
+            #[cfg(not(feature = "local_templating"))]
+            fn it_fails_parsing_local_templating_options_without_feature(#[case] cli_args: &str) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_args,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_error = parsed_args.as_ref().err().map(Error::exit_status);
+                let expected_error = Some(constant::exit_status::GENERIC);
+
+                assert_eq!(actual_error, expected_error);
+            }
+
+            #[rstest]
+            #[case("rust --lockfile x --list")]
+            #[case("rust --lockfile x --diff .gitignore")]
+            #[case("--lockfile x verify rust")]
+            #[case("--lockfile x show rust")]
+            #[case("--lockfile x search rust")]
+            #[case("rust --lockfile x --preview")]
+            #[case("--lockfile x lint")]
+            #[case("--lockfile x explain target/ rust")]
+            fn it_fails_parsing_lockfile_without_generation(#[case] cli_args: &str) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_args,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_error = parsed_args.as_ref().err();
+
+                assert!(actual_error.is_some_and(|error| {
+                    error.exit_status() == constant::exit_status::GENERIC
+                        && error.to_string().starts_with(
+                            "error: the argument '--lockfile <PATH>' cannot be used with",
+                        )
+                }));
+            }
+
+            #[rstest]
+            #[case("rust --list --diff .gitignore", "--list")]
+            #[case("rust --diff .gitignore --preview", "--diff <PATH>")]
+            #[case("--list search rust", "--list")]
+            #[case("--diff .gitignore lint", "--diff <PATH>")]
+            #[case("--preview verify rust", "--preview")]
+            fn it_fails_parsing_conflicting_actions(
+                #[case] cli_args: &str,
+                #[case] conflicting_arg: &str,
+            ) {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    cli_args,
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_error = parsed_args.as_ref().err();
+
+                assert!(actual_error.is_some_and(|error| {
+                    error.exit_status() == constant::exit_status::GENERIC
+                        && error.to_string().starts_with(&format!(
+                            "error: the argument '{conflicting_arg}' cannot be used with"
+                        ))
+                }));
+            }
+
+            #[test]
+            fn it_fails_parsing_when_inexistent_subcommand_option() {
+                let cli_args = DefaultTestUtils::parse_and_map_cli_args(
+                    "lint --bogus",
+                    DefaultTestUtils::to_os_string,
+                );
+                let parsed_args = ClapArgsParser::new().try_parse(cli_args);
+
+                let actual_error = parsed_args.as_ref().err();
+                let expected_error = Error::Cli {
+                    message: DefaultTestUtils::load_expectation_file(
+                        "subcommand_unexpected_argument_error",
+                    ),
+                    exit_status: constant::exit_status::GENERIC,
+                    styled_message: DefaultTestUtils::load_expectation_file(
+                        "ansi_subcommand_unexpected_argument_error",
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -516,13 +1085,12 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file("non_integer_timeout_error"),
                     exit_status: constant::exit_status::GENERIC,
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
+                    styled_message: DefaultTestUtils::load_expectation_file(
                         "ansi_non_integer_timeout_error",
-                    )),
-                    kind: ExitKind::Error,
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -531,23 +1099,20 @@ mod default_args_parser {
             }
 
             #[test]
-            fn it_fails_parsing_when_non_allowed_timeout_unit() {
+            fn it_fails_parsing_when_invalid_timeout_unit() {
                 let cli_args = DefaultTestUtils::parse_and_map_cli_args(
-                    "-u millisecon",
+                    "-t 5h",
                     DefaultTestUtils::to_os_string,
                 );
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
-                    message: DefaultTestUtils::load_expectation_file(
-                        "non_allowed_timeout_unit_error",
-                    ),
+                let expected_error = Error::Cli {
+                    message: DefaultTestUtils::load_expectation_file("invalid_timeout_unit_error"),
                     exit_status: constant::exit_status::GENERIC,
-                    styled_message: Some(DefaultTestUtils::load_expectation_file(
-                        "ansi_non_allowed_timeout_unit_error",
-                    )),
-                    kind: ExitKind::Error,
+                    styled_message: DefaultTestUtils::load_expectation_file(
+                        "ansi_invalid_timeout_unit_error",
+                    ),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -562,7 +1127,11 @@ mod default_args_parser {
             #[case("-i /bar -i /foo", "--lister-uri <LISTER_URI>")]
             #[case("-s https://foo.com -s https://bar.com", "--server-url <SERVER_URL>")]
             #[case("-t1 -t2", "--timeout <TIMEOUT>")]
-            #[case("-u millisecond -u second", "--timeout-unit <TIMEOUT_UNIT>")]
+            #[case(
+                "--connect-timeout 1s --connect-timeout 2s",
+                "--connect-timeout <DURATION>"
+            )]
+            #[case("--read-timeout 1s --read-timeout 2s", "--read-timeout <DURATION>")]
             #[case("-hh", "--help")]
             #[case("-VV", "--version")]
             #[case("-aa", "--author")]
@@ -577,15 +1146,14 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file("given_multiple_times_error")
                         .replace("{argument_name}", option_name),
                     exit_status: constant::exit_status::GENERIC,
-                    styled_message: Some(
-                        DefaultTestUtils::load_expectation_file("ansi_given_multiple_times_error")
-                            .replace("{argument_name}", option_name),
-                    ),
-                    kind: ExitKind::Error,
+                    styled_message: DefaultTestUtils::load_expectation_file(
+                        "ansi_given_multiple_times_error",
+                    )
+                    .replace("{argument_name}", option_name),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -610,19 +1178,16 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file(
                         "boolean_option_with_value_error",
                     )
                     .replace("{argument_name}", option_name),
                     exit_status: constant::exit_status::GENERIC,
-                    styled_message: Some(
-                        DefaultTestUtils::load_expectation_file(
-                            "ansi_boolean_option_with_value_error",
-                        )
-                        .replace("{argument_name}", option_name),
-                    ),
-                    kind: ExitKind::Error,
+                    styled_message: DefaultTestUtils::load_expectation_file(
+                        "ansi_boolean_option_with_value_error",
+                    )
+                    .replace("{argument_name}", option_name),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -644,19 +1209,16 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file(
                         "uri_without_starting_slash_error",
                     )
                     .replace("{argument_name}", option_name),
                     exit_status: constant::exit_status::GENERIC,
-                    styled_message: Some(
-                        DefaultTestUtils::load_expectation_file(
-                            "ansi_uri_without_starting_slash_error",
-                        )
-                        .replace("{argument_name}", option_name),
-                    ),
-                    kind: ExitKind::Error,
+                    styled_message: DefaultTestUtils::load_expectation_file(
+                        "ansi_uri_without_starting_slash_error",
+                    )
+                    .replace("{argument_name}", option_name),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -683,17 +1245,14 @@ mod default_args_parser {
                 let parsed_args = ClapArgsParser::new().try_parse(cli_args);
 
                 let actual_error = parsed_args.as_ref().err();
-                let expected_error = ProgramExit {
+                let expected_error = Error::Cli {
                     message: DefaultTestUtils::load_expectation_file(expectation_filename)
                         .replace("{input_value}", invalid_value),
                     exit_status: constant::exit_status::GENERIC,
-                    styled_message: Some(
-                        DefaultTestUtils::load_expectation_file(&format!(
-                            "ansi_{expectation_filename}"
-                        ))
-                        .replace("{input_value}", invalid_value),
-                    ),
-                    kind: ExitKind::Error,
+                    styled_message: DefaultTestUtils::load_expectation_file(&format!(
+                        "ansi_{expectation_filename}"
+                    ))
+                    .replace("{input_value}", invalid_value),
                 };
                 let expected_error = Some(&expected_error);
 
@@ -712,7 +1271,7 @@ mod default_args_parser {
             #[test]
             fn it_parses_given_cli_options() {
                 let cli_args = DefaultTestUtils::parse_and_map_cli_args(
-                    "rust python -s https://test -g /foo -i /bar --check --list -t 6 -u millisecond",
+                    "rust python -s https://test -g /foo -i /bar --check --list -t 6ms --connect-timeout 2 --read-timeout 3 --cache",
                     DefaultTestUtils::to_os_string,
                 );
 
@@ -724,11 +1283,39 @@ mod default_args_parser {
                     .with_lister_uri("/bar")
                     .with_check_template_names(true)
                     .with_show_list(true)
-                    .with_timeout(6)
-                    .with_timeout_unit(TimeoutUnit::MILLISECOND);
+                    .with_timeout(Duration::from_millis(6))
+                    .with_connect_timeout(Duration::from_secs(2))
+                    .with_read_timeout(Duration::from_secs(3))
+                    .with_cache_responses(true);
 
                 assert_eq!(actual_result, expected_result);
             }
         }
     }
+    mod find_color_choice {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case("rust", ColorChoice::Auto)]
+            #[case("rust --color always", ColorChoice::Always)]
+            #[case("--color=never rust", ColorChoice::Never)]
+            #[case("rust --color never --color always", ColorChoice::Always)]
+            #[case("rust --color foo", ColorChoice::Auto)]
+            #[case("rust -- --color always", ColorChoice::Auto)]
+            fn it_finds_color_choice_in_raw_cli_args(
+                #[case] input: &str,
+                #[case] expected: ColorChoice,
+            ) {
+                let cli_args =
+                    DefaultTestUtils::parse_and_map_cli_args(input, DefaultTestUtils::to_os_string);
+
+                let actual = ClapArgsParser::find_color_choice(&cli_args);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
 }
@@ -1,4 +1,10 @@
-use clap::Error;
+//! Define components to print data to stdout and stderr.
+//!
+//! Output gets styled with colors as allowed by the global [`ColorChoice`]
+//! set once cli args got parsed (see [`DefaultDataPrinter::set_color_choice`]).
+//! Long output written to a terminal gets printed through the pager, unless
+//! disabled (see [`DefaultDataPrinter::set_paging`]).
+use clap::{Error, ValueEnum};
 
 use crate::core::{ProgramExit, QualifiedString};
 
@@ -7,15 +13,39 @@ mod impls;
 #[cfg(test)]
 mod tests;
 
+/// Enum of policies for styling output with colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum ColorChoice {
+    /// Style output only if written to a terminal, and if the `NO_COLOR`
+    /// env var is not set.
+    #[default]
+    Auto,
+
+    /// Always style output.
+    Always,
+
+    /// Never style output.
+    Never,
+}
+
+/// Enum of the streams output gets written to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
 pub enum Data<'a> {
     QualifiedString(&'a QualifiedString),
     ProgramExit(&'a ProgramExit),
     EnvVarReset(&'a str),
     EnvVarRemovalBefore(),
     EnvVarRemovalAfter(),
-    DefaultTimeout(),
     ClapError(&'a Error),
     StyledClapError(&'a Error),
+    StyledError(&'a str),
+    StyledTemplateList(&'a str),
+    StyledTemplates(&'a str, &'a [String]),
     Any(&'a str),
 }
 
@@ -1,13 +1,242 @@
+use std::{
+    env,
+    ffi::OsStr,
+    io::{self, IsTerminal, Write},
+    process::{Command, Stdio},
+    sync::atomic::{AtomicBool, AtomicU8, Ordering},
+};
+
 use crate::{
-    constant::{help_texts, template_manager},
-    printer::{Data, DataPrinter, DefaultDataPrinter},
+    constant::{help_texts, log_messages, template_manager},
+    core::ExitKind,
+    logger::{Logger, Verbosity},
+    printer::{ColorChoice, Data, DataPrinter, DefaultDataPrinter, OutputStream},
 };
 
+/// Global color choice, stored as the position of its [`ColorChoice`]
+/// variant.
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+
+/// Global indicator of whether long output may be printed through the
+/// pager.
+static PAGING: AtomicBool = AtomicBool::new(true);
+
+impl ColorChoice {
+    /// Checks whether output written to a stream gets styled with colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_terminal` - Whether the stream is a terminal.
+    /// * `no_color` - The value of the `NO_COLOR` env var, if set. Any
+    ///   non-empty value disables colors, unless they are forced through
+    ///   [`ColorChoice::Always`].
+    pub fn is_enabled(&self, is_terminal: bool, no_color: Option<&OsStr>) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => is_terminal && no_color.is_none_or(OsStr::is_empty),
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Auto,
+            1 => Self::Always,
+            _ => Self::Never,
+        }
+    }
+}
+
+impl DefaultDataPrinter {
+    /// Sets the global color choice, deciding whether output gets styled.
+    pub fn set_color_choice(color_choice: ColorChoice) {
+        COLOR_CHOICE.store(color_choice as u8, Ordering::Relaxed);
+    }
+
+    /// Gets the global color choice.
+    pub fn color_choice() -> ColorChoice {
+        ColorChoice::from_u8(COLOR_CHOICE.load(Ordering::Relaxed))
+    }
+
+    /// Checks whether output written to given stream gets styled with
+    /// colors, as allowed by the global color choice, the stream being a
+    /// terminal, and the `NO_COLOR` env var.
+    pub fn is_colored(stream: OutputStream) -> bool {
+        let is_terminal = match stream {
+            OutputStream::Stdout => io::stdout().is_terminal(),
+            OutputStream::Stderr => io::stderr().is_terminal(),
+        };
+
+        Self::color_choice().is_enabled(
+            is_terminal,
+            env::var_os(template_manager::NO_COLOR_ENV_VAR).as_deref(),
+        )
+    }
+
+    /// Sets whether long output may be printed through the pager.
+    pub fn set_paging(paging: bool) {
+        PAGING.store(paging, Ordering::Relaxed);
+    }
+
+    /// Checks whether long output may be printed through the pager.
+    pub fn is_paging() -> bool {
+        PAGING.load(Ordering::Relaxed)
+    }
+
+    /// Checks whether given output does not fit in a terminal of given
+    /// height, i.e. whether it has more lines than the terminal.
+    pub fn exceeds_screen(value: &str, height: usize) -> bool {
+        value.lines().count() > height
+    }
+
+    /// Gets the terminal height from the `LINES` env var, or by querying
+    /// the terminal attached to stdout.
+    ///
+    /// # Returns
+    ///
+    /// The number of lines of the terminal, or `None` if it could not be
+    /// found out (e.g. no terminal attached to stdout).
+    fn terminal_height() -> Option<usize> {
+        env::var(template_manager::LINES_ENV_VAR)
+            .ok()
+            .and_then(|lines| lines.trim().parse().ok())
+            .or_else(Self::window_height)
+    }
+
+    /// Queries the height of the terminal attached to stdout through the
+    /// `TIOCGWINSZ` ioctl.
+    #[cfg(unix)]
+    fn window_height() -> Option<usize> {
+        let mut window_size = libc::winsize {
+            ws_row: 0,
+            ws_col: 0,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+        // SAFETY: `TIOCGWINSZ` only writes into the given `winsize` struct,
+        // which outlives the call.
+        let result =
+            unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut window_size) };
+
+        (result == 0 && window_size.ws_row > 0).then_some(usize::from(window_size.ws_row))
+    }
+
+    /// Terminal height can only be told by the `LINES` env var on non-unix
+    /// platforms.
+    #[cfg(not(unix))]
+    fn window_height() -> Option<usize> {
+        None
+    }
+
+    /// Prints given output through the pager command given by `$PAGER`
+    /// (falling back to [`template_manager::DEFAULT_PAGER`]), if paging is
+    /// enabled, stdout is a terminal and the output exceeds its height.
+    ///
+    /// The pager command is run through `sh -c`, so that it may hold quoted
+    /// arguments, as git does.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the output got printed through the pager, `false` if it
+    /// still has to be printed (e.g. short output, pager failure or exit
+    /// with a failure status...).
+    fn page(value: &str) -> bool {
+        if !Self::is_paging() || !io::stdout().is_terminal() {
+            return false;
+        }
+        let Some(height) = Self::terminal_height() else {
+            return false;
+        };
+        if !Self::exceeds_screen(value, height) {
+            return false;
+        }
+
+        let pager = env::var(template_manager::PAGER_ENV_VAR)
+            .ok()
+            .filter(|pager| !pager.trim().is_empty())
+            .unwrap_or_else(|| template_manager::DEFAULT_PAGER.to_string());
+        Logger::debug(
+            &log_messages::PAGING_OUTPUT
+                .replace("{lines}", &value.lines().count().to_string())
+                .replace("{pager}", &pager)
+                .replace("{height}", &height.to_string()),
+        );
+
+        let result = Command::new("sh")
+            .arg("-c")
+            .arg(&pager)
+            .stdin(Stdio::piped())
+            .spawn()
+            .and_then(|mut child| {
+                if let Some(mut stdin) = child.stdin.take() {
+                    // The pager may be quit before reading the whole output.
+                    let _ = stdin.write_all(value.as_bytes());
+                }
+                child.wait()
+            });
+
+        let error = match result {
+            Ok(status) if status.success() => return true,
+            Ok(status) => status.to_string(),
+            Err(error) => error.to_string(),
+        };
+        Logger::verbose(
+            &log_messages::PAGER_FAILURE
+                .replace("{pager}", &pager)
+                .replace("{error}", &error),
+        );
+
+        false
+    }
+
+    fn style_template_list_item(item: &str) -> String {
+        if item.starts_with('*') {
+            help_texts::STYLED_LOCAL_TEMPLATE.replace("{name}", item)
+        } else {
+            help_texts::STYLED_REMOTE_TEMPLATE.replace("{name}", item)
+        }
+    }
+
+    fn style_template_line(line: &str, section_headers: &[String]) -> String {
+        let is_template_title = line
+            .trim()
+            .strip_prefix("### ")
+            .is_some_and(|line| line.ends_with(" ###"));
+
+        if section_headers.iter().any(|header| header == line) {
+            help_texts::STYLED_SECTION_HEADER.replace("{header}", line)
+        } else if is_template_title {
+            help_texts::STYLED_TEMPLATE_TITLE.replace("{title}", line)
+        } else {
+            line.to_string()
+        }
+    }
+}
+
 impl DataPrinter for DefaultDataPrinter {
     fn pp(data: &Data) {
         let value = Self::ppg(data);
         match data {
-            Data::ProgramExit(_) => eprintln!("{value}"),
+            Data::ProgramExit(pe) if pe.kind != ExitKind::Mismatch => {
+                if !Logger::is_enabled(Verbosity::Normal) {
+                    return;
+                }
+
+                if Self::is_colored(OutputStream::Stderr) {
+                    let styled_value = pe
+                        .styled_message
+                        .clone()
+                        .unwrap_or_else(|| Self::ppg(&Data::StyledError(&value)));
+                    eprintln!("{styled_value}")
+                } else {
+                    eprintln!("{value}")
+                }
+            }
+            Data::QualifiedString(_) => {
+                if !Self::page(&value) {
+                    print!("{value}")
+                }
+            }
             _ => println!("{value}"),
         }
     }
@@ -25,14 +254,22 @@ impl DataPrinter for DefaultDataPrinter {
             Data::EnvVarRemovalAfter() => {
                 help_texts::ENV_VAR_REMOVAL_AFTER.replace("{name}", template_manager::HOME_ENV_VAR)
             }
-            Data::DefaultTimeout() => help_texts::DEFAULT_TIMEOUT
-                .replace("{second}", template_manager::TIMEOUT)
-                .replace("{millis}", template_manager::TIMEOUT_MILLISECOND),
             Data::ClapError(error) => {
                 help_texts::HELP_FOR_MORE_INFOS.replace("{error}", &error.render().to_string())
             }
             Data::StyledClapError(error) => help_texts::STYLED_HELP_FOR_MORE_INFOS
                 .replace("{error}", &error.render().ansi().to_string()),
+            Data::StyledError(error) => help_texts::STYLED_ERROR.replace("{error}", error),
+            Data::StyledTemplateList(list) => list
+                .lines()
+                .map(Self::style_template_list_item)
+                .collect::<Vec<String>>()
+                .join("\n"),
+            Data::StyledTemplates(templates, section_headers) => templates
+                .split('\n')
+                .map(|line| Self::style_template_line(line, section_headers))
+                .collect::<Vec<String>>()
+                .join("\n"),
             Data::Any(value) => value.to_string(),
         }
     }
@@ -1 +1,99 @@
+use std::ffi::OsStr;
 
+use rstest::rstest;
+
+use crate::printer::{ColorChoice, Data, DataPrinter, DefaultDataPrinter};
+
+mod color_choice {
+    use super::*;
+
+    mod is_enabled {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case(ColorChoice::Auto, true, None, true)]
+            #[case(ColorChoice::Auto, true, Some(""), true)]
+            #[case(ColorChoice::Auto, true, Some("1"), false)]
+            #[case(ColorChoice::Auto, false, None, false)]
+            #[case(ColorChoice::Always, false, Some("1"), true)]
+            #[case(ColorChoice::Never, true, None, false)]
+            fn it_styles_output_as_allowed_by_terminal_and_no_color(
+                #[case] color_choice: ColorChoice,
+                #[case] is_terminal: bool,
+                #[case] no_color: Option<&str>,
+                #[case] expected: bool,
+            ) {
+                let actual = color_choice.is_enabled(is_terminal, no_color.map(OsStr::new));
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod default_data_printer {
+    use super::*;
+
+    mod exceeds_screen {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case("rust\npython\n", 2, false)]
+            #[case("rust\npython\njava\n", 2, true)]
+            #[case("", 0, false)]
+            fn it_checks_whether_output_has_more_lines_than_terminal(
+                #[case] value: &str,
+                #[case] height: usize,
+                #[case] expected: bool,
+            ) {
+                let actual = DefaultDataPrinter::exceeds_screen(value, height);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod ppg {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_styles_local_and_remote_templates_of_list() {
+                let actual = DefaultDataPrinter::ppg(&Data::StyledTemplateList("*python\nrust"));
+                let expected = "\u{1b}[32m*python\u{1b}[0m\n\u{1b}[36mrust\u{1b}[0m";
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_styles_section_headers_and_template_titles() {
+                let section_headers = [String::from("## LOCAL")];
+
+                let actual = DefaultDataPrinter::ppg(&Data::StyledTemplates(
+                    "## LOCAL\n### *Python ###\n*.pyc\n",
+                    &section_headers,
+                ));
+                let expected =
+                    "\u{1b}[1;33m## LOCAL\u{1b}[0m\n\u{1b}[1m### *Python ###\u{1b}[0m\n*.pyc\n";
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_styles_error_message() {
+                let actual = DefaultDataPrinter::ppg(&Data::StyledError("oops"));
+                let expected = "\u{1b}[31moops\u{1b}[0m";
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
@@ -1,7 +1,24 @@
+use std::{fs, io, path::Path, process::Command};
+
 use crate::{
-    core::{ProgramExit, QualifiedString, TemplateFactory, TemplateManager},
-    parser::{Action, ArgsParser, ClapArgsParser},
+    constant::{error_messages, exit_status, help_texts, log_messages, path, template_manager},
+    core::{
+        LocalGitignoreTemplateManager, QualifiedString, RemoteGitignoreTemplateManager,
+        SourcedTemplate, StringKind, TemplateEditor, TemplateFactory, TemplateLister,
+        TemplateManager,
+    },
+    diff::{ContentDiffer, UnifiedDiffer},
+    error::Error,
+    layout::Layout,
+    lint::LintReport,
+    lockfile::{LockedTemplate, Lockfile},
+    logger::Logger,
+    matcher::{GitignoreMatcher, PathExplanation},
+    parser::{Action, Args, ArgsParser, ClapArgsParser, TemplateCommand, TemplateSource},
+    printer::{Data, DataPrinter, DefaultDataPrinter, OutputStream},
     runner::TemplateManagerRunner,
+    search::{LineMatch, SearchMatch, SearchQuery},
+    verify::{GitignoreFile, TemplateVerification},
 };
 
 impl<F: TemplateFactory<dyn TemplateManager>> TemplateManagerRunner<F> {
@@ -11,23 +28,650 @@ impl<F: TemplateFactory<dyn TemplateManager>> TemplateManagerRunner<F> {
         }
     }
 
-    pub fn exec(&self, parser: &impl ArgsParser) -> Result<QualifiedString, ProgramExit> {
+    pub fn exec(&self, parser: &impl ArgsParser) -> Result<QualifiedString, Error> {
         let args = parser.parse(std::env::args_os());
-        let manager = F::from_args(&args)?;
+        Logger::set_verbosity(args.verbosity());
+        DefaultDataPrinter::set_color_choice(args.color);
+        DefaultDataPrinter::set_paging(!args.no_pager);
+        Logger::debug(&log_messages::RESOLVED_ARGS.replace("{args}", &format!("{args:#?}")));
+
+        // only built by the actions needing it, so that a misconfigured
+        // manager does not fail the other ones
+        let manager = || F::from_args(&args);
 
         let result = match args.to_action() {
-            Action::List => manager.list(),
-            Action::RobustGenerate => manager.generate_with_template_check(&args.template_names),
-            Action::Generate => manager.generate(&args.template_names),
+            Action::ManageTemplate => self.manage_template(&args),
+            Action::Lint => self.lint(&args),
+            Action::Explain => self.explain(manager()?.as_ref(), &args),
+            Action::List => manager()?.list(),
+            Action::Search => self.search(manager()?.as_ref(), &args),
+            Action::Show => self.show(manager()?.as_ref(), &args),
+            Action::Diff => self.diff(manager()?.as_ref(), &args),
+            Action::Verify => self.verify(manager()?.as_ref(), &args),
+            Action::Preview => self.preview(manager()?.as_ref(), &args),
+            Action::RobustGenerate | Action::Generate => self.generate(manager()?.as_ref(), &args),
         };
 
         self.parse_result(&result)
+            .map(|output| self.render(&output, &args))
+    }
+
+    /// Renders given output as it is meant to be printed.
+    ///
+    /// Generated templates are laid out as given by args (see [`Layout`]),
+    /// while any other output simply ends with a newline. Generated
+    /// templates and template lists are styled with colors if enabled (see
+    /// [`DefaultDataPrinter::is_colored`]).
+    fn render(&self, output: &QualifiedString, args: &Args) -> QualifiedString {
+        let is_empty = *output == QualifiedString::empty(output.kind);
+        let is_colored = DefaultDataPrinter::is_colored(OutputStream::Stdout);
+
+        let value = match args.to_action() {
+            Action::Generate | Action::RobustGenerate if !is_empty => {
+                let layout = Layout::from(args);
+                let rendered = layout.render(&output.value, &Self::command_line());
+
+                if is_colored {
+                    let section_headers: Vec<String> = [StringKind::Local, StringKind::Remote]
+                        .into_iter()
+                        .filter_map(|source| layout.section_header(source))
+                        .collect();
+                    DefaultDataPrinter::ppg(&Data::StyledTemplates(&rendered, &section_headers))
+                } else {
+                    rendered
+                }
+            }
+            Action::List if !is_empty && is_colored => format!(
+                "{}\n",
+                DefaultDataPrinter::ppg(&Data::StyledTemplateList(&output.value))
+            ),
+            _ => format!("{}\n", output.value),
+        };
+
+        QualifiedString {
+            value,
+            kind: output.kind,
+        }
+    }
+
+    /// Rebuilds the command line the script got started with, to be
+    /// written in the generated-by banner.
+    fn command_line() -> String {
+        std::iter::once(env!("CARGO_PKG_NAME").to_string())
+            .chain(std::env::args().skip(1).map(|arg| {
+                if arg.contains(char::is_whitespace) {
+                    format!("'{arg}'")
+                } else {
+                    arg
+                }
+            }))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Searches available templates matching the query given by
+    /// [`Args::search_targets`].
+    ///
+    /// Template names are always searched, while template bodies are only
+    /// searched if told so, each local template being then read on its
+    /// own. Remote template bodies are not searched, as it would take one
+    /// service call per available template.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the matching template names, each followed by
+    /// its matching body lines, or an [`Error`] on error (e.g. invalid
+    /// pattern, 4xx, network issues...).
+    fn search(&self, manager: &dyn TemplateManager, args: &Args) -> Result<QualifiedString, Error> {
+        let Some(search_targets) = &args.search_targets else {
+            return Ok(QualifiedString::empty(StringKind::Mixed));
+        };
+        let query = SearchQuery::new(&search_targets.query, search_targets.regex)?;
+        let mut template_names: Vec<String> = manager
+            .list()?
+            .value
+            .lines()
+            .map(|line| line.trim_start_matches('*').to_string())
+            .collect();
+        template_names.sort();
+        template_names.dedup();
+
+        let templates = if search_targets.content {
+            let local_manager = LocalGitignoreTemplateManager::try_from_args(args)?;
+            let local_template_names: Vec<String> = local_manager
+                .list()?
+                .value
+                .lines()
+                .map(String::from)
+                .collect();
+            local_manager.generate_each(&local_template_names)?
+        } else {
+            Vec::new()
+        };
+
+        let highlight = DefaultDataPrinter::is_colored(OutputStream::Stdout);
+        let report = template_names
+            .iter()
+            .filter_map(|template_name| {
+                let lines: Vec<LineMatch> = templates
+                    .iter()
+                    .filter(|template| &template.name == template_name)
+                    .flat_map(|template| query.find_lines(&template.content))
+                    .collect();
+
+                (query.find(template_name).is_some() || !lines.is_empty()).then(|| SearchMatch {
+                    name: template_name.clone(),
+                    lines,
+                })
+            })
+            .map(|search_match| search_match.to_report(highlight))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Ok(QualifiedString {
+            value: report,
+            kind: StringKind::Mixed,
+        })
+    }
+
+    /// Shows the template named [`Args::show_template_name`] along with its
+    /// metadata, once per source generating it (see [`Args::prefer`]).
+    ///
+    /// # Returns
+    ///
+    /// A result containing the template details, or an [`Error`] on
+    /// error (e.g. 4xx, network issues, template not found...).
+    fn show(&self, manager: &dyn TemplateManager, args: &Args) -> Result<QualifiedString, Error> {
+        let template_name = args.show_template_name.clone().unwrap_or_default();
+        let templates = manager.generate_each(&[template_name])?;
+
+        Ok(QualifiedString {
+            value: templates
+                .iter()
+                .map(SourcedTemplate::describe)
+                .collect::<Vec<String>>()
+                .join("\n\n"),
+            kind: StringKind::Mixed,
+        })
+    }
+
+    /// Compares the gitignore file at [`Args::diff_path`] with the
+    /// templates generated from given args.
+    ///
+    /// The generated templates are compared as printed (i.e. laid out as
+    /// given by args), so that a file produced by redirecting the script
+    /// output is up to date. The generated-by banner depends on the command
+    /// line though, so it is left out on both sides.
+    ///
+    /// # Returns
+    ///
+    /// A result containing an up-to-date message if the file matches the
+    /// generated templates, or a [`Error::Mismatch`] holding the unified diff otherwise.
+    fn diff(&self, manager: &dyn TemplateManager, args: &Args) -> Result<QualifiedString, Error> {
+        let path = args.diff_path.as_deref().unwrap_or_default();
+        let generated = if args.check_template_names {
+            manager.generate_with_template_check(&args.template_names)?
+        } else {
+            manager.generate(&args.template_names)?
+        };
+        let actual = Self::read_file(path)?;
+        let layout = Layout {
+            banner: false,
+            ..Layout::from(args)
+        };
+        let expected = layout.undate(&layout.render(&generated.value, ""));
+
+        match UnifiedDiffer::new(path, help_texts::GENERATED_LABEL)
+            .diff(&layout.undate(Layout::strip_banner(&actual)), &expected)
+        {
+            None => Ok(QualifiedString {
+                value: help_texts::UP_TO_DATE.replace("{path}", path),
+                kind: generated.kind,
+            }),
+            Some(diff) => Err(Error::Mismatch {
+                report: diff,
+                exit_status: exit_status::DIFF_MISMATCH,
+            }),
+        }
+    }
+
+    /// Verifies the gitignore file given by [`Args::verify_targets`]
+    /// contains the templates matching its template names.
+    ///
+    /// Each template is generated on its own to serve as reference content,
+    /// so that its rules can be looked up in the file, falling back to
+    /// [`path::GITIGNORE_FILE`] if no file is given.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the verification report if all templates are
+    /// present, or a [`Error::Mismatch`] holding
+    /// the report otherwise.
+    fn verify(&self, manager: &dyn TemplateManager, args: &Args) -> Result<QualifiedString, Error> {
+        let Some(verify_targets) = &args.verify_targets else {
+            return Ok(QualifiedString::empty(StringKind::Mixed));
+        };
+        let path = verify_targets
+            .file_path
+            .as_deref()
+            .unwrap_or(path::GITIGNORE_FILE);
+        let gitignore_file = GitignoreFile::parse(&Self::read_file(path)?);
+
+        let verifications = verify_targets
+            .template_names
+            .iter()
+            .map(|template_name| {
+                let template_names = std::slice::from_ref(template_name);
+                let template = if args.check_template_names {
+                    manager.generate_with_template_check(template_names)?
+                } else {
+                    manager.generate(template_names)?
+                };
+
+                Ok(gitignore_file.verify(template_name, &template.value))
+            })
+            .collect::<Result<Vec<TemplateVerification>, Error>>()?;
+
+        let report = verifications
+            .iter()
+            .map(TemplateVerification::to_report)
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        if verifications.iter().all(TemplateVerification::is_present) {
+            Ok(QualifiedString {
+                value: report,
+                kind: StringKind::Mixed,
+            })
+        } else {
+            Err(Error::Mismatch {
+                report,
+                exit_status: exit_status::VERIFY_FAILURE,
+            })
+        }
+    }
+
+    /// Lists the entries of the current directory the templates matching
+    /// given template names would ignore.
+    ///
+    /// Files usually tracked by version control (e.g. `Cargo.lock`) are
+    /// flagged, as ignoring them is seldom wanted.
+    ///
+    /// # Returns
+    ///
+    /// A result containing one explanation per ignored entry, or an
+    /// [`Error`] if the templates could not be generated or the
+    /// current directory walked through.
+    fn preview(
+        &self,
+        manager: &dyn TemplateManager,
+        args: &Args,
+    ) -> Result<QualifiedString, Error> {
+        let generated = if args.check_template_names {
+            manager.generate_with_template_check(&args.template_names)?
+        } else {
+            manager.generate(&args.template_names)?
+        };
+        let ignored_entries = GitignoreMatcher::parse(&generated.value)
+            .ignored_entries(Path::new("."))
+            .map_err(|error| {
+                Error::io(
+                    &error_messages::FILE_READ_FAILURE
+                        .replace("{path}", ".")
+                        .replace("{error}", &error.to_string()),
+                    error,
+                )
+            })?;
+
+        let report = if ignored_entries.is_empty() {
+            help_texts::PREVIEW_NOTHING_IGNORED.to_string()
+        } else {
+            ignored_entries
+                .iter()
+                .map(|explanation| {
+                    if explanation.is_tracked_looking() {
+                        explanation.to_report() + help_texts::PREVIEW_TRACKED_LOOKING
+                    } else {
+                        explanation.to_report()
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+
+        Ok(QualifiedString {
+            value: report,
+            kind: generated.kind,
+        })
+    }
+
+    /// Manages local templates as given by [`Args::template_command`].
+    ///
+    /// Templates added without any content source, as well as edited ones,
+    /// are opened in `$EDITOR` (falling back to
+    /// [`template_manager::DEFAULT_EDITOR`]).
+    ///
+    /// # Returns
+    ///
+    /// A result containing a summary of the performed change, or an
+    /// [`Error`] on error (e.g. template not found or already
+    /// existing, file system failure, editor failure...).
+    fn manage_template(&self, args: &Args) -> Result<QualifiedString, Error> {
+        let Some(template_command) = &args.template_command else {
+            return Ok(QualifiedString::empty(StringKind::Local));
+        };
+        let editor = LocalGitignoreTemplateManager::try_from_args(args)?;
+
+        let summary = match template_command {
+            TemplateCommand::Add { name, source } => {
+                let content = match source {
+                    TemplateSource::Editor => String::new(),
+                    TemplateSource::File(path) => Self::read_file(path)?,
+                    TemplateSource::Stdin => Self::read_stdin()?,
+                };
+                let path = editor.add(name, &content, false)?;
+                if *source == TemplateSource::Editor {
+                    Self::open_editor(&path)?;
+                }
+
+                help_texts::TEMPLATE_ADDED
+                    .replace("{name}", name)
+                    .replace("{path}", &path)
+            }
+            TemplateCommand::Import { names, force } => {
+                let remote_manager = RemoteGitignoreTemplateManager::from_args(args)?;
+
+                names
+                    .iter()
+                    .map(|name| {
+                        let template = remote_manager.generate(std::slice::from_ref(name))?;
+                        let content =
+                            RemoteGitignoreTemplateManager::strip_wrapper(&template.value);
+
+                        let path = editor.add(name, &content, *force)?;
+
+                        Ok(help_texts::TEMPLATE_IMPORTED
+                            .replace("{name}", name)
+                            .replace("{path}", &path))
+                    })
+                    .collect::<Result<Vec<String>, Error>>()?
+                    .join("\n")
+            }
+            TemplateCommand::Edit { name } => {
+                let path = editor.locate(name)?;
+                Self::open_editor(&path)?;
+
+                help_texts::TEMPLATE_EDITED
+                    .replace("{name}", name)
+                    .replace("{path}", &path)
+            }
+            TemplateCommand::Remove { name } => help_texts::TEMPLATE_REMOVED
+                .replace("{name}", name)
+                .replace("{path}", &editor.remove(name)?),
+            TemplateCommand::Rename { name, new_name } => help_texts::TEMPLATE_RENAMED
+                .replace("{name}", name)
+                .replace("{new_name}", new_name)
+                .replace("{path}", &editor.rename(name, new_name)?),
+        };
+
+        Ok(QualifiedString {
+            value: summary,
+            kind: StringKind::Local,
+        })
+    }
+
+    /// Lints the gitignore files and local templates given by
+    /// [`Args::lint_targets`].
+    ///
+    /// Every local template gets linted when neither files nor templates
+    /// are given.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the lint report if no issue was found, or a
+    /// [`Error::Mismatch`] holding the report
+    /// otherwise.
+    fn lint(&self, args: &Args) -> Result<QualifiedString, Error> {
+        let Some(lint_targets) = &args.lint_targets else {
+            return Ok(QualifiedString::empty(StringKind::Mixed));
+        };
+
+        let mut reports = lint_targets
+            .paths
+            .iter()
+            .map(|path| Ok(LintReport::lint(path, &Self::read_file(path)?)))
+            .collect::<Result<Vec<LintReport>, Error>>()?;
+
+        let lints_all_templates =
+            lint_targets.paths.is_empty() && lint_targets.template_names.is_empty();
+        if lints_all_templates || !lint_targets.template_names.is_empty() {
+            let editor = LocalGitignoreTemplateManager::try_from_args(args)?;
+            let template_names = if lints_all_templates {
+                editor.list()?.value.lines().map(String::from).collect()
+            } else {
+                lint_targets.template_names.clone()
+            };
+
+            for template_name in &template_names {
+                let path = editor.locate(template_name)?;
+                reports.push(LintReport::lint(&path, &Self::read_file(&path)?));
+            }
+        }
+
+        let report = reports
+            .iter()
+            .map(LintReport::to_report)
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        if reports.iter().all(LintReport::is_clean) {
+            Ok(QualifiedString {
+                value: report,
+                kind: StringKind::Mixed,
+            })
+        } else {
+            Err(Error::Mismatch {
+                report,
+                exit_status: exit_status::LINT_FAILURE,
+            })
+        }
+    }
+
+    /// Explains whether the paths given by [`Args::explain_targets`] are
+    /// ignored, and by which rule.
+    ///
+    /// Paths are matched against the generated templates if any template
+    /// name is given, or against a gitignore file otherwise. Paths of
+    /// existing directories are matched as such, even without a trailing
+    /// slash.
+    ///
+    /// # Returns
+    ///
+    /// A result containing one explanation per path, or an [`Error`]
+    /// if the gitignore file could not be read or the templates generated.
+    fn explain(
+        &self,
+        manager: &dyn TemplateManager,
+        args: &Args,
+    ) -> Result<QualifiedString, Error> {
+        let Some(explain_targets) = &args.explain_targets else {
+            return Ok(QualifiedString::empty(StringKind::Mixed));
+        };
+
+        let content = if explain_targets.template_names.is_empty() {
+            let file_path = explain_targets
+                .file_path
+                .as_deref()
+                .unwrap_or(path::GITIGNORE_FILE);
+            Self::read_file(file_path)?
+        } else if args.check_template_names {
+            manager
+                .generate_with_template_check(&explain_targets.template_names)?
+                .value
+        } else {
+            manager.generate(&explain_targets.template_names)?.value
+        };
+        let matcher = GitignoreMatcher::parse(&content);
+
+        let report = explain_targets
+            .paths
+            .iter()
+            .map(|path| {
+                let explanation = if !path.ends_with('/') && Path::new(path).is_dir() {
+                    matcher.explain(&format!("{path}/"))
+                } else {
+                    matcher.explain(path)
+                };
+
+                PathExplanation {
+                    path: path.to_string(),
+                    ..explanation
+                }
+                .to_report()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Ok(QualifiedString {
+            value: report,
+            kind: StringKind::Mixed,
+        })
+    }
+
+    /// Opens given file in the editor named by `$EDITOR`, waiting for it to
+    /// exit.
+    fn open_editor(path: &str) -> Result<(), Error> {
+        let editor = std::env::var(template_manager::EDITOR_ENV_VAR)
+            .ok()
+            .filter(|editor| !editor.trim().is_empty())
+            .unwrap_or_else(|| template_manager::DEFAULT_EDITOR.to_string());
+        let mut editor_parts = editor.split_whitespace();
+        let program = editor_parts
+            .next()
+            .unwrap_or(template_manager::DEFAULT_EDITOR);
+
+        let message = |error: &str| {
+            error_messages::EDITOR_FAILURE
+                .replace("{editor}", &editor)
+                .replace("{error}", error)
+        };
+
+        match Command::new(program).args(editor_parts).arg(path).status() {
+            Ok(status) if status.success() => Ok(()),
+            Ok(status) => Err(Error::TemplateEdition(message(&status.to_string()))),
+            Err(error) => Err(Error::io(&message(&error.to_string()), error)),
+        }
+    }
+
+    fn read_stdin() -> Result<String, Error> {
+        io::read_to_string(io::stdin()).map_err(|error| {
+            Error::io(
+                &error_messages::FILE_READ_FAILURE
+                    .replace("{path}", "stdin")
+                    .replace("{error}", &error.to_string()),
+                error,
+            )
+        })
+    }
+
+    fn read_file(path: &str) -> Result<String, Error> {
+        fs::read_to_string(path).map_err(|error| {
+            Error::io(
+                &error_messages::FILE_READ_FAILURE
+                    .replace("{path}", path)
+                    .replace("{error}", &error.to_string()),
+                error,
+            )
+        })
+    }
+
+    /// Generates the templates matching given template names.
+    ///
+    /// With [`Args::lockfile_path`], each template is generated on its own,
+    /// so that the templates making up the output get locked (see
+    /// [`TemplateManager::generate_sourced`]).
+    ///
+    /// # Returns
+    ///
+    /// A result containing the generated templates, or an [`Error`] on
+    /// error (e.g. 4xx, network issues, template not found, lockfile
+    /// mismatch...).
+    fn generate(
+        &self,
+        manager: &dyn TemplateManager,
+        args: &Args,
+    ) -> Result<QualifiedString, Error> {
+        let Some(path) = &args.lockfile_path else {
+            return Ok(if args.check_template_names {
+                manager.generate_with_template_check(&args.template_names)?
+            } else {
+                manager.generate(&args.template_names)?
+            });
+        };
+
+        let generation =
+            manager.generate_sourced(&args.template_names, args.check_template_names)?;
+        self.lock(
+            path,
+            &generation.templates,
+            &Layout::from(args),
+            args.locked,
+        )?;
+
+        Ok(generation.output)
+    }
+
+    /// Records given templates in the lockfile at given path.
+    ///
+    /// When locked, the lockfile is left untouched and compared with given
+    /// templates instead. Templates are hashed without the dates given
+    /// layout put in their titles (see [`Layout::undate`]), so that they
+    /// do not change from one day to the next.
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or an [`Error`] if the lockfile could not be
+    /// read or written, or if any locked template changed upstream.
+    fn lock(
+        &self,
+        path: &str,
+        templates: &[SourcedTemplate],
+        layout: &Layout,
+        locked: bool,
+    ) -> Result<(), Error> {
+        let lockfile = Lockfile::new(
+            templates
+                .iter()
+                .map(|template| {
+                    LockedTemplate::new(
+                        &template.name,
+                        template.source,
+                        &template.location,
+                        &layout.undate(&template.content),
+                    )
+                })
+                .collect(),
+        );
+
+        if !locked {
+            return lockfile.save(path);
+        }
+
+        let changed_templates = Lockfile::load(path)?.changed_templates(&lockfile);
+        if changed_templates.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::LockMismatch(
+                error_messages::LOCKED_TEMPLATES_CHANGED
+                    .replace("{templates}", &changed_templates.join(", ")),
+            ))
+        }
     }
 
     fn parse_result(
         &self,
-        result: &Result<QualifiedString, ProgramExit>,
-    ) -> Result<QualifiedString, ProgramExit> {
+        result: &Result<QualifiedString, Error>,
+    ) -> Result<QualifiedString, Error> {
         match result {
             Ok(output) if output.value.is_empty() => Ok(QualifiedString::empty(output.kind)),
             Ok(output) => Ok(output.clone()),
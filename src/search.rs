@@ -0,0 +1,42 @@
+//! Define components to search templates by name and content.
+use std::ops::Range;
+
+use regex::Regex;
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Enum for the query used to search templates.
+#[derive(Clone, Debug)]
+pub enum SearchQuery {
+    /// Case-insensitive substring query.
+    Substring(String),
+
+    /// Regular expression query.
+    Pattern(Regex),
+}
+
+/// DTO struct representing a line of a template body matching a query.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LineMatch {
+    /// The line number, starting at 1.
+    pub number: usize,
+
+    /// The line content.
+    pub line: String,
+
+    /// The byte range of the first match within the line.
+    pub range: Range<usize>,
+}
+
+/// DTO struct representing a template matching a query.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SearchMatch {
+    /// The template name.
+    pub name: String,
+
+    /// The body lines matching the query, empty if only the name matched.
+    pub lines: Vec<LineMatch>,
+}
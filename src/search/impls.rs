@@ -0,0 +1,127 @@
+use std::ops::Range;
+
+use regex::Regex;
+
+use crate::{
+    constant::{error_messages, help_texts},
+    error::Error,
+    search::{LineMatch, SearchMatch, SearchQuery},
+};
+
+/// Finds the first occurrence of given lowercase query in given text,
+/// ignoring case.
+///
+/// Text chars are lowercased one at a time rather than the whole text at
+/// once, as lowercasing may change the byte length of non-ascii chars, so
+/// that the match is located on char boundaries of the original text.
+fn find_ignoring_case(text: &str, query: &str) -> Option<Range<usize>> {
+    if query.is_empty() {
+        return Some(0..0);
+    }
+
+    text.char_indices().find_map(|(start, _)| {
+        let mut lowercase_text = String::new();
+
+        for (offset, c) in text[start..].char_indices() {
+            lowercase_text.extend(c.to_lowercase());
+
+            if lowercase_text.len() >= query.len() {
+                return lowercase_text
+                    .starts_with(query)
+                    .then_some(start..start + offset + c.len_utf8());
+            }
+            if !query.starts_with(lowercase_text.as_str()) {
+                return None;
+            }
+        }
+
+        None
+    })
+}
+
+impl SearchQuery {
+    /// Builds a search query.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query text.
+    /// * `is_pattern` - Whether to handle the query as a regular expression
+    ///   rather than a case-insensitive substring.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the search query, or an [`Error`] if the
+    /// query is not a valid regular expression.
+    pub fn new(query: &str, is_pattern: bool) -> Result<Self, Error> {
+        if !is_pattern {
+            return Ok(Self::Substring(query.to_lowercase()));
+        }
+
+        Regex::new(query).map(Self::Pattern).map_err(|error| {
+            Error::InvalidInput(
+                error_messages::INVALID_SEARCH_PATTERN.replace("{error}", &error.to_string()),
+            )
+        })
+    }
+
+    /// Finds the first match of this query in given text.
+    ///
+    /// # Returns
+    ///
+    /// The byte range of the first match, or `None` if the text does not
+    /// match.
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        match self {
+            Self::Substring(query) => find_ignoring_case(text, query),
+            Self::Pattern(regex) => regex.find(text).map(|found| found.range()),
+        }
+    }
+
+    /// Finds the lines of given template body matching this query.
+    pub fn find_lines(&self, body: &str) -> Vec<LineMatch> {
+        body.lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                self.find(line).map(|range| LineMatch {
+                    number: index + 1,
+                    line: line.to_string(),
+                    range,
+                })
+            })
+            .collect()
+    }
+}
+
+impl LineMatch {
+    /// Formats this line match, optionally highlighting the matching part
+    /// with ANSI escape codes.
+    pub fn to_report(&self, highlight: bool) -> String {
+        let line = if highlight {
+            format!(
+                "{}{}{}",
+                &self.line[..self.range.start],
+                help_texts::SEARCH_HIGHLIGHT.replace("{match}", &self.line[self.range.clone()]),
+                &self.line[self.range.end..]
+            )
+        } else {
+            self.line.clone()
+        };
+
+        help_texts::SEARCH_LINE
+            .replace("{number}", &self.number.to_string())
+            .replace("{line}", &line)
+    }
+}
+
+impl SearchMatch {
+    /// Formats this search match as its template name followed by its
+    /// matching lines, if any.
+    pub fn to_report(&self, highlight: bool) -> String {
+        let mut report = self.name.clone();
+        self.lines.iter().for_each(|line_match| {
+            report.push('\n');
+            report.push_str(&line_match.to_report(highlight));
+        });
+        report
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    constant::error_messages,
+    error::Error,
+    search::{LineMatch, SearchMatch, SearchQuery},
+};
+
+mod search_query {
+    use super::*;
+
+    mod new {
+        use super::*;
+
+        mod failure {
+            use super::*;
+
+            #[test]
+            fn it_fails_when_invalid_pattern() {
+                let actual = SearchQuery::new("py(", true).unwrap_err();
+
+                assert!(matches!(
+                    actual,
+                    Error::InvalidInput(message)
+                        if message.starts_with(
+                            &error_messages::INVALID_SEARCH_PATTERN.replace("{error}", "")
+                        )
+                ));
+            }
+        }
+    }
+
+    mod find {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_finds_substring_ignoring_case() {
+                let query = SearchQuery::new("PY", false).unwrap();
+
+                let actual = query.find("python");
+                let expected = Some(0..2);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_finds_substring_of_non_ascii_text_on_char_boundaries() {
+                let query = SearchQuery::new("cache", false).unwrap();
+
+                let actual = query.find("# Ⱥrchives ÉTÉ: CACHE/");
+                let expected = Some(19..24);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_finds_pattern() {
+                let query = SearchQuery::new(r"\.py[cod]", true).unwrap();
+
+                let actual = query.find("*.pyc");
+                let expected = Some(1..5);
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_returns_none_when_no_match() {
+                let query = SearchQuery::new("rust", false).unwrap();
+
+                let actual = query.find("python");
+                let expected = None;
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    mod find_lines {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_finds_matching_lines_with_their_number() {
+                let query = SearchQuery::new("*.pyc", false).unwrap();
+
+                let actual = query.find_lines("__pycache__/\n*.pyc\n*.pyo");
+                let expected = vec![LineMatch {
+                    number: 2,
+                    line: String::from("*.pyc"),
+                    range: 0..5,
+                }];
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
+
+mod search_match {
+    use super::*;
+
+    mod to_report {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_formats_name_and_lines() {
+                let search_match = SearchMatch {
+                    name: String::from("python"),
+                    lines: vec![LineMatch {
+                        number: 2,
+                        line: String::from("*.pyc"),
+                        range: 1..5,
+                    }],
+                };
+
+                let actual = search_match.to_report(false);
+                let expected = "python\n  2: *.pyc";
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn it_highlights_matching_part() {
+                let search_match = SearchMatch {
+                    name: String::from("python"),
+                    lines: vec![LineMatch {
+                        number: 2,
+                        line: String::from("*.pyc"),
+                        range: 1..5,
+                    }],
+                };
+
+                let actual = search_match.to_report(true);
+                let expected = "python\n  2: *\u{1b}[1;31m.pyc\u{1b}[0m";
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
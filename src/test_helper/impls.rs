@@ -85,11 +85,19 @@ impl TestUtils for DefaultTestUtils {
     }
 
     fn get_help_message() -> String {
-        parse_expectation_file_to_help_message("help_message")
+        if cfg!(feature = "local_templating") {
+            parse_expectation_file_to_help_message("local_help_message")
+        } else {
+            parse_expectation_file_to_help_message("help_message")
+        }
     }
 
     fn get_ansi_help_message() -> String {
-        parse_expectation_file_to_help_message("ansi_help_message")
+        if cfg!(feature = "local_templating") {
+            parse_expectation_file_to_help_message("ansi_local_help_message")
+        } else {
+            parse_expectation_file_to_help_message("ansi_help_message")
+        }
     }
 }
 
@@ -141,6 +149,12 @@ fn parse_expectation_file_to_help_message(template_name: &str) -> String {
         .replace("{pkg_name}", env!("CARGO_PKG_NAME"))
         .replace("{about}", parser_infos::ABOUT)
         .replace("{template_names_desc}", help_messages::TEMPLATE_NAMES)
+        .replace("{template_command_desc}", help_messages::TEMPLATE_COMMAND)
+        .replace("{lint_command_desc}", help_messages::LINT_COMMAND)
+        .replace("{explain_command_desc}", help_messages::EXPLAIN_COMMAND)
+        .replace("{search_command_desc}", help_messages::SEARCH_COMMAND)
+        .replace("{show_command_desc}", help_messages::SHOW_COMMAND)
+        .replace("{verify_command_desc}", help_messages::VERIFY_COMMAND)
         .replace("{author_desc}", help_messages::AUTHOR)
         .replace("{server_url_desc}", help_messages::SERVER_URL)
         .replace("{help_desc}", help_messages::HELP)
@@ -167,19 +181,85 @@ fn parse_expectation_file_to_help_message(template_name: &str) -> String {
         .replace("{lister_uri_long}", cli_options::LISTER_URI.long)
         .replace("{lister_uri_desc}", help_messages::LISTER_URI)
         .replace("{lister_uri_default}", template_manager::LISTER_URI)
+        .replace("{api_dialect_long}", cli_options::API_DIALECT.long)
+        .replace("{api_dialect_desc}", help_messages::API_DIALECT)
+        .replace("{api_dialect_default}", template_manager::API_DIALECT)
+        .replace("{api_dialect_values}", "toptal, json, per-template")
         .replace("{check_short}", cli_options::CHECK.short)
         .replace("{check_long}", cli_options::CHECK.long)
         .replace("{check_desc}", help_messages::CHECK)
         .replace("{timeout_short}", cli_options::TIMEOUT.short)
         .replace("{timeout_long}", cli_options::TIMEOUT.long)
         .replace("{timeout_desc}", help_messages::TIMEOUT)
+        .replace("{timeout_default}", template_manager::TIMEOUT)
+        .replace("{connect_timeout_long}", cli_options::CONNECT_TIMEOUT.long)
+        .replace("{connect_timeout_desc}", help_messages::CONNECT_TIMEOUT)
+        .replace("{read_timeout_long}", cli_options::READ_TIMEOUT.long)
+        .replace("{read_timeout_desc}", help_messages::READ_TIMEOUT)
+        .replace("{cache_long}", cli_options::CACHE.long)
+        .replace("{cache_desc}", help_messages::CACHE)
+        .replace("{diff_long}", cli_options::DIFF.long)
+        .replace("{diff_desc}", help_messages::DIFF)
+        .replace("{lockfile_long}", cli_options::LOCKFILE.long)
+        .replace("{lockfile_desc}", help_messages::LOCKFILE)
+        .replace("{locked_long}", cli_options::LOCKED.long)
+        .replace("{locked_desc}", help_messages::LOCKED)
+        .replace("{section_header_long}", cli_options::SECTION_HEADER.long)
+        .replace("{section_header_desc}", help_messages::SECTION_HEADER)
+        .replace(
+            "{section_header_default}",
+            &format!("\"{}\"", template_manager::SECTION_HEADER),
+        )
+        .replace("{template_title_long}", cli_options::TEMPLATE_TITLE.long)
+        .replace("{template_title_desc}", help_messages::TEMPLATE_TITLE)
+        .replace(
+            "{template_title_default}",
+            &format!("\"{}\"", template_manager::TEMPLATE_TITLE),
+        )
+        .replace("{no_headers_long}", cli_options::NO_HEADERS.long)
+        .replace("{no_headers_desc}", help_messages::NO_HEADERS)
+        .replace("{banner_long}", cli_options::BANNER.long)
+        .replace("{banner_desc}", help_messages::BANNER)
+        .replace(
+            "{trailing_newline_long}",
+            cli_options::TRAILING_NEWLINE.long,
+        )
+        .replace("{trailing_newline_desc}", help_messages::TRAILING_NEWLINE)
+        .replace(
+            "{trailing_newline_default}",
+            template_manager::TRAILING_NEWLINE,
+        )
+        .replace("{trailing_newline_values}", "always, never")
+        .replace("{prefer_long}", cli_options::PREFER.long)
+        .replace("{prefer_desc}", help_messages::PREFER)
+        .replace("{prefer_default}", template_manager::PREFER)
+        .replace("{prefer_values}", "local, remote, both, merge")
+        .replace("{order_long}", cli_options::ORDER.long)
+        .replace("{order_desc}", help_messages::ORDER)
+        .replace("{order_default}", template_manager::ORDER)
+        .replace("{order_values}", "given, alphabetical, source")
+        .replace("{strict_long}", cli_options::STRICT.long)
+        .replace("{strict_desc}", help_messages::STRICT)
+        .replace("{template_ext_long}", cli_options::TEMPLATE_EXTENSION.long)
+        .replace("{template_ext_desc}", help_messages::TEMPLATE_EXTENSION)
         .replace(
-            "{timeout_default}",
-            &DefaultDataPrinter::ppg(&Data::DefaultTimeout()),
+            "{template_ext_default}",
+            template_manager::TEMPLATE_EXTENSION,
         )
-        .replace("{timeout_unit_short}", cli_options::TIMEOUT_UNIT.short)
-        .replace("{timeout_unit_long}", cli_options::TIMEOUT_UNIT.long)
-        .replace("{timeout_unit_desc}", help_messages::TIMEOUT_UNIT)
-        .replace("{timeout_unit_default}", template_manager::TIMEOUT_UNIT)
-        .replace("{timeout_unit_values}", "millisecond, second")
+        .replace("{exclude_long}", cli_options::EXCLUDE.long)
+        .replace("{exclude_desc}", help_messages::EXCLUDE)
+        .replace("{preview_long}", cli_options::PREVIEW.long)
+        .replace("{preview_desc}", help_messages::PREVIEW)
+        .replace("{verbose_short}", cli_options::VERBOSE.short)
+        .replace("{verbose_long}", cli_options::VERBOSE.long)
+        .replace("{verbose_desc}", help_messages::VERBOSE)
+        .replace("{quiet_short}", cli_options::QUIET.short)
+        .replace("{quiet_long}", cli_options::QUIET.long)
+        .replace("{quiet_desc}", help_messages::QUIET)
+        .replace("{color_long}", cli_options::COLOR.long)
+        .replace("{color_desc}", help_messages::COLOR)
+        .replace("{color_default}", template_manager::COLOR)
+        .replace("{color_values}", "auto, always, never")
+        .replace("{no_pager_long}", cli_options::NO_PAGER.long)
+        .replace("{no_pager_desc}", help_messages::NO_PAGER)
 }
@@ -1,4 +1,6 @@
 //! Define components to validate cli args.
+use std::time::Duration;
+
 pub use crate::validator::impls::DefaultCliArgsValidator;
 
 mod impls;
@@ -81,4 +83,36 @@ pub trait CliArgsValidator {
     /// A result containing the provided value if valid url, or an error
     /// containing proper error message otherwise.
     fn is_valid_url(value: &str) -> Result<String, String>;
+
+    /// Checks if given value is a valid duration.
+    ///
+    /// A valid duration is an unsigned integer followed by an optional unit
+    /// among `ms` (milliseconds), `s` (seconds) or `m` (minutes). A value
+    /// without unit is considered as seconds.
+    ///
+    /// # Arguments
+    ///
+    /// `value` - The value to be checked
+    ///
+    /// # Returns
+    ///
+    /// A result containing the parsed duration if valid, or an error
+    /// containing proper error message otherwise.
+    fn is_valid_duration(value: &str) -> Result<Duration, String>;
+
+    /// Checks if given value is a valid gitignore pattern.
+    ///
+    /// A pattern is invalid if it can never match any path (e.g. an
+    /// unclosed or reversed character class), as told by
+    /// [`crate::matcher::GitignoreRule::is_valid`].
+    ///
+    /// # Arguments
+    ///
+    /// `value` - The value to be checked
+    ///
+    /// # Returns
+    ///
+    /// A result containing the provided value if valid pattern, or an error
+    /// containing proper error message otherwise.
+    fn is_valid_pattern(value: &str) -> Result<String, String>;
 }
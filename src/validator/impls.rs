@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use url::Url;
 
-use crate::{constant, validator::CliArgsValidator};
+use crate::{constant, matcher::GitignoreRule, validator::CliArgsValidator};
 
 /// Default implementation of cli args validator.
 ///
@@ -11,6 +13,21 @@ impl DefaultCliArgsValidator {
     fn has_valid_scheme(url: &Url) -> bool {
         url.scheme() == "http" || url.scheme() == "https"
     }
+
+    fn parse_duration(value: &str) -> Option<Duration> {
+        let unit_idx = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (amount, unit) = value.split_at(unit_idx);
+        let amount: u64 = amount.parse().ok()?;
+
+        match unit {
+            "ms" => Some(Duration::from_millis(amount)),
+            "" | "s" => Some(Duration::from_secs(amount)),
+            "m" => amount.checked_mul(60).map(Duration::from_secs),
+            _ => None,
+        }
+    }
 }
 
 impl CliArgsValidator for DefaultCliArgsValidator {
@@ -62,4 +79,20 @@ impl CliArgsValidator for DefaultCliArgsValidator {
             Err(_) => Err(constant::error_messages::INVALID_URL.to_string()),
         }
     }
+
+    fn is_valid_duration(value: &str) -> Result<Duration, String> {
+        match Self::parse_duration(value) {
+            Some(duration) => Ok(duration),
+            None => Err(constant::error_messages::INVALID_DURATION.to_string()),
+        }
+    }
+
+    fn is_valid_pattern(value: &str) -> Result<String, String> {
+        match GitignoreRule::parse(1, value, None) {
+            Some(rule) if !rule.is_valid() => {
+                Err(constant::error_messages::INVALID_PATTERN.to_string())
+            }
+            _ => Ok(value.to_string()),
+        }
+    }
 }
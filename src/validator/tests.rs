@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use rstest::*;
 
 use super::*;
@@ -196,4 +198,75 @@ mod default_cli_args_validator {
             }
         }
     }
+
+    mod is_valid_duration {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case("5", Duration::from_secs(5))]
+            #[case("2s", Duration::from_secs(2))]
+            #[case("1500ms", Duration::from_millis(1500))]
+            #[case("1m", Duration::from_secs(60))]
+            fn it_returns_ok_for_valid_duration(#[case] value: &str, #[case] expected: Duration) {
+                let expected: Result<Duration, String> = Ok(expected);
+                let actual = DefaultCliArgsValidator::is_valid_duration(value);
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[rstest]
+            #[case("")]
+            #[case("ms")]
+            #[case("-1")]
+            #[case("5h")]
+            #[case("1.5s")]
+            fn it_returns_error_for_invalid_duration(#[case] value: &str) {
+                let expected: Result<Duration, String> =
+                    Err(constant::error_messages::INVALID_DURATION.to_string());
+                let actual = DefaultCliArgsValidator::is_valid_duration(value);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+    mod is_valid_pattern {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[rstest]
+            #[case("drafts/*")]
+            #[case("READ?E")]
+            #[case("debug[!0-9].log")]
+            fn it_returns_ok_for_valid_pattern(#[case] value: &str) {
+                let expected: Result<String, String> = Ok(value.to_string());
+                let actual = DefaultCliArgsValidator::is_valid_pattern(value);
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        mod failure {
+            use super::*;
+
+            #[rstest]
+            #[case("debug[0-9.log")]
+            #[case("debug[9-0].log")]
+            fn it_returns_error_for_invalid_pattern(#[case] value: &str) {
+                let expected: Result<String, String> =
+                    Err(constant::error_messages::INVALID_PATTERN.to_string());
+                let actual = DefaultCliArgsValidator::is_valid_pattern(value);
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
 }
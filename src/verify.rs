@@ -0,0 +1,52 @@
+//! Define components to verify gitignore files against templates.
+use std::collections::HashSet;
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+/// Enum for the status of a template within a gitignore file.
+#[derive(Clone, PartialEq, Debug)]
+pub enum TemplateStatus {
+    /// The template section is present, along with all the template rules.
+    UpToDate,
+
+    /// No section is named after the template, but all the template rules
+    /// are present.
+    MatchedByRules,
+
+    /// The template section is present, but some template rules are not.
+    ///
+    /// The `missing_rules` field holds the template rules absent from the
+    /// gitignore file, in template order.
+    Outdated { missing_rules: Vec<String> },
+
+    /// Neither the template section nor all the template rules are present.
+    Missing,
+}
+
+/// DTO struct representing the verification result of a template.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TemplateVerification {
+    /// The verified template name.
+    pub name: String,
+
+    /// The status of the template within the gitignore file.
+    pub status: TemplateStatus,
+}
+
+/// DTO struct representing a parsed gitignore file.
+///
+/// Sections are identified by the `### Name ###` headers emitted by the
+/// template managers, and rules by any line being neither empty nor a
+/// comment.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GitignoreFile {
+    /// The normalized names of the sections (i.e. lowercase, without
+    /// whitespaces nor local template marker).
+    pub sections: HashSet<String>,
+
+    /// The rules of the file, trimmed.
+    pub rules: HashSet<String>,
+}
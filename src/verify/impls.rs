@@ -0,0 +1,102 @@
+use crate::{
+    constant::help_texts,
+    verify::{GitignoreFile, TemplateStatus, TemplateVerification},
+};
+
+/// Iterates over the rules of given gitignore content (i.e. its trimmed
+/// lines that are neither empty nor a comment).
+fn rules(content: &str) -> impl Iterator<Item = &str> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn normalize_name(name: &str) -> String {
+    name.trim_start_matches('*')
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+impl GitignoreFile {
+    /// Parses given gitignore content into its sections and rules.
+    pub fn parse(content: &str) -> Self {
+        Self {
+            sections: content
+                .lines()
+                .filter_map(|line| {
+                    line.trim()
+                        .strip_prefix("### ")
+                        .and_then(|line| line.strip_suffix(" ###"))
+                })
+                .map(normalize_name)
+                .collect(),
+            rules: rules(content).map(String::from).collect(),
+        }
+    }
+
+    /// Verifies the presence of a template within this gitignore file.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_name` - The name of the template to be verified.
+    /// * `template` - The reference content of the template, as generated
+    ///   by a template manager.
+    ///
+    /// # Returns
+    ///
+    /// The verification result of the template.
+    pub fn verify(&self, template_name: &str, template: &str) -> TemplateVerification {
+        let has_section = self.sections.contains(&normalize_name(template_name));
+        let missing_rules: Vec<String> = rules(template)
+            .filter(|rule| !self.rules.contains(*rule))
+            .map(String::from)
+            .collect();
+
+        let status = match (has_section, missing_rules.is_empty()) {
+            (true, true) => TemplateStatus::UpToDate,
+            (false, true) => TemplateStatus::MatchedByRules,
+            (true, false) => TemplateStatus::Outdated { missing_rules },
+            (false, false) => TemplateStatus::Missing,
+        };
+
+        TemplateVerification {
+            name: template_name.to_string(),
+            status,
+        }
+    }
+}
+
+impl TemplateVerification {
+    /// Whether the template is considered present in the gitignore file.
+    pub fn is_present(&self) -> bool {
+        matches!(
+            self.status,
+            TemplateStatus::UpToDate | TemplateStatus::MatchedByRules
+        )
+    }
+
+    /// Formats this verification result as a report entry.
+    ///
+    /// Outdated templates list their missing rules on the following lines.
+    pub fn to_report(&self) -> String {
+        match &self.status {
+            TemplateStatus::UpToDate => help_texts::VERIFY_UP_TO_DATE.replace("{name}", &self.name),
+            TemplateStatus::MatchedByRules => {
+                help_texts::VERIFY_MATCHED_BY_RULES.replace("{name}", &self.name)
+            }
+            TemplateStatus::Outdated { missing_rules } => {
+                let mut report = help_texts::VERIFY_OUTDATED
+                    .replace("{name}", &self.name)
+                    .replace("{count}", &missing_rules.len().to_string());
+                missing_rules.iter().for_each(|rule| {
+                    report.push_str(&help_texts::VERIFY_MISSING_RULE.replace("{rule}", rule))
+                });
+                report
+            }
+            TemplateStatus::Missing => help_texts::VERIFY_MISSING.replace("{name}", &self.name),
+        }
+    }
+}
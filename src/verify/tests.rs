@@ -0,0 +1,99 @@
+use crate::verify::{GitignoreFile, TemplateStatus, TemplateVerification};
+
+const GITIGNORE: &str = "# Created by https://www.toptal.com/developers/gitignore/api/rust\n\n### Rust ###\ndebug/\ntarget/\n\n## LOCAL\n\n### *Node ###\nnode_modules/\n";
+
+mod gitignore_file {
+    use super::*;
+
+    mod parse {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_parses_sections_and_rules() {
+                let actual = GitignoreFile::parse(GITIGNORE);
+
+                assert_eq!(actual.sections.len(), 2);
+                assert!(actual.sections.contains("rust"));
+                assert!(actual.sections.contains("node"));
+                assert_eq!(actual.rules.len(), 3);
+                assert!(actual.rules.contains("node_modules/"));
+            }
+        }
+    }
+
+    mod verify {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_reports_up_to_date_template() {
+                let actual =
+                    GitignoreFile::parse(GITIGNORE).verify("rust", "### Rust ###\ntarget/");
+                let expected = TemplateStatus::UpToDate;
+
+                assert_eq!(actual.status, expected);
+            }
+
+            #[test]
+            fn it_reports_template_matched_by_rules() {
+                let actual =
+                    GitignoreFile::parse(GITIGNORE).verify("cargo", "### Cargo ###\ntarget/");
+                let expected = TemplateStatus::MatchedByRules;
+
+                assert_eq!(actual.status, expected);
+            }
+
+            #[test]
+            fn it_reports_outdated_template_with_missing_rules() {
+                let actual = GitignoreFile::parse(GITIGNORE)
+                    .verify("rust", "### Rust ###\ntarget/\nCargo.lock\n*.pdb");
+                let expected = TemplateStatus::Outdated {
+                    missing_rules: vec![String::from("Cargo.lock"), String::from("*.pdb")],
+                };
+
+                assert_eq!(actual.status, expected);
+            }
+
+            #[test]
+            fn it_reports_missing_template() {
+                let actual = GitignoreFile::parse(GITIGNORE)
+                    .verify("python", "### Python ###\n__pycache__/");
+                let expected = TemplateStatus::Missing;
+
+                assert_eq!(actual.status, expected);
+            }
+        }
+    }
+}
+
+mod template_verification {
+    use super::*;
+
+    mod to_report {
+        use super::*;
+
+        mod success {
+            use super::*;
+
+            #[test]
+            fn it_lists_missing_rules_of_outdated_template() {
+                let verification = TemplateVerification {
+                    name: String::from("rust"),
+                    status: TemplateStatus::Outdated {
+                        missing_rules: vec![String::from("Cargo.lock")],
+                    },
+                };
+
+                let actual = verification.to_report();
+                let expected = "rust: outdated, 1 rule(s) missing\n  - Cargo.lock";
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
@@ -153,6 +153,10 @@ mod success {
             let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
 
             cli_tool.arg(format!("-{}", constant::cli_options::HELP.short));
+            cli_tool.args([
+                format!("--{}", constant::cli_options::COLOR.long),
+                String::from("always"),
+            ]);
             let result = cli_tool
                 .output()
                 .expect(constant::error_messages::CMD_EXECUTION_FAILURE);
@@ -312,7 +316,7 @@ mod success {
             if #[cfg(feature = "local_templating")] {
                 #[rstest]
                 #[serial]
-                fn it_outputs_gitignore_templates_from_api_with_timeout_unit_option(
+                fn it_outputs_gitignore_templates_from_api_with_granular_timeout_options(
                     _ctx: EnvTestContext,
                 ) {
                     let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
@@ -324,7 +328,7 @@ mod success {
                     );
 
                     cli_tool.args(DefaultTestUtils::parse_cli_args(
-                        "rust python --timeout 5000 --timeout-unit millisecond",
+                        "rust python --timeout 5000ms --connect-timeout 2s --read-timeout 2s",
                     ));
                     let result = cli_tool
                         .output()
@@ -340,11 +344,11 @@ mod success {
             } else {
                 #[test]
                 #[parallel]
-                fn it_outputs_gitignore_templates_from_api_with_timeout_unit_option() {
+                fn it_outputs_gitignore_templates_from_api_with_granular_timeout_options() {
                     let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
 
                     cli_tool.args(DefaultTestUtils::parse_cli_args(
-                        "rust python --timeout 5000 --timeout-unit millisecond",
+                        "rust python --timeout 5000ms --connect-timeout 2s --read-timeout 2s",
                     ));
                     let result = cli_tool
                         .output()
@@ -376,7 +380,7 @@ mod failure {
                     _ctx: EnvTestContext,
                 ) {
                     let pos_args = "";
-                    let expectation_file_name = "ansi_no_pos_args_error";
+                    let expectation_file_name = "no_pos_args_error";
 
                     let mut cli_tools = get_test_bin(env!("CARGO_PKG_NAME"));
                     let template_dir = DefaultTestUtils::get_resource_file_path("templates");
@@ -408,7 +412,7 @@ mod failure {
                     _ctx: EnvTestContext,
                 ) {
                     let pos_args = "rust python,java";
-                    let expectation_file_name = "ansi_comma_pos_args_error";
+                    let expectation_file_name = "comma_pos_args_error";
 
                     let mut cli_tools = get_test_bin(env!("CARGO_PKG_NAME"));
                     let template_dir = DefaultTestUtils::get_resource_file_path("templates");
@@ -469,8 +473,8 @@ mod failure {
             } else {
                 #[rstest]
                 #[parallel]
-                #[case("", "ansi_no_pos_args_error")]
-                #[case("rust python,java", "ansi_comma_pos_args_error")]
+                #[case("", "no_pos_args_error")]
+                #[case("rust python,java", "comma_pos_args_error")]
                 #[case("foo", "template_not_found_error")]
                 fn it_outputs_error_and_fails_when_invalid_pos_args(
                     #[case] pos_args: &str,
@@ -520,7 +524,7 @@ mod failure {
                     DefaultTestUtils::load_expectation_file("server_not_found_error") + "\n";
 
                 let actual_status_code = result.status.code();
-                let expected_status_code = Some(constant::exit_status::GENERIC);
+                let expected_status_code = Some(constant::exit_status::DNS_FAILURE);
 
                 assert_eq!(actual_status_code, expected_status_code);
                 assert_eq!(actual_output, expected_output);
@@ -1,5 +1,5 @@
 #[cfg(feature = "local_templating")]
-use std::{fs, path::Path};
+use std::{fs, io::Write, path::Path, process::Stdio};
 use std::{thread, time::Duration};
 
 #[cfg(feature = "local_templating")]
@@ -109,6 +109,317 @@ mod success {
                 }
             }
         }
+
+        #[test]
+        #[parallel]
+        #[cfg(feature = "local_templating")]
+        fn it_adds_local_template_read_from_stdin() {
+            let template_dir = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_templates_{}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&template_dir);
+
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+            cli_tool
+                .env(template_manager::HOME_ENV_VAR, &template_dir)
+                .args(["template", "add", "tools/vim", "--from-stdin"])
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped());
+            let mut child = cli_tool
+                .spawn()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            child
+                .stdin
+                .take()
+                .expect(error_messages::CMD_EXECUTION_FAILURE)
+                .write_all(b"*.swp\n")
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            let result = child
+                .wait_with_output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+            let template_path = template_dir.join("tools/vim.txt");
+            let actual_content = std::fs::read_to_string(&template_path).unwrap_or_default();
+            std::fs::remove_dir_all(&template_dir).unwrap();
+
+            let actual_output = String::from_utf8_lossy(&result.stdout);
+            let expected_output = format!(
+                "Added template tools/vim to {}\n",
+                template_path.to_string_lossy()
+            );
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::SUCCESS);
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_output, expected_output);
+            assert_eq!(actual_content, "*.swp\n");
+        }
+
+        #[test]
+        #[parallel]
+        fn it_explains_paths_against_gitignore_file() {
+            let gitignore_path = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_explained_{}.gitignore",
+                std::process::id()
+            ));
+            std::fs::write(&gitignore_path, "### Rust ###\ntarget/\n*.log\n!keep.log\n").unwrap();
+
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+            cli_tool
+                .args(["explain", "target/debug", "keep.log", "src/main.rs"])
+                .args(["--file", &gitignore_path.to_string_lossy()]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            std::fs::remove_file(&gitignore_path).unwrap();
+
+            let actual_output = String::from_utf8_lossy(&result.stdout);
+            let expected_output = concat!(
+                "target/debug: ignored since its parent directory target is ignored by 'target/' at line 2 of template Rust\n",
+                "keep.log: not ignored, re-included by '!keep.log' at line 4 of template Rust\n",
+                "src/main.rs: not ignored, no rule matches\n",
+            );
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::SUCCESS);
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_output, expected_output);
+        }
+
+        #[test]
+        #[parallel]
+        fn it_prints_long_output_directly_when_not_a_terminal() {
+            let gitignore_path = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_paged_{}.gitignore",
+                std::process::id()
+            ));
+            std::fs::write(&gitignore_path, "target/\n").unwrap();
+
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+            cli_tool
+                .env(template_manager::PAGER_ENV_VAR, "false")
+                .env(template_manager::LINES_ENV_VAR, "1")
+                .args(["explain", "target", "src"])
+                .args(["--file", &gitignore_path.to_string_lossy()]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            std::fs::remove_file(&gitignore_path).unwrap();
+
+            let actual_output = String::from_utf8_lossy(&result.stdout);
+            let expected_output = concat!(
+                "target: ignored by 'target/' at line 1\n",
+                "src: not ignored, no rule matches\n",
+            );
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::SUCCESS);
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_output, expected_output);
+        }
+
+        #[test]
+        #[parallel]
+        #[cfg(feature = "local_templating")]
+        fn it_logs_template_directory_once_when_verbose() {
+            let template_dir = DefaultTestUtils::get_resource_file_path("templates");
+            let lockfile_path = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_logged_lock_{}",
+                std::process::id()
+            ));
+
+            let mut mock_server = Server::new();
+            let mock_server_base_url = mock_server.url();
+            let template_lister_mock = mock_server
+                .mock("GET", template_manager::LISTER_URI)
+                .with_status(200)
+                .with_body("go")
+                .create();
+
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+            cli_tool
+                .env(template_manager::HOME_ENV_VAR, &template_dir)
+                .args(["rust", "python", "--verbose"])
+                .args(["--server-url", &mock_server_base_url])
+                .arg("--lockfile")
+                .arg(&lockfile_path);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            std::fs::remove_file(&lockfile_path).unwrap();
+
+            let actual_error_message = String::from_utf8_lossy(&result.stderr);
+            let expected_log_message = constant::log_messages::TEMPLATE_DIR_FROM_ENV
+                .replace("{path}", &template_dir)
+                .replace("{name}", template_manager::HOME_ENV_VAR);
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::SUCCESS);
+
+            template_lister_mock.assert();
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(
+                actual_error_message
+                    .lines()
+                    .filter(|line| line.ends_with(&expected_log_message))
+                    .count(),
+                1
+            );
+        }
+
+        #[test]
+        #[parallel]
+        #[cfg(feature = "local_templating")]
+        fn it_imports_remote_template_without_api_comments() {
+            let template_dir = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_imported_templates_{}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&template_dir);
+
+            let mut mock_server = Server::new();
+            let mock_server_base_url = mock_server.url();
+            let template_generator_mock = mock_server
+                .mock(
+                    "GET",
+                    format!("{}/rust", template_manager::GENERATOR_URI).as_str(),
+                )
+                .with_status(200)
+                .with_body(DefaultTestUtils::load_expectation_file("rust_template"))
+                .create();
+
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+            cli_tool
+                .env(template_manager::HOME_ENV_VAR, &template_dir)
+                .args(["--server-url", &mock_server_base_url])
+                .args(["template", "import", "rust"]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+            let actual_content =
+                std::fs::read_to_string(template_dir.join("rust.txt")).unwrap_or_default();
+            std::fs::remove_dir_all(&template_dir).unwrap();
+
+            let expected_content = DefaultTestUtils::load_resource_file("templates/rust.txt");
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::SUCCESS);
+
+            template_generator_mock.assert();
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_content.trim_end(), expected_content.trim_end());
+        }
+
+        #[test]
+        #[parallel]
+        #[cfg(feature = "local_templating")]
+        fn it_generates_imported_template_as_local_one() {
+            let template_dir = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_imported_generated_templates_{}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&template_dir);
+
+            let mut mock_server = Server::new();
+            let mock_server_base_url = mock_server.url();
+            let template_generator_mock = mock_server
+                .mock(
+                    "GET",
+                    format!("{}/rust", template_manager::GENERATOR_URI).as_str(),
+                )
+                .with_status(200)
+                .with_body(DefaultTestUtils::load_expectation_file("rust_template"))
+                .create();
+            let template_lister_mock = mock_server
+                .mock("GET", template_manager::LISTER_URI)
+                .with_status(200)
+                .with_body("rust")
+                .create();
+
+            let import_result = get_test_bin(env!("CARGO_PKG_NAME"))
+                .env(template_manager::HOME_ENV_VAR, &template_dir)
+                .args(["--server-url", &mock_server_base_url])
+                .args(["template", "import", "rust"])
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            let result = get_test_bin(env!("CARGO_PKG_NAME"))
+                .env(template_manager::HOME_ENV_VAR, &template_dir)
+                .args(["--server-url", &mock_server_base_url])
+                .args(["rust", "--prefer", "local"])
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            std::fs::remove_dir_all(&template_dir).unwrap();
+
+            let actual_output = String::from_utf8_lossy(&result.stdout);
+            let expected_output = format!(
+                "## LOCAL\n\n### *Rust ###\n{}\n",
+                DefaultTestUtils::load_resource_file("templates/rust.txt").trim_end()
+            );
+
+            let actual_status_code = (import_result.status.code(), result.status.code());
+            let expected_status_code = (Some(exit_status::SUCCESS), Some(exit_status::SUCCESS));
+
+            template_generator_mock.assert();
+            template_lister_mock.assert();
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_output, expected_output);
+        }
+
+        #[test]
+        #[parallel]
+        #[cfg(feature = "local_templating")]
+        fn it_outputs_matching_local_template_bodies_with_search_subcommand() {
+            let template_dir = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_searched_templates_{}",
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&template_dir);
+            fs::create_dir_all(&template_dir).expect("Error creating template directory");
+            fs::write(template_dir.join("python.txt"), "__pycache__/\n*.pyc\n")
+                .expect("Error writing local template");
+
+            let mut mock_server = Server::new();
+            let mock_server_base_url = mock_server.url();
+            let template_lister_mock = mock_server
+                .mock("GET", template_manager::LISTER_URI)
+                .with_status(200)
+                .with_body("python,rust")
+                .create();
+            let template_generator_mock = mock_server
+                .mock("GET", mockito::Matcher::Any)
+                .expect(0)
+                .create();
+
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+            cli_tool
+                .env(template_manager::HOME_ENV_VAR, &template_dir)
+                .args(["--server-url", &mock_server_base_url])
+                .args(["search", "*.pyc", "--content"]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            fs::remove_dir_all(&template_dir).unwrap();
+
+            let actual_output = String::from_utf8_lossy(&result.stdout);
+            let expected_output = "python\n  3: *.pyc\n";
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::SUCCESS);
+
+            template_lister_mock.assert();
+            template_generator_mock.assert();
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_output, expected_output);
+        }
     }
 
     mod pos_args {
@@ -181,21 +492,165 @@ mod success {
                         .create();
 
                     cli_tool
-                        .arg("rust")
+                        .arg("rust")
+                        .args(["--server-url", &mock_server_base_url])
+                        .args(["--generator-uri", "/custom"]);
+                    let result = cli_tool
+                        .output()
+                        .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+                    let actual_output = String::from_utf8_lossy(&result.stdout);
+                    let expected_output =
+                        DefaultTestUtils::load_expectation_file("rust_template");
+
+                    let actual_status_code = result.status.code();
+                    let expected_status_code = Some(exit_status::SUCCESS);
+
+                    template_generator_mock.assert();
+
+                    assert_eq!(actual_status_code, expected_status_code);
+                    assert_eq!(actual_output, expected_output);
+                }
+
+                #[test]
+                #[parallel]
+                fn it_outputs_template_with_banner_and_without_trailing_newline() {
+                    let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+
+                    let mut mock_server = Server::new();
+                    let mock_server_base_url = mock_server.url();
+                    let template_generator_mock = mock_server
+                        .mock("GET", format!("{}/rust", template_manager::GENERATOR_URI).as_str())
+                        .with_status(200)
+                        .with_body("### Rust ###\ntarget/")
+                        .create();
+
+                    cli_tool
+                        .args(["rust", "--banner", "--trailing-newline", "never"])
+                        .args(["--server-url", &mock_server_base_url]);
+                    let result = cli_tool
+                        .output()
+                        .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+                    let actual_output = String::from_utf8_lossy(&result.stdout);
+                    let expected_output = format!(
+                        "# Generated by {0} {1}\n# Command: {0} rust --banner --trailing-newline never --server-url {mock_server_base_url}\n\n### Rust ###\ntarget/",
+                        env!("CARGO_PKG_NAME"),
+                        env!("CARGO_PKG_VERSION")
+                    );
+
+                    let actual_status_code = result.status.code();
+                    let expected_status_code = Some(exit_status::SUCCESS);
+
+                    template_generator_mock.assert();
+
+                    assert_eq!(actual_status_code, expected_status_code);
+                    assert_eq!(actual_output, expected_output);
+                }
+
+                #[test]
+                #[parallel]
+                fn it_outputs_template_details_with_show_subcommand() {
+                    let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+
+                    let mut mock_server = Server::new();
+                    let mock_server_base_url = mock_server.url();
+                    let template_generator_service_uri =
+                        format!("{}/rust", template_manager::GENERATOR_URI);
+                    let template_generator_mock = mock_server
+                        .mock("GET", template_generator_service_uri.as_str())
+                        .with_status(200)
+                        .with_body("### Rust ###\ntarget/")
+                        .create();
+
+                    cli_tool
+                        .args(["--server-url", &mock_server_base_url])
+                        .args(["show", "rust"]);
+                    let result = cli_tool
+                        .output()
+                        .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+                    let actual_output = String::from_utf8_lossy(&result.stdout);
+                    let expected_output = format!(
+                        "==> rust (remote)\nLocation: {mock_server_base_url}{template_generator_service_uri}\nSize: 20 bytes\nRules: 1\n\n### Rust ###\ntarget/\n"
+                    );
+
+                    let actual_status_code = result.status.code();
+                    let expected_status_code = Some(exit_status::SUCCESS);
+
+                    template_generator_mock.assert();
+
+                    assert_eq!(actual_status_code, expected_status_code);
+                    assert_eq!(actual_output, expected_output);
+                }
+
+                #[test]
+                #[parallel]
+                fn it_outputs_matching_templates_with_search_subcommand() {
+                    let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+
+                    let mut mock_server = Server::new();
+                    let mock_server_base_url = mock_server.url();
+                    let template_lister_mock = mock_server
+                        .mock("GET", template_manager::LISTER_URI)
+                        .with_status(200)
+                        .with_body("python,rust")
+                        .create();
+
+                    cli_tool
+                        .args(["--server-url", &mock_server_base_url])
+                        .args(["search", "py"]);
+                    let result = cli_tool
+                        .output()
+                        .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+                    let actual_output = String::from_utf8_lossy(&result.stdout);
+                    let expected_output = "python\n";
+
+                    let actual_status_code = result.status.code();
+                    let expected_status_code = Some(exit_status::SUCCESS);
+
+                    template_lister_mock.assert();
+
+                    assert_eq!(actual_status_code, expected_status_code);
+                    assert_eq!(actual_output, expected_output);
+                }
+
+                #[test]
+                #[parallel]
+                fn it_outputs_merged_templates_with_per_template_dialect() {
+                    let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+
+                    let mut mock_server = Server::new();
+                    let mock_server_base_url = mock_server.url();
+                    let rust_template_mock = mock_server
+                        .mock("GET", "/templates/rust")
+                        .with_status(200)
+                        .with_body("target/")
+                        .create();
+                    let python_template_mock = mock_server
+                        .mock("GET", "/templates/python")
+                        .with_status(200)
+                        .with_body("__pycache__/")
+                        .create();
+
+                    cli_tool
+                        .args(["rust", "python"])
                         .args(["--server-url", &mock_server_base_url])
-                        .args(["--generator-uri", "/custom"]);
+                        .args(["--generator-uri", "/templates"])
+                        .args(["--api-dialect", "per-template"]);
                     let result = cli_tool
                         .output()
                         .expect(error_messages::CMD_EXECUTION_FAILURE);
 
                     let actual_output = String::from_utf8_lossy(&result.stdout);
-                    let expected_output =
-                        DefaultTestUtils::load_expectation_file("rust_template");
+                    let expected_output = "### Rust ###\ntarget/\n\n### Python ###\n__pycache__/\n";
 
                     let actual_status_code = result.status.code();
                     let expected_status_code = Some(exit_status::SUCCESS);
 
-                    template_generator_mock.assert();
+                    rust_template_mock.assert();
+                    python_template_mock.assert();
 
                     assert_eq!(actual_status_code, expected_status_code);
                     assert_eq!(actual_output, expected_output);
@@ -358,6 +813,59 @@ mod success {
                     assert_eq!(actual_status_code, expected_status_code);
                     assert_eq!(actual_output, expected_output);
                 }
+
+                #[test]
+                #[parallel]
+                fn it_outputs_cached_template_list_when_not_modified() {
+                    let home_path = std::env::temp_dir()
+                        .join(format!("gitignore_template_generator_home_{}", std::process::id()));
+                    let _ = std::fs::remove_dir_all(&home_path);
+
+                    let mut mock_server = Server::new();
+                    let mock_server_base_url = mock_server.url();
+                    let template_lister_mock = mock_server
+                        .mock("GET", constant::template_manager::LISTER_URI)
+                        .match_header("if-none-match", mockito::Matcher::Missing)
+                        .with_status(200)
+                        .with_header("etag", "\"v1\"")
+                        .with_body(DefaultTestUtils::load_expectation_file("template_list"))
+                        .create();
+                    let not_modified_mock = mock_server
+                        .mock("GET", constant::template_manager::LISTER_URI)
+                        .match_header("if-none-match", "\"v1\"")
+                        .with_status(304)
+                        .create();
+
+                    let mut outputs = Vec::new();
+                    for _ in 0..2 {
+                        let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+                        cli_tool
+                            .env("HOME", &home_path)
+                            .env_remove(constant::template_manager::CACHE_HOME_ENV_VAR)
+                            .arg("--list")
+                            .arg("--cache")
+                            .args(["--server-url", &mock_server_base_url]);
+                        outputs.push(
+                            cli_tool
+                                .output()
+                                .expect(error_messages::CMD_EXECUTION_FAILURE),
+                        );
+                    }
+                    std::fs::remove_dir_all(&home_path).unwrap();
+
+                    let actual_output = String::from_utf8_lossy(&outputs[1].stdout);
+                    let expected_output =
+                        DefaultTestUtils::load_expectation_file("template_list");
+
+                    let actual_status_code = outputs[1].status.code();
+                    let expected_status_code = Some(exit_status::SUCCESS);
+
+                    template_lister_mock.assert();
+                    not_modified_mock.assert();
+
+                    assert_eq!(actual_status_code, expected_status_code);
+                    assert_eq!(actual_output, expected_output);
+                }
             }
         }
     }
@@ -405,7 +913,7 @@ mod failure {
                         format!("{}\n", error_messages::INVALID_ENCODING);
 
                     let actual_status_code = result.status.code();
-                    let expected_status_code = Some(exit_status::HTTP_CLIENT_ERROR);
+                    let expected_status_code = Some(exit_status::BODY_PARSING_ISSUE);
 
                     template_list_mock.assert();
                     template_generator_mock.assert();
@@ -441,10 +949,164 @@ mod failure {
                         format!("{}\n", error_messages::INVALID_ENCODING);
 
                     let actual_status_code = result.status.code();
-                    let expected_status_code = Some(exit_status::HTTP_CLIENT_ERROR);
+                    let expected_status_code = Some(exit_status::BODY_PARSING_ISSUE);
+
+                    template_generator_mock.assert();
+
+                    assert_eq!(actual_status_code, expected_status_code);
+                    assert_eq!(actual_error_message, expected_error_message);
+                }
+
+                #[test]
+                #[parallel]
+                fn it_outputs_diff_and_fails_when_gitignore_differs() {
+                    let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+                    let gitignore_path = std::env::temp_dir().join(format!(
+                        "gitignore_template_generator_diff_{}",
+                        std::process::id()
+                    ));
+                    std::fs::write(&gitignore_path, "target/\n*.log\n").unwrap();
+
+                    let mut mock_server = Server::new();
+                    let mock_server_base_url = mock_server.url();
+                    let template_generator_service_uri =
+                        format!("{}/rust", template_manager::GENERATOR_URI);
+                    let template_generator_mock = mock_server
+                        .mock("GET", template_generator_service_uri.as_str())
+                        .with_status(200)
+                        .with_body("target/\nCargo.lock\n")
+                        .create();
+
+                    cli_tool
+                        .arg("rust")
+                        .args(["--server-url", &mock_server_base_url])
+                        .arg("--diff")
+                        .arg(&gitignore_path);
+                    let result = cli_tool
+                        .output()
+                        .expect(error_messages::CMD_EXECUTION_FAILURE);
+                    std::fs::remove_file(&gitignore_path).unwrap();
+
+                    let actual_output = String::from_utf8_lossy(&result.stdout);
+                    let expected_output = format!(
+                        "--- {}\n+++ generated\n@@ -1,2 +1,2 @@\n target/\n-*.log\n+Cargo.lock\n",
+                        gitignore_path.display()
+                    );
+
+                    let actual_status_code = result.status.code();
+                    let expected_status_code = Some(exit_status::DIFF_MISMATCH);
 
                     template_generator_mock.assert();
 
+                    assert_eq!(actual_status_code, expected_status_code);
+                    assert_eq!(actual_output, expected_output);
+                }
+
+                #[test]
+                #[parallel]
+                fn it_outputs_report_and_fails_when_templates_missing() {
+                    let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+                    let gitignore_path = std::env::temp_dir().join(format!(
+                        "gitignore_template_generator_verify_{}",
+                        std::process::id()
+                    ));
+                    std::fs::write(&gitignore_path, "### Rust ###\ntarget/\n").unwrap();
+
+                    let mut mock_server = Server::new();
+                    let mock_server_base_url = mock_server.url();
+                    let rust_template_mock = mock_server
+                        .mock("GET", format!("{}/rust", template_manager::GENERATOR_URI).as_str())
+                        .with_status(200)
+                        .with_body("### Rust ###\ntarget/\nCargo.lock")
+                        .create();
+                    let python_template_mock = mock_server
+                        .mock("GET", format!("{}/python", template_manager::GENERATOR_URI).as_str())
+                        .with_status(200)
+                        .with_body("### Python ###\n__pycache__/")
+                        .create();
+
+                    cli_tool
+                        .args(["--server-url", &mock_server_base_url])
+                        .args(["verify", "rust", "python", "--file"])
+                        .arg(&gitignore_path);
+                    let result = cli_tool
+                        .output()
+                        .expect(error_messages::CMD_EXECUTION_FAILURE);
+                    std::fs::remove_file(&gitignore_path).unwrap();
+
+                    let actual_output = String::from_utf8_lossy(&result.stdout);
+                    let expected_output =
+                        "rust: outdated, 1 rule(s) missing\n  - Cargo.lock\npython: missing\n";
+
+                    let actual_status_code = result.status.code();
+                    let expected_status_code = Some(exit_status::VERIFY_FAILURE);
+
+                    rust_template_mock.assert();
+                    python_template_mock.assert();
+
+                    assert_eq!(actual_status_code, expected_status_code);
+                    assert_eq!(actual_output, expected_output);
+                }
+
+                #[test]
+                #[parallel]
+                fn it_outputs_error_and_fails_when_locked_template_changed() {
+                    let lockfile_path = std::env::temp_dir().join(format!(
+                        "gitignore_template_generator_lock_{}",
+                        std::process::id()
+                    ));
+                    let template_generator_service_uri =
+                        format!("{}/rust", template_manager::GENERATOR_URI);
+
+                    let mut mock_server = Server::new();
+                    let mock_server_base_url = mock_server.url();
+                    let initial_template_mock = mock_server
+                        .mock("GET", template_generator_service_uri.as_str())
+                        .with_status(200)
+                        .with_body("target/")
+                        .create();
+
+                    let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+                    cli_tool
+                        .arg("rust")
+                        .args(["--server-url", &mock_server_base_url])
+                        .arg("--lockfile")
+                        .arg(&lockfile_path);
+                    cli_tool
+                        .output()
+                        .expect(error_messages::CMD_EXECUTION_FAILURE);
+                    initial_template_mock.remove();
+
+                    let changed_template_mock = mock_server
+                        .mock("GET", template_generator_service_uri.as_str())
+                        .with_status(200)
+                        .with_body("target/\nCargo.lock")
+                        .expect(1)
+                        .create();
+
+                    let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+                    cli_tool
+                        .arg("rust")
+                        .args(["--server-url", &mock_server_base_url])
+                        .arg("--lockfile")
+                        .arg(&lockfile_path)
+                        .arg("--locked");
+                    let result = cli_tool
+                        .output()
+                        .expect(error_messages::CMD_EXECUTION_FAILURE);
+                    std::fs::remove_file(&lockfile_path).unwrap();
+
+                    let actual_error_message = String::from_utf8_lossy(&result.stderr);
+                    let expected_error_message = format!(
+                        "{}\n",
+                        error_messages::LOCKED_TEMPLATES_CHANGED.replace("{templates}", "rust")
+                    );
+
+                    let actual_status_code = result.status.code();
+                    let expected_status_code = Some(exit_status::LOCK_MISMATCH);
+
+                    changed_template_mock.assert();
+
                     assert_eq!(actual_status_code, expected_status_code);
                     assert_eq!(actual_error_message, expected_error_message);
                 }
@@ -475,7 +1137,7 @@ mod failure {
             let expected_error_message = format!("{}\n", error_messages::INVALID_ENCODING);
 
             let actual_status_code = result.status.code();
-            let expected_status_code = Some(exit_status::HTTP_CLIENT_ERROR);
+            let expected_status_code = Some(exit_status::BODY_PARSING_ISSUE);
 
             template_generator_mock.assert();
 
@@ -526,7 +1188,7 @@ mod failure {
                     );
 
                     let actual_status_code = result.status.code();
-                    let expected_status_code = Some(exit_status::GENERIC);
+                    let expected_status_code = Some(exit_status::HTTP_CLIENT_STATUS);
 
                     template_list_mock.assert();
                     template_generator_mock.assert();
@@ -565,7 +1227,7 @@ mod failure {
                     );
 
                     let actual_status_code = result.status.code();
-                    let expected_status_code = Some(exit_status::GENERIC);
+                    let expected_status_code = Some(exit_status::HTTP_CLIENT_STATUS);
 
                     template_generator_mock.assert();
 
@@ -602,7 +1264,118 @@ mod failure {
             );
 
             let actual_status_code = result.status.code();
-            let expected_status_code = Some(exit_status::GENERIC);
+            let expected_status_code = Some(exit_status::HTTP_CLIENT_STATUS);
+
+            template_generator_mock.assert();
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_error_message, expected_error_message);
+        }
+
+        #[test]
+        #[parallel]
+        fn it_logs_http_calls_when_verbose() {
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+
+            let mut mock_server = Server::new();
+            let mock_server_base_url = mock_server.url();
+            let template_generator_mock = mock_server
+                .mock("GET", template_manager::LISTER_URI)
+                .with_status(404)
+                .create();
+
+            cli_tool
+                .args(["--list", "--verbose"])
+                .args(["--server-url", &mock_server_base_url])
+                .args(["--lister-uri", template_manager::LISTER_URI]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+            let actual_error_message = String::from_utf8_lossy(&result.stderr);
+            let expected_log_prefix = format!(
+                "[verbose] GET {mock_server_base_url}{} -> 404 in ",
+                template_manager::LISTER_URI
+            );
+            let expected_error_message = format!(
+                "{}\n",
+                error_messages::API_CALL_FAILURE.replace("{error}", error_messages::HTTP_404)
+            );
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::HTTP_CLIENT_STATUS);
+
+            template_generator_mock.assert();
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert!(
+                actual_error_message
+                    .lines()
+                    .any(|line| line.starts_with(&expected_log_prefix))
+            );
+            assert!(actual_error_message.ends_with(&expected_error_message));
+        }
+
+        #[test]
+        #[parallel]
+        fn it_fails_without_error_message_when_quiet() {
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+
+            let mut mock_server = Server::new();
+            let mock_server_base_url = mock_server.url();
+            let template_generator_mock = mock_server
+                .mock("GET", template_manager::LISTER_URI)
+                .with_status(404)
+                .create();
+
+            cli_tool
+                .args(["--list", "--quiet"])
+                .args(["--server-url", &mock_server_base_url])
+                .args(["--lister-uri", template_manager::LISTER_URI]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+            let actual_error_message = String::from_utf8_lossy(&result.stderr);
+            let expected_error_message = "";
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::HTTP_CLIENT_STATUS);
+
+            template_generator_mock.assert();
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_error_message, expected_error_message);
+        }
+
+        #[test]
+        #[parallel]
+        fn it_outputs_styled_error_and_fails_when_color_forced() {
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+
+            let mut mock_server = Server::new();
+            let mock_server_base_url = mock_server.url();
+            let template_generator_mock = mock_server
+                .mock("GET", template_manager::LISTER_URI)
+                .with_status(404)
+                .create();
+
+            cli_tool
+                .args(["--list", "--color", "always"])
+                .args(["--server-url", &mock_server_base_url])
+                .args(["--lister-uri", template_manager::LISTER_URI]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+
+            let actual_error_message = String::from_utf8_lossy(&result.stderr);
+            let expected_error_message = format!(
+                "\u{1b}[31m{}\u{1b}[0m\n",
+                error_messages::API_CALL_FAILURE.replace("{error}", error_messages::HTTP_404)
+            );
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::HTTP_CLIENT_STATUS);
 
             template_generator_mock.assert();
 
@@ -630,7 +1403,7 @@ mod failure {
                 .arg("--list")
                 .args(["--server-url", &mock_server_base_url])
                 .args(["--lister-uri", template_manager::LISTER_URI])
-                .args(["--timeout", "1"]);
+                .args(["--timeout", "1s"]);
             let result = cli_tool
                 .output()
                 .expect(error_messages::CMD_EXECUTION_FAILURE);
@@ -639,12 +1412,75 @@ mod failure {
             let expected_error_message = constant::error_messages::TIMEOUT.to_string() + "\n";
 
             let actual_status_code = result.status.code();
-            let expected_status_code = Some(exit_status::HTTP_CLIENT_ERROR);
+            let expected_status_code = Some(exit_status::TIMEOUT);
 
             template_generator_mock.assert();
 
             assert_eq!(actual_status_code, expected_status_code);
             assert_eq!(actual_error_message, expected_error_message);
         }
+
+        #[test]
+        #[parallel]
+        fn it_reports_lint_issues_and_fails() {
+            let gitignore_path = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_linted_{}.gitignore",
+                std::process::id()
+            ));
+            std::fs::write(&gitignore_path, "target/\n*.log \ntarget/\n").unwrap();
+            let gitignore_path = gitignore_path.to_string_lossy().to_string();
+
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+            cli_tool.args(["lint", &gitignore_path]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            std::fs::remove_file(&gitignore_path).unwrap();
+
+            let actual_output = String::from_utf8_lossy(&result.stdout);
+            let expected_output = format!(
+                "{gitignore_path}:2: trailing whitespace is ignored unless escaped with a backslash\n{gitignore_path}:3: duplicate of the rule at line 1\n"
+            );
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::LINT_FAILURE);
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_output, expected_output);
+        }
+
+        #[test]
+        #[parallel]
+        fn it_lints_without_building_template_manager() {
+            let gitignore_path = std::env::temp_dir().join(format!(
+                "gitignore_template_generator_linted_clean_{}.gitignore",
+                std::process::id()
+            ));
+            std::fs::write(&gitignore_path, "target/\n").unwrap();
+            let gitignore_path = gitignore_path.to_string_lossy().to_string();
+
+            let mut cli_tool = get_test_bin(env!("CARGO_PKG_NAME"));
+            cli_tool
+                .env_remove("HOME")
+                .env_remove(template_manager::CACHE_HOME_ENV_VAR)
+                .arg("--cache")
+                .args(["lint", &gitignore_path]);
+            let result = cli_tool
+                .output()
+                .expect(error_messages::CMD_EXECUTION_FAILURE);
+            std::fs::remove_file(&gitignore_path).unwrap();
+
+            let actual_output = String::from_utf8_lossy(&result.stdout);
+            let expected_output = format!(
+                "{}\n",
+                constant::help_texts::LINT_CLEAN.replace("{source}", &gitignore_path)
+            );
+
+            let actual_status_code = result.status.code();
+            let expected_status_code = Some(exit_status::SUCCESS);
+
+            assert_eq!(actual_status_code, expected_status_code);
+            assert_eq!(actual_output, expected_output);
+        }
     }
 }